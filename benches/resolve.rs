@@ -0,0 +1,45 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use material_color_utilities_rs::{
+    dynamiccolor::{
+        color_role::ColorRole,
+        dynamic_scheme::DynamicScheme,
+        spec_version::{Platform, SpecVersion},
+        variant::Variant,
+    },
+    hct::hct::Hct,
+    utils::argb::Argb,
+};
+
+fn scheme(spec_version: SpecVersion) -> DynamicScheme {
+    DynamicScheme::from_spec(
+        Variant::TonalSpot,
+        Hct::from_argb(Argb(0xff4285f4)),
+        false,
+        0.5,
+        spec_version,
+        Platform::Phone,
+    )
+}
+
+fn resolve(c: &mut Criterion) {
+    for (name, spec_version) in [
+        ("2021", SpecVersion::Spec2021),
+        #[cfg(feature = "spec-2025")]
+        ("2025", SpecVersion::Spec2025),
+    ] {
+        let scheme = scheme(spec_version);
+        let mut group = c.benchmark_group(format!("resolve all roles ({})", name));
+        group.bench_function("each role", |b| {
+            b.iter(|| {
+                ColorRole::ALL
+                    .map(|role| black_box(&scheme).get(role))
+                    .to_vec()
+            })
+        });
+        group.bench_function("resolve", |b| b.iter(|| black_box(&scheme).resolve()));
+        group.finish();
+    }
+}
+
+criterion_group!(benches, resolve);
+criterion_main!(benches);
@@ -1,6 +1,6 @@
 use crate::{
     hct::{cam16::Cam16, hct::Hct},
-    utils::{color_utils::ColorUtils, math_utils::MathUtils},
+    utils::{argb::Argb, math_utils::MathUtils},
 };
 
 /// Functions for blending in HCT and CAM16.
@@ -15,9 +15,9 @@ impl Blend {
     /// Returns The design color with a hue shifted towards the
     /// system's color, a slightly warmer/cooler variant of the design
     /// color's hue.
-    pub fn harmonize(design_color: i64, source_color: i64) -> i64 {
-        let from_hct = Hct::from_int(design_color);
-        let to_hct = Hct::from_int(source_color);
+    pub fn harmonize_argb(design_color: Argb, source_color: Argb) -> Argb {
+        let from_hct = Hct::from_argb(design_color);
+        let to_hct = Hct::from_argb(source_color);
         let difference_degrees =
             MathUtils::difference_degrees(from_hct.get_hue(), to_hct.get_hue());
         let rotation_degrees = (difference_degrees * 0.5).min(15.0);
@@ -26,7 +26,7 @@ impl Blend {
                 + rotation_degrees
                     * MathUtils::rotation_direction(from_hct.get_hue(), to_hct.get_hue()),
         );
-        Hct::from(output_hue, from_hct.get_chroma(), from_hct.get_tone()).to_int()
+        Hct::from(output_hue, from_hct.get_chroma(), from_hct.get_tone()).to_argb()
     }
 
     /// Blends hue from one color into another. The chroma and tone of
@@ -37,16 +37,12 @@ impl Blend {
     /// [amount] how much blending to perform; 0.0 >= and <= 1.0
     /// Returns from, with a hue blended towards to. Chroma and tone
     /// are constant.
-    pub fn hct_hue(from: i64, to: i64, amount: f64) -> i64 {
-        let ucs = Self::cam16_ucs(from, to, amount);
-        let ucs_cam = Cam16::from_int(ucs);
-        let from_cam = Cam16::from_int(from);
-        let blended = Hct::from(
-            ucs_cam.hue,
-            from_cam.chroma,
-            ColorUtils::lstar_from_argb(from),
-        );
-        blended.to_int()
+    pub fn hct_hue_argb(from: Argb, to: Argb, amount: f64) -> Argb {
+        let ucs = Self::cam16_ucs_argb(from, to, amount);
+        let ucs_cam = Cam16::from_argb(ucs);
+        let from_cam = Cam16::from_argb(from);
+        let blended = Hct::from(ucs_cam.hue, from_cam.chroma, from.lstar());
+        blended.to_argb()
     }
 
     /// Blend in CAM16-UCS space.
@@ -56,9 +52,9 @@ impl Blend {
     /// [amount] how much blending to perform; 0.0 >= and <= 1.0
     /// Returns from, blended towards to. Hue, chroma, and tone will
     /// change.
-    pub fn cam16_ucs(from: i64, to: i64, amount: f64) -> i64 {
-        let from_cam = Cam16::from_int(from);
-        let to_cam = Cam16::from_int(to);
+    pub fn cam16_ucs_argb(from: Argb, to: Argb, amount: f64) -> Argb {
+        let from_cam = Cam16::from_argb(from);
+        let to_cam = Cam16::from_argb(to);
         let from_j = from_cam.jstar;
         let from_a = from_cam.astar;
         let from_b = from_cam.bstar;
@@ -68,6 +64,21 @@ impl Blend {
         let jstar = from_j + (to_j - from_j) * amount;
         let astar = from_a + (to_a - from_a) * amount;
         let bstar = from_b + (to_b - from_b) * amount;
-        Cam16::from_ucs(jstar, astar, bstar).to_int()
+        Cam16::from_ucs(jstar, astar, bstar).to_argb()
+    }
+
+    #[deprecated = "use `Blend::harmonize_argb` instead"]
+    pub fn harmonize(design_color: i64, source_color: i64) -> i64 {
+        return Self::harmonize_argb(Argb(design_color as u32), Argb(source_color as u32)).into();
+    }
+
+    #[deprecated = "use `Blend::hct_hue_argb` instead"]
+    pub fn hct_hue(from: i64, to: i64, amount: f64) -> i64 {
+        return Self::hct_hue_argb(Argb(from as u32), Argb(to as u32), amount).into();
+    }
+
+    #[deprecated = "use `Blend::cam16_ucs_argb` instead"]
+    pub fn cam16_ucs(from: i64, to: i64, amount: f64) -> i64 {
+        return Self::cam16_ucs_argb(Argb(from as u32), Argb(to as u32), amount).into();
     }
 }
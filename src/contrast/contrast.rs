@@ -1,4 +1,7 @@
-use crate::utils::{color_utils::ColorUtils, math_utils::MathUtils};
+use crate::{
+    error::error::Error,
+    utils::{color_utils::ColorUtils, math_utils::MathUtils},
+};
 
 /// Utility methods for calculating contrast given two colors, or calculating a
 /// color given one color and a contrast ratio.
@@ -44,15 +47,17 @@ impl Contrast {
 
     /// Returns a tone >= [tone] that ensures [ratio].
     /// Return value is between 0 and 100.
-    /// Returns -1 if [ratio] cannot be achieved with [tone].
+    /// Returns [Error::UnreachableContrast] if [ratio] cannot be achieved with
+    /// [tone].
     ///
     /// [tone] Tone return value must contrast with.
-    /// Range is 0 to 100. Invalid values will result in -1 being returned.
+    /// Range is 0 to 100. Invalid values will result in
+    /// [Error::ToneOutOfRange] being returned.
     /// [ratio] Contrast ratio of return value and [tone].
     /// Range is 1 to 21, invalid values have undefined behavior.
-    pub fn lighter(tone: f64, ratio: f64) -> f64 {
+    pub fn try_lighter(tone: f64, ratio: f64) -> Result<f64, Error> {
         if tone < 0.0 || tone > 100.0 {
-            return -1.0;
+            return Err(Error::ToneOutOfRange(tone));
         }
 
         let dark_y = ColorUtils::y_from_lstar(tone);
@@ -60,29 +65,31 @@ impl Contrast {
         let real_contrast = Self::_ratio_of_ys(light_y, dark_y);
         let delta = (real_contrast - ratio).abs();
         if real_contrast < ratio && delta > 0.04 {
-            return -1.0;
+            return Err(Error::UnreachableContrast { tone, ratio });
         }
 
         // Ensure gamut mapping, which requires a 'range' on tone, will still result
         // the correct ratio by darkening slightly.
         let return_value = ColorUtils::lstar_from_y(light_y) + 0.4;
         if return_value < 0.0 || return_value > 100.0 {
-            return -1.0;
+            return Err(Error::UnreachableContrast { tone, ratio });
         }
-        return_value
+        Ok(return_value)
     }
 
     /// Returns a tone <= [tone] that ensures [ratio].
     /// Return value is between 0 and 100.
-    /// Returns -1 if [ratio] cannot be achieved with [tone].
+    /// Returns [Error::UnreachableContrast] if [ratio] cannot be achieved with
+    /// [tone].
     ///
     /// [tone] Tone return value must contrast with.
-    /// Range is 0 to 100. Invalid values will result in -1 being returned.
+    /// Range is 0 to 100. Invalid values will result in
+    /// [Error::ToneOutOfRange] being returned.
     /// [ratio] Contrast ratio of return value and [tone].
     /// Range is 1 to 21, invalid values have undefined behavior.
-    pub fn darker(tone: f64, ratio: f64) -> f64 {
+    pub fn try_darker(tone: f64, ratio: f64) -> Result<f64, Error> {
         if tone < 0.0 || tone > 100.0 {
-            return -1.0;
+            return Err(Error::ToneOutOfRange(tone));
         }
 
         let light_y = ColorUtils::y_from_lstar(tone);
@@ -91,16 +98,30 @@ impl Contrast {
 
         let delta = (real_contrast - ratio).abs();
         if real_contrast < ratio && delta > 0.04 {
-            return -1.0;
+            return Err(Error::UnreachableContrast { tone, ratio });
         }
 
         // Ensure gamut mapping, which requires a 'range' on tone, will still result
         // the correct ratio by darkening slightly.
         let return_value = ColorUtils::lstar_from_y(dark_y) - 0.4;
         if return_value < 0.0 || return_value > 100.0 {
-            return -1.0;
+            return Err(Error::UnreachableContrast { tone, ratio });
         }
-        return_value
+        Ok(return_value)
+    }
+
+    /// Returns a tone >= [tone] that ensures [ratio].
+    /// Returns -1 if [ratio] cannot be achieved with [tone].
+    #[deprecated = "use `Contrast::try_lighter` instead"]
+    pub fn lighter(tone: f64, ratio: f64) -> f64 {
+        Self::try_lighter(tone, ratio).unwrap_or(-1.0)
+    }
+
+    /// Returns a tone <= [tone] that ensures [ratio].
+    /// Returns -1 if [ratio] cannot be achieved with [tone].
+    #[deprecated = "use `Contrast::try_darker` instead"]
+    pub fn darker(tone: f64, ratio: f64) -> f64 {
+        Self::try_darker(tone, ratio).unwrap_or(-1.0)
     }
 
     /// Returns a tone >= [tone] that ensures [ratio].
@@ -116,12 +137,7 @@ impl Contrast {
     /// [ratio] Desired contrast ratio of return value and tone parameter.
     /// Range is 1 to 21, invalid values have undefined behavior.
     pub fn lighter_unsafe(tone: f64, ratio: f64) -> f64 {
-        let lighter_safe = Self::lighter(tone, ratio);
-        if lighter_safe < 0.0 {
-            100.0
-        } else {
-            lighter_safe
-        }
+        Self::try_lighter(tone, ratio).unwrap_or(100.0)
     }
 
     /// Returns a tone <= [tone] that ensures [ratio].
@@ -137,7 +153,6 @@ impl Contrast {
     /// [ratio] Desired contrast ratio of return value and tone parameter.
     /// Range is 1 to 21, invalid values have undefined behavior.
     pub fn darker_unsafe(tone: f64, ratio: f64) -> f64 {
-        let darker_safe = Self::darker(tone, ratio);
-        return if darker_safe < 0.0 { 0.0 } else { darker_safe };
+        return Self::try_darker(tone, ratio).unwrap_or(0.0);
     }
 }
@@ -0,0 +1,277 @@
+use std::{fmt, str::FromStr};
+
+use crate::error::error::Error;
+
+use super::spec_version::SpecVersion;
+
+/// A color role of the Material color system, one per [MaterialDynamicColors]
+/// color.
+///
+/// With the `serde` feature, a role is serialized as its [name].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum ColorRole {
+    PrimaryPaletteKeyColor,
+    SecondaryPaletteKeyColor,
+    TertiaryPaletteKeyColor,
+    NeutralPaletteKeyColor,
+    NeutralVariantPaletteKeyColor,
+    Background,
+    OnBackground,
+    Surface,
+    SurfaceDim,
+    SurfaceBright,
+    SurfaceContainerLowest,
+    SurfaceContainerLow,
+    SurfaceContainer,
+    SurfaceContainerHigh,
+    SurfaceContainerHighest,
+    OnSurface,
+    SurfaceVariant,
+    OnSurfaceVariant,
+    InverseSurface,
+    InverseOnSurface,
+    Outline,
+    OutlineVariant,
+    Shadow,
+    Scrim,
+    SurfaceTint,
+    Primary,
+    PrimaryDim,
+    OnPrimary,
+    PrimaryContainer,
+    OnPrimaryContainer,
+    InversePrimary,
+    Secondary,
+    SecondaryDim,
+    OnSecondary,
+    SecondaryContainer,
+    OnSecondaryContainer,
+    Tertiary,
+    TertiaryDim,
+    OnTertiary,
+    TertiaryContainer,
+    OnTertiaryContainer,
+    Error,
+    ErrorDim,
+    OnError,
+    ErrorContainer,
+    OnErrorContainer,
+    Success,
+    OnSuccess,
+    SuccessContainer,
+    OnSuccessContainer,
+    Warning,
+    OnWarning,
+    WarningContainer,
+    OnWarningContainer,
+    Info,
+    OnInfo,
+    InfoContainer,
+    OnInfoContainer,
+    PrimaryFixed,
+    PrimaryFixedDim,
+    OnPrimaryFixed,
+    OnPrimaryFixedVariant,
+    SecondaryFixed,
+    SecondaryFixedDim,
+    OnSecondaryFixed,
+    OnSecondaryFixedVariant,
+    TertiaryFixed,
+    TertiaryFixedDim,
+    OnTertiaryFixed,
+    OnTertiaryFixedVariant,
+}
+
+impl ColorRole {
+    /// Every role, in the order [MaterialDynamicColors] declares them.
+    pub const ALL: [ColorRole; 70] = [
+        ColorRole::PrimaryPaletteKeyColor,
+        ColorRole::SecondaryPaletteKeyColor,
+        ColorRole::TertiaryPaletteKeyColor,
+        ColorRole::NeutralPaletteKeyColor,
+        ColorRole::NeutralVariantPaletteKeyColor,
+        ColorRole::Background,
+        ColorRole::OnBackground,
+        ColorRole::Surface,
+        ColorRole::SurfaceDim,
+        ColorRole::SurfaceBright,
+        ColorRole::SurfaceContainerLowest,
+        ColorRole::SurfaceContainerLow,
+        ColorRole::SurfaceContainer,
+        ColorRole::SurfaceContainerHigh,
+        ColorRole::SurfaceContainerHighest,
+        ColorRole::OnSurface,
+        ColorRole::SurfaceVariant,
+        ColorRole::OnSurfaceVariant,
+        ColorRole::InverseSurface,
+        ColorRole::InverseOnSurface,
+        ColorRole::Outline,
+        ColorRole::OutlineVariant,
+        ColorRole::Shadow,
+        ColorRole::Scrim,
+        ColorRole::SurfaceTint,
+        ColorRole::Primary,
+        ColorRole::PrimaryDim,
+        ColorRole::OnPrimary,
+        ColorRole::PrimaryContainer,
+        ColorRole::OnPrimaryContainer,
+        ColorRole::InversePrimary,
+        ColorRole::Secondary,
+        ColorRole::SecondaryDim,
+        ColorRole::OnSecondary,
+        ColorRole::SecondaryContainer,
+        ColorRole::OnSecondaryContainer,
+        ColorRole::Tertiary,
+        ColorRole::TertiaryDim,
+        ColorRole::OnTertiary,
+        ColorRole::TertiaryContainer,
+        ColorRole::OnTertiaryContainer,
+        ColorRole::Error,
+        ColorRole::ErrorDim,
+        ColorRole::OnError,
+        ColorRole::ErrorContainer,
+        ColorRole::OnErrorContainer,
+        ColorRole::Success,
+        ColorRole::OnSuccess,
+        ColorRole::SuccessContainer,
+        ColorRole::OnSuccessContainer,
+        ColorRole::Warning,
+        ColorRole::OnWarning,
+        ColorRole::WarningContainer,
+        ColorRole::OnWarningContainer,
+        ColorRole::Info,
+        ColorRole::OnInfo,
+        ColorRole::InfoContainer,
+        ColorRole::OnInfoContainer,
+        ColorRole::PrimaryFixed,
+        ColorRole::PrimaryFixedDim,
+        ColorRole::OnPrimaryFixed,
+        ColorRole::OnPrimaryFixedVariant,
+        ColorRole::SecondaryFixed,
+        ColorRole::SecondaryFixedDim,
+        ColorRole::OnSecondaryFixed,
+        ColorRole::OnSecondaryFixedVariant,
+        ColorRole::TertiaryFixed,
+        ColorRole::TertiaryFixedDim,
+        ColorRole::OnTertiaryFixed,
+        ColorRole::OnTertiaryFixedVariant,
+    ];
+
+    /// The role a scheme following [spec_version] resolves in place of this
+    /// one.
+    ///
+    /// [SpecVersion::Spec2021] has no dim roles, so [ColorRole::PrimaryDim],
+    /// [ColorRole::SecondaryDim], [ColorRole::TertiaryDim] and
+    /// [ColorRole::ErrorDim] resolve as their base role there. Every other
+    /// role resolves as itself.
+    pub const fn for_spec(self, spec_version: SpecVersion) -> ColorRole {
+        match (self, spec_version) {
+            (ColorRole::PrimaryDim, SpecVersion::Spec2021) => ColorRole::Primary,
+            (ColorRole::SecondaryDim, SpecVersion::Spec2021) => ColorRole::Secondary,
+            (ColorRole::TertiaryDim, SpecVersion::Spec2021) => ColorRole::Tertiary,
+            (ColorRole::ErrorDim, SpecVersion::Spec2021) => ColorRole::Error,
+            _ => self,
+        }
+    }
+
+    /// The snake_case name of the role, e.g. `"on_primary_container"`.
+    ///
+    /// Matches [DynamicColor.name] of the color the role resolves to.
+    pub const fn name(self) -> &'static str {
+        match self {
+            ColorRole::PrimaryPaletteKeyColor => "primary_palette_key_color",
+            ColorRole::SecondaryPaletteKeyColor => "secondary_palette_key_color",
+            ColorRole::TertiaryPaletteKeyColor => "tertiary_palette_key_color",
+            ColorRole::NeutralPaletteKeyColor => "neutral_palette_key_color",
+            ColorRole::NeutralVariantPaletteKeyColor => "neutral_variant_palette_key_color",
+            ColorRole::Background => "background",
+            ColorRole::OnBackground => "on_background",
+            ColorRole::Surface => "surface",
+            ColorRole::SurfaceDim => "surface_dim",
+            ColorRole::SurfaceBright => "surface_bright",
+            ColorRole::SurfaceContainerLowest => "surface_container_lowest",
+            ColorRole::SurfaceContainerLow => "surface_container_low",
+            ColorRole::SurfaceContainer => "surface_container",
+            ColorRole::SurfaceContainerHigh => "surface_container_high",
+            ColorRole::SurfaceContainerHighest => "surface_container_highest",
+            ColorRole::OnSurface => "on_surface",
+            ColorRole::SurfaceVariant => "surface_variant",
+            ColorRole::OnSurfaceVariant => "on_surface_variant",
+            ColorRole::InverseSurface => "inverse_surface",
+            ColorRole::InverseOnSurface => "inverse_on_surface",
+            ColorRole::Outline => "outline",
+            ColorRole::OutlineVariant => "outline_variant",
+            ColorRole::Shadow => "shadow",
+            ColorRole::Scrim => "scrim",
+            ColorRole::SurfaceTint => "surface_tint",
+            ColorRole::Primary => "primary",
+            ColorRole::PrimaryDim => "primary_dim",
+            ColorRole::OnPrimary => "on_primary",
+            ColorRole::PrimaryContainer => "primary_container",
+            ColorRole::OnPrimaryContainer => "on_primary_container",
+            ColorRole::InversePrimary => "inverse_primary",
+            ColorRole::Secondary => "secondary",
+            ColorRole::SecondaryDim => "secondary_dim",
+            ColorRole::OnSecondary => "on_secondary",
+            ColorRole::SecondaryContainer => "secondary_container",
+            ColorRole::OnSecondaryContainer => "on_secondary_container",
+            ColorRole::Tertiary => "tertiary",
+            ColorRole::TertiaryDim => "tertiary_dim",
+            ColorRole::OnTertiary => "on_tertiary",
+            ColorRole::TertiaryContainer => "tertiary_container",
+            ColorRole::OnTertiaryContainer => "on_tertiary_container",
+            ColorRole::Error => "error",
+            ColorRole::ErrorDim => "error_dim",
+            ColorRole::OnError => "on_error",
+            ColorRole::ErrorContainer => "error_container",
+            ColorRole::OnErrorContainer => "on_error_container",
+            ColorRole::Success => "success",
+            ColorRole::OnSuccess => "on_success",
+            ColorRole::SuccessContainer => "success_container",
+            ColorRole::OnSuccessContainer => "on_success_container",
+            ColorRole::Warning => "warning",
+            ColorRole::OnWarning => "on_warning",
+            ColorRole::WarningContainer => "warning_container",
+            ColorRole::OnWarningContainer => "on_warning_container",
+            ColorRole::Info => "info",
+            ColorRole::OnInfo => "on_info",
+            ColorRole::InfoContainer => "info_container",
+            ColorRole::OnInfoContainer => "on_info_container",
+            ColorRole::PrimaryFixed => "primary_fixed",
+            ColorRole::PrimaryFixedDim => "primary_fixed_dim",
+            ColorRole::OnPrimaryFixed => "on_primary_fixed",
+            ColorRole::OnPrimaryFixedVariant => "on_primary_fixed_variant",
+            ColorRole::SecondaryFixed => "secondary_fixed",
+            ColorRole::SecondaryFixedDim => "secondary_fixed_dim",
+            ColorRole::OnSecondaryFixed => "on_secondary_fixed",
+            ColorRole::OnSecondaryFixedVariant => "on_secondary_fixed_variant",
+            ColorRole::TertiaryFixed => "tertiary_fixed",
+            ColorRole::TertiaryFixedDim => "tertiary_fixed_dim",
+            ColorRole::OnTertiaryFixed => "on_tertiary_fixed",
+            ColorRole::OnTertiaryFixedVariant => "on_tertiary_fixed_variant",
+        }
+    }
+}
+
+impl fmt::Display for ColorRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for ColorRole {
+    type Err = Error;
+
+    /// Parses a role from its [name].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ColorRole::ALL
+            .into_iter()
+            .find(|role| role.name() == s)
+            .ok_or_else(|| Error::UnknownColorRole(s.to_string()))
+    }
+}
@@ -0,0 +1,634 @@
+use crate::{hct::hct::Hct, palettes::tonal_palette::TonalPalette, utils::math_utils::MathUtils};
+
+use super::{
+    dynamic_scheme::DynamicScheme,
+    spec_version::{Platform, SpecVersion},
+    variant::Variant,
+};
+
+/// Palettes and tones of the [SpecVersion::Spec2025] specification.
+///
+/// Every function returns `None` for variants the 2025 specification doesn't
+/// revise, which keep their 2021 palettes and tones. Tone functions also
+/// return `None` for schemes following [SpecVersion::Spec2021].
+pub struct ColorSpec2025 {}
+impl ColorSpec2025 {
+    pub fn primary_palette(
+        variant: &Variant,
+        source_color_hct: &Hct,
+        is_dark: bool,
+        platform: Platform,
+    ) -> Option<TonalPalette> {
+        let hue = source_color_hct.get_hue();
+        let phone = platform == Platform::Phone;
+        let chroma = match variant {
+            Variant::Neutral => match (phone, _is_blue(hue)) {
+                (true, true) => 12.0,
+                (true, false) => 8.0,
+                (false, true) => 16.0,
+                (false, false) => 12.0,
+            },
+            Variant::TonalSpot => {
+                if phone && is_dark {
+                    26.0
+                } else {
+                    32.0
+                }
+            }
+            Variant::Expressive => match (phone, is_dark) {
+                (true, true) => 36.0,
+                (true, false) => 48.0,
+                (false, _) => 40.0,
+            },
+            Variant::Vibrant => {
+                if phone {
+                    74.0
+                } else {
+                    56.0
+                }
+            }
+            _ => return None,
+        };
+        return Some(TonalPalette::of(hue, chroma));
+    }
+
+    pub fn secondary_palette(
+        variant: &Variant,
+        source_color_hct: &Hct,
+        is_dark: bool,
+        platform: Platform,
+    ) -> Option<TonalPalette> {
+        let hue = source_color_hct.get_hue();
+        let phone = platform == Platform::Phone;
+        return match variant {
+            Variant::Neutral => Some(TonalPalette::of(
+                hue,
+                match (phone, _is_blue(hue)) {
+                    (true, true) => 6.0,
+                    (true, false) => 4.0,
+                    (false, true) => 10.0,
+                    (false, false) => 6.0,
+                },
+            )),
+            Variant::TonalSpot => Some(TonalPalette::of(hue, 16.0)),
+            Variant::Expressive => Some(TonalPalette::of(
+                _rotated_hue(
+                    source_color_hct,
+                    &[0.0, 105.0, 140.0, 204.0, 253.0, 278.0, 300.0, 333.0, 360.0],
+                    &[-160.0, 155.0, -100.0, 96.0, -96.0, -156.0, -165.0, -160.0],
+                ),
+                if phone && is_dark { 16.0 } else { 24.0 },
+            )),
+            Variant::Vibrant => Some(TonalPalette::of(
+                _rotated_hue(
+                    source_color_hct,
+                    &[0.0, 38.0, 105.0, 140.0, 333.0, 360.0],
+                    &[-14.0, 10.0, -14.0, 10.0, -14.0],
+                ),
+                if phone { 56.0 } else { 36.0 },
+            )),
+            _ => None,
+        };
+    }
+
+    pub fn tertiary_palette(
+        variant: &Variant,
+        source_color_hct: &Hct,
+        platform: Platform,
+    ) -> Option<TonalPalette> {
+        let phone = platform == Platform::Phone;
+        return match variant {
+            Variant::Neutral => Some(TonalPalette::of(
+                _rotated_hue(
+                    source_color_hct,
+                    &[0.0, 38.0, 105.0, 161.0, 204.0, 278.0, 333.0, 360.0],
+                    &[-32.0, 26.0, 10.0, -39.0, 24.0, -15.0, -32.0],
+                ),
+                if phone { 20.0 } else { 36.0 },
+            )),
+            Variant::TonalSpot => Some(TonalPalette::of(
+                _rotated_hue(
+                    source_color_hct,
+                    &[0.0, 20.0, 71.0, 161.0, 333.0, 360.0],
+                    &[-40.0, 48.0, -32.0, 40.0, -32.0],
+                ),
+                if phone { 28.0 } else { 32.0 },
+            )),
+            Variant::Expressive => Some(TonalPalette::of(
+                _rotated_hue(
+                    source_color_hct,
+                    &[0.0, 105.0, 140.0, 204.0, 253.0, 278.0, 300.0, 333.0, 360.0],
+                    &[-165.0, 160.0, -105.0, 101.0, -101.0, -160.0, -170.0, -165.0],
+                ),
+                48.0,
+            )),
+            Variant::Vibrant => Some(TonalPalette::of(
+                _rotated_hue(
+                    source_color_hct,
+                    &[0.0, 38.0, 71.0, 105.0, 140.0, 161.0, 253.0, 333.0, 360.0],
+                    &[-72.0, 35.0, 24.0, -24.0, 62.0, 50.0, 62.0, -72.0],
+                ),
+                56.0,
+            )),
+            _ => None,
+        };
+    }
+
+    pub fn neutral_palette(
+        variant: &Variant,
+        source_color_hct: &Hct,
+        is_dark: bool,
+        platform: Platform,
+    ) -> Option<TonalPalette> {
+        let hue = source_color_hct.get_hue();
+        let phone = platform == Platform::Phone;
+        return match variant {
+            Variant::Neutral => Some(TonalPalette::of(hue, if phone { 1.4 } else { 6.0 })),
+            Variant::TonalSpot => Some(TonalPalette::of(hue, if phone { 5.0 } else { 10.0 })),
+            Variant::Expressive => Some(TonalPalette::of(
+                _expressive_neutral_hue(source_color_hct),
+                _expressive_neutral_chroma(source_color_hct, is_dark, platform),
+            )),
+            Variant::Vibrant => Some(TonalPalette::of(
+                _vibrant_neutral_hue(source_color_hct),
+                _vibrant_neutral_chroma(source_color_hct, platform),
+            )),
+            _ => None,
+        };
+    }
+
+    pub fn neutral_variant_palette(
+        variant: &Variant,
+        source_color_hct: &Hct,
+        is_dark: bool,
+        platform: Platform,
+    ) -> Option<TonalPalette> {
+        let hue = source_color_hct.get_hue();
+        let phone = platform == Platform::Phone;
+        return match variant {
+            Variant::Neutral => Some(TonalPalette::of(hue, (if phone { 1.4 } else { 6.0 }) * 2.2)),
+            Variant::TonalSpot => Some(TonalPalette::of(
+                hue,
+                (if phone { 5.0 } else { 10.0 }) * 1.7,
+            )),
+            Variant::Expressive => {
+                let neutral_hue = _expressive_neutral_hue(source_color_hct);
+                let neutral_chroma =
+                    _expressive_neutral_chroma(source_color_hct, is_dark, platform);
+                Some(TonalPalette::of(
+                    neutral_hue,
+                    neutral_chroma * if _is_yellow(neutral_hue) { 1.6 } else { 2.3 },
+                ))
+            }
+            Variant::Vibrant => Some(TonalPalette::of(
+                _vibrant_neutral_hue(source_color_hct),
+                _vibrant_neutral_chroma(source_color_hct, platform) * 1.29,
+            )),
+            _ => None,
+        };
+    }
+
+    pub fn error_palette(
+        variant: &Variant,
+        source_color_hct: &Hct,
+        platform: Platform,
+    ) -> Option<TonalPalette> {
+        let error_hue = _piecewise_value(
+            source_color_hct,
+            &[0.0, 3.0, 13.0, 23.0, 33.0, 43.0, 153.0, 273.0, 360.0],
+            &[12.0, 22.0, 32.0, 12.0, 22.0, 32.0, 22.0, 12.0],
+        );
+        let phone = platform == Platform::Phone;
+        let chroma = match variant {
+            Variant::Neutral => {
+                if phone {
+                    50.0
+                } else {
+                    40.0
+                }
+            }
+            Variant::TonalSpot => {
+                if phone {
+                    60.0
+                } else {
+                    48.0
+                }
+            }
+            Variant::Expressive => {
+                if phone {
+                    64.0
+                } else {
+                    48.0
+                }
+            }
+            Variant::Vibrant => {
+                if phone {
+                    80.0
+                } else {
+                    60.0
+                }
+            }
+            _ => return None,
+        };
+        return Some(TonalPalette::of(
+            MathUtils::sanitize_degrees_double(error_hue),
+            chroma,
+        ));
+    }
+}
+
+impl ColorSpec2025 {
+    /// The tone between [lower] and [upper] where [palette] reaches its
+    /// chroma, searching from the lightest tone.
+    pub(crate) fn t_max_c(palette: &TonalPalette, lower: f64, upper: f64) -> f64 {
+        return MathUtils::clamp_double(lower, upper, palette.max_chroma_tone(true));
+    }
+
+    /// The tone between [lower] and [upper] where [palette] reaches its
+    /// chroma, searching from the darkest tone.
+    pub(crate) fn t_min_c(palette: &TonalPalette, lower: f64, upper: f64) -> f64 {
+        return MathUtils::clamp_double(lower, upper, palette.max_chroma_tone(false));
+    }
+
+    /// The tone of the surface and background roles.
+    pub fn surface_tone(s: &DynamicScheme) -> Option<f64> {
+        if !_is_revised(s) {
+            return None;
+        }
+        if s.platform == Platform::Watch {
+            return Some(0.0);
+        }
+        return Some(if s.is_dark {
+            4.0
+        } else {
+            _light_surface_tone(s, 99.0, 97.0, 98.0)
+        });
+    }
+
+    pub fn surface_dim_tone(s: &DynamicScheme) -> Option<f64> {
+        if !_is_revised(s) {
+            return None;
+        }
+        return Some(if s.is_dark {
+            4.0
+        } else {
+            _light_surface_tone(s, 90.0, 85.0, 87.0)
+        });
+    }
+
+    pub fn surface_bright_tone(s: &DynamicScheme) -> Option<f64> {
+        if !_is_revised(s) {
+            return None;
+        }
+        return Some(if s.is_dark {
+            18.0
+        } else {
+            _light_surface_tone(s, 99.0, 97.0, 98.0)
+        });
+    }
+
+    pub fn surface_container_lowest_tone(s: &DynamicScheme) -> Option<f64> {
+        if !_is_revised(s) {
+            return None;
+        }
+        return Some(if s.is_dark { 0.0 } else { 100.0 });
+    }
+
+    pub fn surface_container_low_tone(s: &DynamicScheme) -> Option<f64> {
+        if !_is_revised(s) {
+            return None;
+        }
+        if s.platform == Platform::Watch {
+            return Some(15.0);
+        }
+        return Some(if s.is_dark {
+            6.0
+        } else {
+            _light_surface_tone(s, 98.0, 95.0, 96.0)
+        });
+    }
+
+    pub fn surface_container_tone(s: &DynamicScheme) -> Option<f64> {
+        if !_is_revised(s) {
+            return None;
+        }
+        if s.platform == Platform::Watch {
+            return Some(20.0);
+        }
+        return Some(if s.is_dark {
+            9.0
+        } else {
+            _light_surface_tone(s, 96.0, 92.0, 94.0)
+        });
+    }
+
+    pub fn surface_container_high_tone(s: &DynamicScheme) -> Option<f64> {
+        if !_is_revised(s) {
+            return None;
+        }
+        if s.platform == Platform::Watch {
+            return Some(25.0);
+        }
+        return Some(if s.is_dark {
+            12.0
+        } else {
+            _light_surface_tone(s, 94.0, 90.0, 92.0)
+        });
+    }
+
+    pub fn surface_container_highest_tone(s: &DynamicScheme) -> Option<f64> {
+        if !_is_revised(s) {
+            return None;
+        }
+        return Some(if s.is_dark {
+            15.0
+        } else {
+            _light_surface_tone(s, 92.0, 88.0, 90.0)
+        });
+    }
+
+    pub fn primary_tone(s: &DynamicScheme) -> Option<f64> {
+        if !_is_revised(s) {
+            return None;
+        }
+        let palette = &s.primary_palette;
+        if s.platform == Platform::Watch {
+            return Some(match s.variant {
+                Variant::Neutral => 90.0,
+                Variant::TonalSpot => Self::t_max_c(palette, 0.0, 90.0),
+                _ => Self::t_max_c(palette, 0.0, 100.0),
+            });
+        }
+        return Some(match s.variant {
+            Variant::Neutral => {
+                if s.is_dark {
+                    80.0
+                } else {
+                    40.0
+                }
+            }
+            Variant::TonalSpot => {
+                if s.is_dark {
+                    80.0
+                } else {
+                    Self::t_max_c(palette, 0.0, 100.0)
+                }
+            }
+            Variant::Expressive => {
+                let upper = if _is_yellow(palette.hue) {
+                    25.0
+                } else if _is_cyan(palette.hue) {
+                    88.0
+                } else {
+                    98.0
+                };
+                Self::t_max_c(palette, 0.0, upper)
+            }
+            _ => Self::t_max_c(
+                palette,
+                0.0,
+                if _is_cyan(palette.hue) { 88.0 } else { 98.0 },
+            ),
+        });
+    }
+
+    pub fn primary_container_tone(s: &DynamicScheme) -> Option<f64> {
+        if !_is_revised(s) {
+            return None;
+        }
+        if s.platform == Platform::Watch {
+            return Some(30.0);
+        }
+        let palette = &s.primary_palette;
+        let cyan = _is_cyan(palette.hue);
+        return Some(match (&s.variant, s.is_dark) {
+            (Variant::Neutral, true) => 30.0,
+            (Variant::Neutral, false) => 90.0,
+            (Variant::TonalSpot, true) => Self::t_min_c(palette, 35.0, 93.0),
+            (Variant::TonalSpot, false) => Self::t_max_c(palette, 0.0, 90.0),
+            (Variant::Expressive, true) => Self::t_max_c(palette, 30.0, 93.0),
+            (Variant::Expressive, false) => {
+                Self::t_max_c(palette, 78.0, if cyan { 88.0 } else { 90.0 })
+            }
+            (_, true) => Self::t_min_c(palette, 66.0, 93.0),
+            (_, false) => Self::t_max_c(palette, 66.0, if cyan { 88.0 } else { 93.0 }),
+        });
+    }
+
+    pub fn secondary_tone(s: &DynamicScheme) -> Option<f64> {
+        if !_is_revised(s) {
+            return None;
+        }
+        let palette = &s.secondary_palette;
+        if s.platform == Platform::Watch {
+            return Some(match s.variant {
+                Variant::Neutral => 90.0,
+                _ => Self::t_max_c(palette, 0.0, 90.0),
+            });
+        }
+        return Some(match (&s.variant, s.is_dark) {
+            (Variant::Neutral, true) => Self::t_min_c(palette, 0.0, 98.0),
+            (Variant::Vibrant, true) => Self::t_max_c(palette, 0.0, 90.0),
+            (Variant::Vibrant, false) => Self::t_max_c(palette, 0.0, 98.0),
+            (_, true) => 80.0,
+            (_, false) => Self::t_max_c(palette, 0.0, 100.0),
+        });
+    }
+
+    pub fn secondary_container_tone(s: &DynamicScheme) -> Option<f64> {
+        if !_is_revised(s) {
+            return None;
+        }
+        if s.platform == Platform::Watch {
+            return Some(30.0);
+        }
+        let palette = &s.secondary_palette;
+        return Some(match (&s.variant, s.is_dark) {
+            (Variant::Vibrant, true) => Self::t_min_c(palette, 30.0, 40.0),
+            (Variant::Vibrant, false) => Self::t_max_c(palette, 84.0, 90.0),
+            (Variant::Expressive, true) => 15.0,
+            (Variant::Expressive, false) => Self::t_max_c(palette, 90.0, 95.0),
+            (_, true) => 25.0,
+            (_, false) => 90.0,
+        });
+    }
+
+    pub fn tertiary_tone(s: &DynamicScheme) -> Option<f64> {
+        if !_is_revised(s) {
+            return None;
+        }
+        let palette = &s.tertiary_palette;
+        if s.platform == Platform::Watch {
+            return Some(match s.variant {
+                Variant::TonalSpot => Self::t_max_c(palette, 0.0, 90.0),
+                _ => Self::t_max_c(palette, 0.0, 100.0),
+            });
+        }
+        return Some(match (&s.variant, s.is_dark) {
+            (Variant::Expressive | Variant::Vibrant, _) => {
+                let upper = if _is_cyan(palette.hue) {
+                    88.0
+                } else if s.is_dark {
+                    98.0
+                } else {
+                    100.0
+                };
+                Self::t_max_c(palette, 0.0, upper)
+            }
+            (_, true) => Self::t_max_c(palette, 0.0, 98.0),
+            (_, false) => Self::t_max_c(palette, 0.0, 100.0),
+        });
+    }
+
+    pub fn tertiary_container_tone(s: &DynamicScheme) -> Option<f64> {
+        if !_is_revised(s) {
+            return None;
+        }
+        let palette = &s.tertiary_palette;
+        if s.platform == Platform::Watch {
+            return Some(match s.variant {
+                Variant::TonalSpot => Self::t_max_c(palette, 0.0, 90.0),
+                _ => Self::t_max_c(palette, 0.0, 100.0),
+            });
+        }
+        let upper = if s.is_dark { 93.0 } else { 100.0 };
+        return Some(match (&s.variant, s.is_dark) {
+            (Variant::Neutral, true) => Self::t_max_c(palette, 0.0, 93.0),
+            (Variant::Neutral, false) => Self::t_max_c(palette, 0.0, 96.0),
+            (Variant::TonalSpot, _) => Self::t_max_c(palette, 0.0, upper),
+            (Variant::Expressive, _) => Self::t_max_c(
+                palette,
+                75.0,
+                if _is_cyan(palette.hue) { 88.0 } else { upper },
+            ),
+            (_, true) => Self::t_max_c(palette, 0.0, 93.0),
+            (_, false) => Self::t_max_c(palette, 72.0, 100.0),
+        });
+    }
+
+    /// The tone of the error role, or of a role following its rules with
+    /// [palette] instead of the error palette.
+    pub fn error_tone(s: &DynamicScheme, palette: &TonalPalette) -> Option<f64> {
+        if !_is_revised(s) {
+            return None;
+        }
+        if s.platform == Platform::Watch {
+            return Some(Self::t_min_c(palette, 0.0, 100.0));
+        }
+        return Some(if s.is_dark {
+            Self::t_min_c(palette, 0.0, 98.0)
+        } else {
+            Self::t_max_c(palette, 0.0, 100.0)
+        });
+    }
+
+    /// The tone of the error container role, or of a role following its rules
+    /// with [palette] instead of the error palette.
+    pub fn error_container_tone(s: &DynamicScheme, palette: &TonalPalette) -> Option<f64> {
+        if !_is_revised(s) {
+            return None;
+        }
+        if s.platform == Platform::Watch {
+            return Some(30.0);
+        }
+        return Some(if s.is_dark {
+            Self::t_min_c(palette, 30.0, 93.0)
+        } else {
+            Self::t_max_c(palette, 0.0, 90.0)
+        });
+    }
+}
+
+/// Whether the tones of [scheme] follow the 2025 specification, which only
+/// revises some variants.
+fn _is_revised(scheme: &DynamicScheme) -> bool {
+    return scheme.spec_version != SpecVersion::Spec2021
+        && matches!(
+            scheme.variant,
+            Variant::Neutral | Variant::TonalSpot | Variant::Vibrant | Variant::Expressive
+        );
+}
+
+/// The tone of a surface of a light [scheme]: [yellow] if its neutral
+/// palette is yellow, which looks dirty when darker, [vibrant] for
+/// [Variant::Vibrant], and [other] otherwise.
+fn _light_surface_tone(scheme: &DynamicScheme, yellow: f64, vibrant: f64, other: f64) -> f64 {
+    if _is_yellow(scheme.neutral_palette.hue) {
+        return yellow;
+    }
+    if scheme.variant == Variant::Vibrant {
+        return vibrant;
+    }
+    return other;
+}
+
+fn _is_blue(hue: f64) -> bool {
+    (250.0..270.0).contains(&hue)
+}
+
+fn _is_yellow(hue: f64) -> bool {
+    (105.0..125.0).contains(&hue)
+}
+
+fn _is_cyan(hue: f64) -> bool {
+    (170.0..207.0).contains(&hue)
+}
+
+/// The value paired with the hue range [source_color_hct] falls in, or its hue
+/// if it falls in none.
+fn _piecewise_value(source_color_hct: &Hct, hue_breakpoints: &[f64], values: &[f64]) -> f64 {
+    let source_hue = source_color_hct.get_hue();
+    for (range, value) in hue_breakpoints.windows(2).zip(values) {
+        if range[0] <= source_hue && source_hue < range[1] {
+            return MathUtils::sanitize_degrees_double(*value);
+        }
+    }
+    return source_hue;
+}
+
+/// Rotates the hue of [source_color_hct] by the rotation paired with the hue
+/// range it falls in. Unlike [DynamicScheme::get_rotated_hue], ranges include
+/// their start.
+fn _rotated_hue(source_color_hct: &Hct, hue_breakpoints: &[f64], rotations: &[f64]) -> f64 {
+    let rotation = if hue_breakpoints.len() < 2 || rotations.is_empty() {
+        0.0
+    } else {
+        _piecewise_value(source_color_hct, hue_breakpoints, rotations)
+    };
+    return MathUtils::sanitize_degrees_double(source_color_hct.get_hue() + rotation);
+}
+
+fn _expressive_neutral_hue(source_color_hct: &Hct) -> f64 {
+    return _rotated_hue(
+        source_color_hct,
+        &[0.0, 71.0, 124.0, 253.0, 278.0, 300.0, 360.0],
+        &[10.0, 0.0, 10.0, 0.0, 10.0, 0.0],
+    );
+}
+
+fn _expressive_neutral_chroma(source_color_hct: &Hct, is_dark: bool, platform: Platform) -> f64 {
+    let neutral_hue = _expressive_neutral_hue(source_color_hct);
+    if platform != Platform::Phone {
+        return 12.0;
+    }
+    if !is_dark {
+        return 18.0;
+    }
+    return if _is_yellow(neutral_hue) { 6.0 } else { 14.0 };
+}
+
+fn _vibrant_neutral_hue(source_color_hct: &Hct) -> f64 {
+    return _rotated_hue(
+        source_color_hct,
+        &[0.0, 38.0, 105.0, 140.0, 333.0, 360.0],
+        &[-14.0, 10.0, -14.0, 10.0, -14.0],
+    );
+}
+
+fn _vibrant_neutral_chroma(source_color_hct: &Hct, platform: Platform) -> f64 {
+    let neutral_hue = _vibrant_neutral_hue(source_color_hct);
+    if platform == Platform::Phone || _is_blue(neutral_hue) {
+        return 28.0;
+    }
+    return 20.0;
+}
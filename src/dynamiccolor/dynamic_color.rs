@@ -1,8 +1,14 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+};
 
 use crate::{
-    contrast::contrast::Contrast, dynamiccolor::src::tone_delta_pair::TonePolarity, hct::hct::Hct,
-    palettes::tonal_palette::TonalPalette, utils::math_utils::MathUtils,
+    contrast::contrast::Contrast,
+    dynamiccolor::src::tone_delta_pair::{DeltaConstraint, TonePolarity},
+    hct::hct::Hct,
+    palettes::tonal_palette::TonalPalette,
+    utils::{argb::Argb, math_utils::MathUtils},
 };
 
 use super::{
@@ -11,14 +17,14 @@ use super::{
 };
 pub struct DynamicColor {
     pub name: String,
-    pub palette: Box<dyn Fn(&DynamicScheme) -> &TonalPalette>,
-    pub tone: Box<dyn Fn(&DynamicScheme) -> f64>,
+    pub palette: Box<dyn Fn(&DynamicScheme) -> &TonalPalette + Send + Sync>,
+    pub tone: Box<dyn Fn(&DynamicScheme) -> f64 + Send + Sync>,
     pub is_background: bool,
-    pub background: Option<Box<dyn Fn(&DynamicScheme) -> DynamicColor>>,
-    pub second_background: Option<Box<dyn Fn(&DynamicScheme) -> DynamicColor>>,
+    pub background: Option<Box<dyn Fn(&DynamicScheme) -> DynamicColor + Send + Sync>>,
+    pub second_background: Option<Box<dyn Fn(&DynamicScheme) -> DynamicColor + Send + Sync>>,
     pub contrast_curve: Option<ContrastCurve>,
-    pub tone_delta_pair: Option<Box<dyn Fn(&DynamicScheme) -> ToneDeltaPair>>,
-    _hct_cache: HashMap<DynamicScheme, Hct>,
+    pub tone_delta_pair: Option<Box<dyn Fn(&DynamicScheme) -> ToneDeltaPair + Send + Sync>>,
+    _hct_cache: Mutex<HashMap<DynamicScheme, Hct>>,
 }
 impl DynamicColor {
     /// The base (explicit) constructor for [DynamicColor].
@@ -43,13 +49,13 @@ impl DynamicColor {
     /// constructed.
     pub fn new(
         name: &str,
-        palette: Box<dyn Fn(&DynamicScheme) -> &TonalPalette>,
-        tone: Box<dyn Fn(&DynamicScheme) -> f64>,
+        palette: Box<dyn Fn(&DynamicScheme) -> &TonalPalette + Send + Sync>,
+        tone: Box<dyn Fn(&DynamicScheme) -> f64 + Send + Sync>,
         is_background: bool,
-        background: Option<Box<dyn Fn(&DynamicScheme) -> DynamicColor>>,
-        second_background: Option<Box<dyn Fn(&DynamicScheme) -> DynamicColor>>,
+        background: Option<Box<dyn Fn(&DynamicScheme) -> DynamicColor + Send + Sync>>,
+        second_background: Option<Box<dyn Fn(&DynamicScheme) -> DynamicColor + Send + Sync>>,
         contrast_curve: Option<ContrastCurve>,
-        tone_delta_pair: Option<Box<dyn Fn(&DynamicScheme) -> ToneDeltaPair>>,
+        tone_delta_pair: Option<Box<dyn Fn(&DynamicScheme) -> ToneDeltaPair + Send + Sync>>,
     ) -> Self {
         Self {
             name: name.to_string(),
@@ -60,7 +66,7 @@ impl DynamicColor {
             second_background,
             contrast_curve,
             tone_delta_pair,
-            _hct_cache: HashMap::new(),
+            _hct_cache: Mutex::new(HashMap::new()),
         }
     }
 
@@ -88,13 +94,13 @@ impl DynamicColor {
     /// constructed.
     pub fn from_palette(
         name: Option<&str>,
-        palette: Box<dyn Fn(&DynamicScheme) -> &TonalPalette>,
-        tone: Box<dyn Fn(&DynamicScheme) -> f64>,
+        palette: Box<dyn Fn(&DynamicScheme) -> &TonalPalette + Send + Sync>,
+        tone: Box<dyn Fn(&DynamicScheme) -> f64 + Send + Sync>,
         is_background: Option<bool>,
-        background: Option<Box<dyn Fn(&DynamicScheme) -> DynamicColor>>,
-        second_background: Option<Box<dyn Fn(&DynamicScheme) -> DynamicColor>>,
+        background: Option<Box<dyn Fn(&DynamicScheme) -> DynamicColor + Send + Sync>>,
+        second_background: Option<Box<dyn Fn(&DynamicScheme) -> DynamicColor + Send + Sync>>,
         contrast_curve: Option<ContrastCurve>,
-        tone_delta_pair: Option<Box<dyn Fn(&DynamicScheme) -> ToneDeltaPair>>,
+        tone_delta_pair: Option<Box<dyn Fn(&DynamicScheme) -> ToneDeltaPair + Send + Sync>>,
     ) -> Self {
         Self {
             name: name.unwrap_or("").to_string(),
@@ -105,17 +111,22 @@ impl DynamicColor {
             second_background,
             contrast_curve,
             tone_delta_pair,
-            _hct_cache: HashMap::new(),
+            _hct_cache: Mutex::new(HashMap::new()),
         }
     }
 
-    /// Return a ARGB integer (i.e. a hex code).
-
+    /// Return an ARGB color (i.e. a hex code).
+    ///
     /// [scheme] Defines the conditions of the user interface, for example,
     /// whether or not it is dark mode or light mode, and what the desired
     /// contrast level is.
-    pub fn get_argb(&mut self, scheme: &DynamicScheme) -> i64 {
-        return self.get_hct(scheme).to_int();
+    pub fn get_color(&self, scheme: &DynamicScheme) -> Argb {
+        return self.get_hct(scheme).to_argb();
+    }
+
+    #[deprecated = "use `DynamicColor::get_color` instead"]
+    pub fn get_argb(&self, scheme: &DynamicScheme) -> i64 {
+        return self.get_color(scheme).into();
     }
 
     /// Return a color, expressed in the HCT color space, that this
@@ -124,17 +135,26 @@ impl DynamicColor {
     /// [scheme] Defines the conditions of the user interface, for example,
     /// whether or not it is dark mode or light mode, and what the desired
     /// contrast level is.
-    pub fn get_hct(&mut self, scheme: &DynamicScheme) -> Hct {
-        let cached_answer = self._hct_cache.get(scheme);
+    pub fn get_hct(&self, scheme: &DynamicScheme) -> Hct {
+        let cached_answer = self
+            ._hct_cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(scheme)
+            .cloned();
         match cached_answer {
-            Some(cached_answer) => cached_answer.clone(),
+            Some(cached_answer) => cached_answer,
             None => {
                 let tone = self.get_tone(scheme);
                 let answer = (self.palette)(scheme).get_hct(tone);
-                if self._hct_cache.len() > 4 {
-                    self._hct_cache.clear();
+                let mut hct_cache = self
+                    ._hct_cache
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner);
+                if hct_cache.len() > 4 {
+                    hct_cache.clear();
                 }
-                self._hct_cache.insert(scheme.clone(), answer.clone());
+                hct_cache.insert(scheme.clone(), answer.clone());
                 return answer;
             }
         }
@@ -147,12 +167,39 @@ impl DynamicColor {
     /// whether or not it is dark mode or light mode, and what the desired
     /// contrast level is.
     pub fn get_tone(&self, scheme: &DynamicScheme) -> f64 {
+        return self.get_tone_cached(scheme, &mut HashMap::new());
+    }
+
+    /// Same as [get_tone], but reads and records the tones of named colors in
+    /// [tones], so a background shared by several colors of [scheme] is only
+    /// solved once.
+    ///
+    /// Colors are identified by [name], as in [ToneDeltaPair] resolution.
+    pub(crate) fn get_tone_cached(
+        &self,
+        scheme: &DynamicScheme,
+        tones: &mut HashMap<String, f64>,
+    ) -> f64 {
+        if let Some(tone) = tones.get(&self.name) {
+            return *tone;
+        }
+        let tone = self._solve_tone(scheme, tones);
+        if !self.name.is_empty() {
+            tones.insert(self.name.clone(), tone);
+        }
+        return tone;
+    }
+
+    fn _solve_tone(&self, scheme: &DynamicScheme, tones: &mut HashMap<String, f64>) -> f64 {
         let decreasing_contrast = scheme.contrast_level < 0.0;
 
         match &self.tone_delta_pair {
             // Case 1: dual foreground, pair of colors with delta constraint.
             Some(tone_delta_pair) => {
                 let pair = (tone_delta_pair)(scheme);
+                if let Some(constraint) = pair.constraint {
+                    return self._solve_constrained_tone(scheme, &pair, constraint, tones);
+                }
                 let role_a = pair.role_a;
                 let role_b = pair.role_b;
                 let delta = pair.delta;
@@ -161,7 +208,7 @@ impl DynamicColor {
 
                 let background = self.background.as_ref().unwrap();
                 let bg = (background)(scheme);
-                let bg_tone = bg.get_tone(scheme);
+                let bg_tone = bg.get_tone_cached(scheme, tones);
 
                 let a_is_nearer = polarity == TonePolarity::Nearer
                     || (polarity == TonePolarity::Lighter && !scheme.is_dark)
@@ -269,7 +316,7 @@ impl DynamicColor {
                 }
 
                 let background = self.background.as_ref().unwrap();
-                let bg_tone = (background)(scheme).get_tone(scheme);
+                let bg_tone = (background)(scheme).get_tone_cached(scheme, tones);
                 let contrast_curve = self.contrast_curve.as_ref().unwrap();
                 let desired_ratio = contrast_curve.get(scheme.contrast_level);
 
@@ -298,8 +345,8 @@ impl DynamicColor {
 
                     let background = self.background.as_ref().unwrap();
                     let second_background = self.second_background.as_ref().unwrap();
-                    let bg_tone1 = background(scheme).get_tone(scheme);
-                    let bg_tone2 = second_background(scheme).get_tone(scheme);
+                    let bg_tone1 = background(scheme).get_tone_cached(scheme, tones);
+                    let bg_tone2 = second_background(scheme).get_tone_cached(scheme, tones);
 
                     let upper = bg_tone1.max(bg_tone2);
                     let lower = bg_tone1.min(bg_tone2);
@@ -311,36 +358,27 @@ impl DynamicColor {
                     }
 
                     // The darkest light tone that satisfies the desired ratio,
-                    // or -1 if such ratio cannot be reached.
-                    let light_option = Contrast::lighter(upper, desired_ratio);
+                    // if such ratio can be reached.
+                    let light_option = Contrast::try_lighter(upper, desired_ratio).ok();
 
                     // The lightest dark tone that satisfies the desired ratio,
-                    // or -1 if such ratio cannot be reached.
-                    let dark_option = Contrast::darker(lower, desired_ratio);
+                    // if such ratio can be reached.
+                    let dark_option = Contrast::try_darker(lower, desired_ratio).ok();
 
                     // Tones suitable for the foreground.
-                    let mut availables: Vec<f64> = Vec::new();
-                    if light_option != -1.0 {
-                        availables.push(light_option);
-                    }
-                    if dark_option != -1.0 {
-                        availables.push(dark_option);
-                    }
+                    let availables: Vec<f64> =
+                        [light_option, dark_option].into_iter().flatten().collect();
 
                     let prefers_light = Self::tone_prefers_light_foreground(bg_tone1)
                         || Self::tone_prefers_light_foreground(bg_tone2);
 
                     if prefers_light {
-                        return if light_option < 0.0 {
-                            100.0
-                        } else {
-                            light_option
-                        };
+                        return light_option.unwrap_or(100.0);
                     }
                     if availables.len() == 1 {
                         return availables[0];
                     }
-                    return if dark_option < 0.0 { 0.0 } else { dark_option };
+                    return dark_option.unwrap_or(0.0);
                 }
 
                 return answer;
@@ -348,6 +386,79 @@ impl DynamicColor {
         };
     }
 
+    /// Solves a color whose [ToneDeltaPair] has a [DeltaConstraint]: the other
+    /// role is solved first, then this color is moved to satisfy [constraint]
+    /// against it, and finally to contrast with its background.
+    ///
+    /// Contrast wins over [constraint] when they disagree.
+    fn _solve_constrained_tone(
+        &self,
+        scheme: &DynamicScheme,
+        pair: &ToneDeltaPair,
+        constraint: DeltaConstraint,
+        tones: &mut HashMap<String, f64>,
+    ) -> f64 {
+        let am_role_a = self.name == pair.role_a.name;
+        let reference = if am_role_a {
+            &pair.role_b
+        } else {
+            &pair.role_a
+        };
+        let reference_tone = reference.get_tone_cached(scheme, tones);
+
+        let a_is_lighter = match pair.polarity {
+            TonePolarity::Lighter => true,
+            TonePolarity::Darker => false,
+            TonePolarity::Nearer => !scheme.is_dark,
+            TonePolarity::Farther => scheme.is_dark,
+        };
+        // How much lighter than the other role this color should be.
+        let relative_delta = if a_is_lighter == am_role_a {
+            pair.delta
+        } else {
+            -pair.delta
+        };
+        let bound = reference_tone + relative_delta;
+
+        let tone = (self.tone)(scheme);
+        let mut answer = match constraint {
+            DeltaConstraint::Exact => bound,
+            DeltaConstraint::Nearer if relative_delta > 0.0 => tone.clamp(reference_tone, bound),
+            DeltaConstraint::Nearer => tone.clamp(bound, reference_tone),
+            DeltaConstraint::Farther if relative_delta > 0.0 => tone.max(bound),
+            DeltaConstraint::Farther => tone.min(bound),
+        };
+        answer = MathUtils::clamp_double(0.0, 100.0, answer);
+
+        if let (Some(background), Some(contrast_curve)) = (&self.background, &self.contrast_curve) {
+            let bg_tone = background(scheme).get_tone_cached(scheme, tones);
+            let desired_ratio = contrast_curve.get(scheme.contrast_level);
+            if Contrast::ratio_of_tones(bg_tone, answer) < desired_ratio {
+                answer = DynamicColor::foreground_tone(bg_tone, desired_ratio);
+            } else if scheme.contrast_level < 0.0 {
+                // Reduced contrast is a preference, so it only goes as far as
+                // [constraint] allows.
+                let reduced = DynamicColor::foreground_tone(bg_tone, desired_ratio);
+                answer = match constraint {
+                    DeltaConstraint::Exact => answer,
+                    DeltaConstraint::Nearer | DeltaConstraint::Farther => {
+                        if (reduced - bound) * relative_delta >= 0.0 {
+                            reduced
+                        } else {
+                            bound
+                        }
+                    }
+                };
+            }
+        }
+
+        // Avoids the 50-59 awkward zone, moving away from the other role.
+        if self.is_background && 50.0 <= answer && answer < 60.0 {
+            answer = if relative_delta > 0.0 { 60.0 } else { 49.0 };
+        }
+        return answer;
+    }
+
     /// Given a background tone, find a foreground tone, while ensuring they reach
     /// a contrast ratio that is as close to [ratio] as possible.
     ///
@@ -1,19 +1,48 @@
 use std::hash::Hash;
 
-use crate::{hct::hct::Hct, palettes::tonal_palette::TonalPalette, utils::math_utils::MathUtils};
+use crate::{
+    error::error::Error,
+    hct::hct::Hct,
+    palettes::tonal_palette::TonalPalette,
+    scheme::{
+        scheme_content::SchemeContent, scheme_expressive::SchemeExpressive,
+        scheme_fidelity::SchemeFidelity, scheme_fruit_salad::SchemeFruitSalad,
+        scheme_monochrome::SchemeMonochrome, scheme_neutral::SchemeNeutral,
+        scheme_rainbow::SchemeRainbow, scheme_tonal_spot::SchemeTonalSpot,
+        scheme_vibrant::SchemeVibrant,
+    },
+    utils::{argb::Argb, math_utils::MathUtils},
+};
 
 use super::{
-    dynamic_color::DynamicColor, material_dynamic_colors::MaterialDynamicColors, variant::Variant,
+    color_role::ColorRole,
+    color_spec_2025::ColorSpec2025,
+    dynamic_color::DynamicColor,
+    material_dynamic_colors::MaterialDynamicColors,
+    resolved_scheme::ResolvedScheme,
+    spec_version::{Platform, SpecVersion},
+    variant::Variant,
 };
 
 /// Constructed by a set of values representing the current UI state (such as
 /// whether or not its dark theme, what the theme style is, etc.), and
 /// provides a set of [TonalPalette]s that can create colors that fit in
 /// with the theme style. Used by [DynamicColor] to resolve into a color.\
+///
+/// With the `serde` feature, a scheme is serialized as its
+/// `source_color_argb`, `variant`, `is_dark`, `contrast_level` and palettes.
+/// On deserialization, [source_color_hct] is recomputed from the source color
+/// and missing success, warning and info palettes, spec version and platform
+/// take their defaults.
 #[derive(Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "DynamicSchemeData", into = "DynamicSchemeData")
+)]
 pub struct DynamicScheme {
-    /// The source color of the theme as an ARGB integer.
-    pub source_color_argb: i64,
+    /// The source color of the theme as an ARGB color.
+    pub source_color_argb: Argb,
 
     /// The source color of the theme in HCT.
     pub source_color_hct: Hct,
@@ -28,6 +57,12 @@ pub struct DynamicScheme {
     /// standard (i.e. the design as spec'd), and 1 represents maximum contrast.
     pub contrast_level: f64,
 
+    /// The version of the specification the palettes and roles follow.
+    pub spec_version: SpecVersion,
+
+    /// The kind of device the scheme is displayed on.
+    pub platform: Platform,
+
     /// Given a tone, produces a color. Hue and chroma of the color are specified
     /// in the design specification of the variant. Usually colorful.
     pub primary_palette: TonalPalette,
@@ -53,7 +88,92 @@ pub struct DynamicScheme {
 
     /// Given a tone, produces a reddish, colorful, color.
     pub error_palette: TonalPalette,
+
+    /// Given a tone, produces a greenish, colorful, color. Used for the
+    /// success roles.
+    pub success_palette: TonalPalette,
+
+    /// Given a tone, produces a yellowish, colorful, color. Used for the
+    /// warning roles.
+    pub warning_palette: TonalPalette,
+
+    /// Given a tone, produces a bluish, colorful, color. Used for the info
+    /// roles.
+    pub info_palette: TonalPalette,
+}
+/// The serialized form of a [DynamicScheme].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct DynamicSchemeData {
+    source_color_argb: Argb,
+    variant: Variant,
+    is_dark: bool,
+    contrast_level: f64,
+    #[serde(default)]
+    spec_version: SpecVersion,
+    #[serde(default)]
+    platform: Platform,
+    primary_palette: TonalPalette,
+    secondary_palette: TonalPalette,
+    tertiary_palette: TonalPalette,
+    neutral_palette: TonalPalette,
+    neutral_variant_palette: TonalPalette,
+    error_palette: TonalPalette,
+    #[serde(default = "DynamicScheme::default_success_palette")]
+    success_palette: TonalPalette,
+    #[serde(default = "DynamicScheme::default_warning_palette")]
+    warning_palette: TonalPalette,
+    #[serde(default = "DynamicScheme::default_info_palette")]
+    info_palette: TonalPalette,
 }
+
+#[cfg(feature = "serde")]
+impl From<DynamicScheme> for DynamicSchemeData {
+    fn from(scheme: DynamicScheme) -> Self {
+        DynamicSchemeData {
+            source_color_argb: scheme.source_color_argb,
+            variant: scheme.variant,
+            is_dark: scheme.is_dark,
+            contrast_level: scheme.contrast_level,
+            spec_version: scheme.spec_version,
+            platform: scheme.platform,
+            primary_palette: scheme.primary_palette,
+            secondary_palette: scheme.secondary_palette,
+            tertiary_palette: scheme.tertiary_palette,
+            neutral_palette: scheme.neutral_palette,
+            neutral_variant_palette: scheme.neutral_variant_palette,
+            error_palette: scheme.error_palette,
+            success_palette: scheme.success_palette,
+            warning_palette: scheme.warning_palette,
+            info_palette: scheme.info_palette,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<DynamicSchemeData> for DynamicScheme {
+    fn from(data: DynamicSchemeData) -> Self {
+        DynamicScheme {
+            source_color_argb: data.source_color_argb,
+            source_color_hct: Hct::from_argb(data.source_color_argb),
+            variant: data.variant,
+            is_dark: data.is_dark,
+            contrast_level: data.contrast_level,
+            spec_version: data.spec_version,
+            platform: data.platform,
+            primary_palette: data.primary_palette,
+            secondary_palette: data.secondary_palette,
+            tertiary_palette: data.tertiary_palette,
+            neutral_palette: data.neutral_palette,
+            neutral_variant_palette: data.neutral_variant_palette,
+            error_palette: data.error_palette,
+            success_palette: data.success_palette,
+            warning_palette: data.warning_palette,
+            info_palette: data.info_palette,
+        }
+    }
+}
+
 impl PartialEq for DynamicScheme {
     fn eq(&self, other: &Self) -> bool {
         self.source_color_argb == other.source_color_argb
@@ -61,12 +181,17 @@ impl PartialEq for DynamicScheme {
             && self.variant == other.variant
             && self.is_dark == other.is_dark
             && self.contrast_level == other.contrast_level
+            && self.spec_version == other.spec_version
+            && self.platform == other.platform
             && self.primary_palette == other.primary_palette
             && self.secondary_palette == other.secondary_palette
             && self.tertiary_palette == other.tertiary_palette
             && self.neutral_palette == other.neutral_palette
             && self.neutral_variant_palette == other.neutral_variant_palette
             && self.error_palette == other.error_palette
+            && self.success_palette == other.success_palette
+            && self.warning_palette == other.warning_palette
+            && self.info_palette == other.info_palette
     }
 }
 impl Eq for DynamicScheme {}
@@ -77,16 +202,22 @@ impl Hash for DynamicScheme {
         self.variant.hash(state);
         self.is_dark.hash(state);
         self.contrast_level.to_ne_bytes().hash(state);
+        self.spec_version.hash(state);
+        self.platform.hash(state);
         self.primary_palette.hash(state);
         self.secondary_palette.hash(state);
         self.tertiary_palette.hash(state);
         self.neutral_palette.hash(state);
         self.neutral_variant_palette.hash(state);
         self.error_palette.hash(state);
+        self.success_palette.hash(state);
+        self.warning_palette.hash(state);
+        self.info_palette.hash(state);
     }
 }
 
 impl DynamicScheme {
+    #[deprecated = "use `DynamicScheme::from_palettes` instead"]
     pub fn new(
         source_color_argb: i64,
         variant: Variant,
@@ -97,27 +228,205 @@ impl DynamicScheme {
         tertiary_palette: TonalPalette,
         neutral_palette: TonalPalette,
         neutral_variant_palette: TonalPalette,
+    ) -> Self {
+        Self::from_palettes(
+            Argb(source_color_argb as u32),
+            variant,
+            contrast_level,
+            is_dark,
+            primary_palette,
+            secondary_palette,
+            tertiary_palette,
+            neutral_palette,
+            neutral_variant_palette,
+        )
+    }
+
+    pub fn from_palettes(
+        source_color_argb: Argb,
+        variant: Variant,
+        contrast_level: Option<f64>,
+        is_dark: bool,
+        primary_palette: TonalPalette,
+        secondary_palette: TonalPalette,
+        tertiary_palette: TonalPalette,
+        neutral_palette: TonalPalette,
+        neutral_variant_palette: TonalPalette,
     ) -> Self {
         Self {
             source_color_argb,
-            source_color_hct: Hct::from_int(source_color_argb),
+            source_color_hct: Hct::from_argb(source_color_argb),
             variant,
             is_dark,
             contrast_level: contrast_level.unwrap_or(0.0),
+            spec_version: SpecVersion::Spec2021,
+            platform: Platform::Phone,
             primary_palette,
             secondary_palette,
             tertiary_palette,
             neutral_palette,
             neutral_variant_palette,
-            error_palette: TonalPalette::of(25.0, 84.0),
+            error_palette: Self::default_error_palette(),
+            success_palette: Self::default_success_palette(),
+            warning_palette: Self::default_warning_palette(),
+            info_palette: Self::default_info_palette(),
+        }
+    }
+
+    /// The error palette of schemes that don't specify one.
+    pub fn default_error_palette() -> TonalPalette {
+        TonalPalette::of(25.0, 84.0)
+    }
+
+    /// The success palette of schemes that don't specify one.
+    pub fn default_success_palette() -> TonalPalette {
+        TonalPalette::of(145.0, 48.0)
+    }
+
+    /// The warning palette of schemes that don't specify one.
+    pub fn default_warning_palette() -> TonalPalette {
+        TonalPalette::of(80.0, 60.0)
+    }
+
+    /// The info palette of schemes that don't specify one.
+    pub fn default_info_palette() -> TonalPalette {
+        TonalPalette::of(265.0, 48.0)
+    }
+
+    /// Returns this scheme with its error palette replaced by [palette].
+    pub fn with_error_palette(mut self, palette: TonalPalette) -> DynamicScheme {
+        self.error_palette = palette;
+        self
+    }
+
+    /// Returns this scheme with its success palette replaced by [palette].
+    pub fn with_success_palette(mut self, palette: TonalPalette) -> DynamicScheme {
+        self.success_palette = palette;
+        self
+    }
+
+    /// Returns this scheme with its warning palette replaced by [palette].
+    pub fn with_warning_palette(mut self, palette: TonalPalette) -> DynamicScheme {
+        self.warning_palette = palette;
+        self
+    }
+
+    /// Returns this scheme with its info palette replaced by [palette].
+    pub fn with_info_palette(mut self, palette: TonalPalette) -> DynamicScheme {
+        self.info_palette = palette;
+        self
+    }
+
+    /// Creates the scheme of [variant] for [source_color_hct].
+    ///
+    /// Same as constructing the matching scheme, e.g. [SchemeTonalSpot] for
+    /// [Variant::TonalSpot], and taking its `dynamic_scheme`. Use
+    /// [SchemeBuilder] to also override palettes.
+    ///
+    /// Follows [SpecVersion::Spec2021] on a [Platform::Phone], see [from_spec].
+    pub fn from_variant(
+        variant: Variant,
+        source_color_hct: Hct,
+        is_dark: bool,
+        contrast_level: f64,
+    ) -> DynamicScheme {
+        return Self::from_spec(
+            variant,
+            source_color_hct,
+            is_dark,
+            contrast_level,
+            SpecVersion::Spec2021,
+            Platform::Phone,
+        );
+    }
+
+    /// Creates the scheme of [variant] for [source_color_hct], following
+    /// [spec_version] on [platform].
+    ///
+    /// [SpecVersion::Spec2025] replaces the palettes of the variants it
+    /// revises; other variants keep their [SpecVersion::Spec2021] palettes.
+    pub fn from_spec(
+        variant: Variant,
+        source_color_hct: Hct,
+        is_dark: bool,
+        contrast_level: f64,
+        spec_version: SpecVersion,
+        platform: Platform,
+    ) -> DynamicScheme {
+        let (hct, dark, contrast) = (source_color_hct.clone(), is_dark, contrast_level);
+        let mut scheme = match variant {
+            Variant::Monochrome => SchemeMonochrome::new(hct, dark, contrast).dynamic_scheme,
+            Variant::Neutral => SchemeNeutral::new(hct, dark, contrast).dynamic_scheme,
+            Variant::TonalSpot => SchemeTonalSpot::new(hct, dark, contrast).dynamic_scheme,
+            Variant::Vibrant => SchemeVibrant::new(hct, dark, contrast).dynamic_scheme,
+            Variant::Expressive => SchemeExpressive::new(hct, dark, contrast).dynamic_scheme,
+            Variant::Content => SchemeContent::new(hct, dark, contrast).dynamic_scheme,
+            Variant::Fidelity => SchemeFidelity::new(hct, dark, contrast).dynamic_scheme,
+            Variant::Rainbow => SchemeRainbow::new(hct, dark, contrast).dynamic_scheme,
+            Variant::FruitSalad => SchemeFruitSalad::new(hct, dark, contrast).dynamic_scheme,
+        };
+        scheme.spec_version = spec_version;
+        scheme.platform = platform;
+        if spec_version == SpecVersion::Spec2021 {
+            return scheme;
+        }
+
+        let source = &source_color_hct;
+        let variant = &scheme.variant;
+        if let Some(palette) = ColorSpec2025::primary_palette(variant, source, dark, platform) {
+            scheme.primary_palette = palette;
+        }
+        if let Some(palette) = ColorSpec2025::secondary_palette(variant, source, dark, platform) {
+            scheme.secondary_palette = palette;
+        }
+        if let Some(palette) = ColorSpec2025::tertiary_palette(variant, source, platform) {
+            scheme.tertiary_palette = palette;
         }
+        if let Some(palette) = ColorSpec2025::neutral_palette(variant, source, dark, platform) {
+            scheme.neutral_palette = palette;
+        }
+        if let Some(palette) =
+            ColorSpec2025::neutral_variant_palette(variant, source, dark, platform)
+        {
+            scheme.neutral_variant_palette = palette;
+        }
+        if let Some(palette) = ColorSpec2025::error_palette(variant, source, platform) {
+            scheme.error_palette = palette;
+        }
+        return scheme;
     }
 
+    /// Rotates the hue of [source_color] by the rotation paired with the hue
+    /// range it falls in.
+    ///
+    /// Panics if [hues] and [rotations] have different lengths, see
+    /// [try_get_rotated_hue].
     pub fn get_rotated_hue(source_color: &Hct, hues: &Vec<f64>, rotations: &Vec<f64>) -> f64 {
+        return Self::try_get_rotated_hue(source_color, hues, rotations)
+            .unwrap_or_else(|error| panic!("{}", error));
+    }
+
+    /// Rotates the hue of [source_color] by the rotation paired with the hue
+    /// range it falls in.
+    ///
+    /// Returns [Error::MismatchedRotations] if [hues] and [rotations] have
+    /// different lengths.
+    pub fn try_get_rotated_hue(
+        source_color: &Hct,
+        hues: &[f64],
+        rotations: &[f64],
+    ) -> Result<f64, Error> {
         let source_hue = source_color.get_hue();
-        assert!(hues.len() == rotations.len());
+        if hues.len() != rotations.len() {
+            return Err(Error::MismatchedRotations {
+                hues: hues.len(),
+                rotations: rotations.len(),
+            });
+        }
         if rotations.len() == 1 {
-            return MathUtils::sanitize_degrees_double(source_color.get_hue() + rotations[0]);
+            return Ok(MathUtils::sanitize_degrees_double(
+                source_color.get_hue() + rotations[0],
+            ));
         }
         let size: i64 = hues.len() as i64;
         let mut i: i64 = 0;
@@ -125,183 +434,471 @@ impl DynamicScheme {
             let this_hue = hues[i as usize];
             let next_hue = hues[i as usize + 1];
             if this_hue < source_hue && source_hue < next_hue {
-                return MathUtils::sanitize_degrees_double(source_hue + rotations[i as usize]);
+                return Ok(MathUtils::sanitize_degrees_double(
+                    source_hue + rotations[i as usize],
+                ));
             }
             i += 1;
         }
         // If this statement executes, something is wrong, there should have been a rotation
         // found using the arrays.
-        return source_hue;
+        return Ok(source_hue);
     }
 
-    pub fn get_hct(&self, dynamic_color: &mut DynamicColor) -> Hct {
+    pub fn get_hct(&self, dynamic_color: &DynamicColor) -> Hct {
         dynamic_color.get_hct(self)
     }
-    pub fn get_argb(&self, dynamic_color: &mut DynamicColor) -> i64 {
-        dynamic_color.get_argb(self)
+    pub fn get_color(&self, dynamic_color: &DynamicColor) -> Argb {
+        dynamic_color.get_color(self)
+    }
+    #[deprecated = "use `DynamicScheme::get_color` instead"]
+    pub fn get_argb(&self, dynamic_color: &DynamicColor) -> i64 {
+        self.get_color(dynamic_color).into()
+    }
+
+    /// The color of [role] in this scheme, resolved as
+    /// [ColorRole::for_spec] of its [spec_version].
+    pub fn get(&self, role: ColorRole) -> Argb {
+        self.get_color(&MaterialDynamicColors::for_role(
+            role.for_spec(self.spec_version),
+        ))
+    }
+
+    /// Resolves every [ColorRole] of this scheme at once.
+    ///
+    /// Faster than calling [get] for each role, since shared backgrounds are
+    /// only solved once.
+    pub fn resolve(&self) -> ResolvedScheme {
+        ResolvedScheme::from_scheme(self)
     }
 
     // Getters.
+    pub fn primary_palette_key_color(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::primary_palette_key_color())
+    }
+    pub fn secondary_palette_key_color(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::secondary_palette_key_color())
+    }
+    pub fn tertiary_palette_key_color(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::tertiary_palette_key_color())
+    }
+    pub fn neutral_palette_key_color(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::neutral_palette_key_color())
+    }
+    pub fn neutral_variant_palette_key_color(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::neutral_variant_palette_key_color())
+    }
+    pub fn background(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::background())
+    }
+    pub fn on_background(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_background())
+    }
+    pub fn surface(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::surface())
+    }
+    pub fn surface_dim(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::surface_dim())
+    }
+    pub fn surface_bright(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::surface_bright())
+    }
+    pub fn surface_container_lowest(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::surface_container_lowest())
+    }
+    pub fn surface_container_low(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::surface_container_low())
+    }
+    pub fn surface_container(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::surface_container())
+    }
+    pub fn surface_container_high(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::surface_container_high())
+    }
+    pub fn surface_container_highest(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::surface_container_highest())
+    }
+    pub fn on_surface(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_surface())
+    }
+    pub fn surface_variant(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::surface_variant())
+    }
+    pub fn on_surface_variant(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_surface_variant())
+    }
+    pub fn inverse_surface(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::inverse_surface())
+    }
+    pub fn inverse_on_surface(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::inverse_on_surface())
+    }
+    pub fn outline(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::outline())
+    }
+    pub fn outline_variant(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::outline_variant())
+    }
+    pub fn shadow(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::shadow())
+    }
+    pub fn scrim(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::scrim())
+    }
+    pub fn surface_tint(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::surface_tint())
+    }
+    pub fn primary(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::primary())
+    }
+    pub fn primary_dim(&self) -> Argb {
+        self.get(ColorRole::PrimaryDim)
+    }
+    pub fn on_primary(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_primary())
+    }
+    pub fn primary_container(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::primary_container())
+    }
+    pub fn on_primary_container(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_primary_container())
+    }
+    pub fn inverse_primary(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::inverse_primary())
+    }
+    pub fn secondary(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::secondary())
+    }
+    pub fn secondary_dim(&self) -> Argb {
+        self.get(ColorRole::SecondaryDim)
+    }
+    pub fn on_secondary(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_secondary())
+    }
+    pub fn secondary_container(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::secondary_container())
+    }
+    pub fn on_secondary_container(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_secondary_container())
+    }
+    pub fn tertiary(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::tertiary())
+    }
+    pub fn tertiary_dim(&self) -> Argb {
+        self.get(ColorRole::TertiaryDim)
+    }
+    pub fn on_tertiary(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_tertiary())
+    }
+    pub fn tertiary_container(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::tertiary_container())
+    }
+    pub fn on_tertiary_container(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_tertiary_container())
+    }
+    pub fn error(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::error())
+    }
+    pub fn error_dim(&self) -> Argb {
+        self.get(ColorRole::ErrorDim)
+    }
+    pub fn on_error(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_error())
+    }
+    pub fn error_container(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::error_container())
+    }
+    pub fn on_error_container(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_error_container())
+    }
+    pub fn success(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::success())
+    }
+    pub fn on_success(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_success())
+    }
+    pub fn success_container(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::success_container())
+    }
+    pub fn on_success_container(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_success_container())
+    }
+    pub fn warning(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::warning())
+    }
+    pub fn on_warning(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_warning())
+    }
+    pub fn warning_container(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::warning_container())
+    }
+    pub fn on_warning_container(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_warning_container())
+    }
+    pub fn info(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::info())
+    }
+    pub fn on_info(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_info())
+    }
+    pub fn info_container(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::info_container())
+    }
+    pub fn on_info_container(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_info_container())
+    }
+    pub fn primary_fixed(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::primary_fixed())
+    }
+    pub fn primary_fixed_dim(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::primary_fixed_dim())
+    }
+    pub fn on_primary_fixed(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_primary_fixed())
+    }
+    pub fn on_primary_fixed_variant(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_primary_fixed_variant())
+    }
+    pub fn secondary_fixed(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::secondary_fixed())
+    }
+    pub fn secondary_fixed_dim(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::secondary_fixed_dim())
+    }
+    pub fn on_secondary_fixed(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_secondary_fixed())
+    }
+    pub fn on_secondary_fixed_variant(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_secondary_fixed_variant())
+    }
+    pub fn tertiary_fixed(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::tertiary_fixed())
+    }
+    pub fn tertiary_fixed_dim(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::tertiary_fixed_dim())
+    }
+    pub fn on_tertiary_fixed(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_tertiary_fixed())
+    }
+    pub fn on_tertiary_fixed_variant(&self) -> Argb {
+        self.get_color(&MaterialDynamicColors::on_tertiary_fixed_variant())
+    }
+
+    // Deprecated getters.
+    #[deprecated = "use `DynamicScheme::primary_palette_key_color` instead"]
     pub fn get_primary_palette_key_color(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::primary_palette_key_color())
+        self.primary_palette_key_color().into()
     }
+    #[deprecated = "use `DynamicScheme::secondary_palette_key_color` instead"]
     pub fn get_secondary_palette_key_color(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::secondary_palette_key_color())
+        self.secondary_palette_key_color().into()
     }
+    #[deprecated = "use `DynamicScheme::tertiary_palette_key_color` instead"]
     pub fn get_tertiary_palette_key_color(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::tertiary_palette_key_color())
+        self.tertiary_palette_key_color().into()
     }
+    #[deprecated = "use `DynamicScheme::neutral_palette_key_color` instead"]
     pub fn get_neutral_palette_key_color(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::neutral_palette_key_color())
+        self.neutral_palette_key_color().into()
     }
+    #[deprecated = "use `DynamicScheme::neutral_variant_palette_key_color` instead"]
     pub fn get_neutral_variant_palette_key_color(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::neutral_variant_palette_key_color())
+        self.neutral_variant_palette_key_color().into()
     }
+    #[deprecated = "use `DynamicScheme::background` instead"]
     pub fn get_background(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::background())
+        self.background().into()
     }
+    #[deprecated = "use `DynamicScheme::on_background` instead"]
     pub fn get_on_background(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::on_background())
+        self.on_background().into()
     }
+    #[deprecated = "use `DynamicScheme::surface` instead"]
     pub fn get_surface(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::surface())
+        self.surface().into()
     }
+    #[deprecated = "use `DynamicScheme::surface_dim` instead"]
     pub fn get_surface_dim(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::surface_dim())
+        self.surface_dim().into()
     }
+    #[deprecated = "use `DynamicScheme::surface_bright` instead"]
     pub fn get_surface_bright(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::surface_bright())
+        self.surface_bright().into()
     }
+    #[deprecated = "use `DynamicScheme::surface_container_lowest` instead"]
     pub fn get_surface_container_lowest(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::surface_container_lowest())
+        self.surface_container_lowest().into()
     }
+    #[deprecated = "use `DynamicScheme::surface_container_low` instead"]
     pub fn get_surface_container_low(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::surface_container_low())
+        self.surface_container_low().into()
     }
+    #[deprecated = "use `DynamicScheme::surface_container` instead"]
     pub fn get_surface_container(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::surface_container())
+        self.surface_container().into()
     }
+    #[deprecated = "use `DynamicScheme::surface_container_high` instead"]
     pub fn get_surface_container_high(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::surface_container_high())
+        self.surface_container_high().into()
     }
+    #[deprecated = "use `DynamicScheme::surface_container_highest` instead"]
     pub fn get_surface_container_highest(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::surface_container_highest())
+        self.surface_container_highest().into()
     }
+    #[deprecated = "use `DynamicScheme::on_surface` instead"]
     pub fn get_on_surface(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::on_surface())
+        self.on_surface().into()
     }
+    #[deprecated = "use `DynamicScheme::surface_variant` instead"]
     pub fn get_surface_variant(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::surface_variant())
+        self.surface_variant().into()
     }
+    #[deprecated = "use `DynamicScheme::on_surface_variant` instead"]
     pub fn get_on_surface_variant(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::on_surface_variant())
+        self.on_surface_variant().into()
     }
+    #[deprecated = "use `DynamicScheme::inverse_surface` instead"]
     pub fn get_inverse_surface(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::inverse_surface())
+        self.inverse_surface().into()
     }
+    #[deprecated = "use `DynamicScheme::inverse_on_surface` instead"]
     pub fn get_inverse_on_surface(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::inverse_on_surface())
+        self.inverse_on_surface().into()
     }
+    #[deprecated = "use `DynamicScheme::outline` instead"]
     pub fn get_outline(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::outline())
+        self.outline().into()
     }
+    #[deprecated = "use `DynamicScheme::outline_variant` instead"]
     pub fn get_outline_variant(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::outline_variant())
+        self.outline_variant().into()
     }
+    #[deprecated = "use `DynamicScheme::shadow` instead"]
     pub fn get_shadow(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::shadow())
+        self.shadow().into()
     }
+    #[deprecated = "use `DynamicScheme::scrim` instead"]
     pub fn get_scrim(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::scrim())
+        self.scrim().into()
     }
+    #[deprecated = "use `DynamicScheme::surface_tint` instead"]
     pub fn get_surface_tint(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::surface_tint())
+        self.surface_tint().into()
     }
+    #[deprecated = "use `DynamicScheme::primary` instead"]
     pub fn get_primary(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::primary())
+        self.primary().into()
     }
+    #[deprecated = "use `DynamicScheme::on_primary` instead"]
     pub fn get_on_primary(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::on_primary())
+        self.on_primary().into()
     }
+    #[deprecated = "use `DynamicScheme::primary_container` instead"]
     pub fn get_primary_container(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::primary_container())
+        self.primary_container().into()
     }
+    #[deprecated = "use `DynamicScheme::on_primary_container` instead"]
     pub fn get_on_primary_container(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::on_primary_container())
+        self.on_primary_container().into()
     }
+    #[deprecated = "use `DynamicScheme::inverse_primary` instead"]
     pub fn get_inverse_primary(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::inverse_primary())
+        self.inverse_primary().into()
     }
+    #[deprecated = "use `DynamicScheme::secondary` instead"]
     pub fn get_secondary(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::secondary())
+        self.secondary().into()
     }
+    #[deprecated = "use `DynamicScheme::on_secondary` instead"]
     pub fn get_on_secondary(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::on_secondary())
+        self.on_secondary().into()
     }
+    #[deprecated = "use `DynamicScheme::secondary_container` instead"]
     pub fn get_secondary_container(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::secondary_container())
+        self.secondary_container().into()
     }
+    #[deprecated = "use `DynamicScheme::on_secondary_container` instead"]
     pub fn get_on_secondary_container(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::on_secondary_container())
+        self.on_secondary_container().into()
     }
+    #[deprecated = "use `DynamicScheme::tertiary` instead"]
     pub fn get_tertiary(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::tertiary())
+        self.tertiary().into()
     }
+    #[deprecated = "use `DynamicScheme::on_tertiary` instead"]
     pub fn get_on_tertiary(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::on_tertiary())
+        self.on_tertiary().into()
     }
+    #[deprecated = "use `DynamicScheme::tertiary_container` instead"]
     pub fn get_tertiary_container(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::tertiary_container())
+        self.tertiary_container().into()
     }
+    #[deprecated = "use `DynamicScheme::on_tertiary_container` instead"]
     pub fn get_on_tertiary_container(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::on_tertiary_container())
+        self.on_tertiary_container().into()
     }
+    #[deprecated = "use `DynamicScheme::error` instead"]
     pub fn get_error(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::error())
+        self.error().into()
     }
+    #[deprecated = "use `DynamicScheme::on_error` instead"]
     pub fn get_on_error(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::on_error())
+        self.on_error().into()
     }
+    #[deprecated = "use `DynamicScheme::error_container` instead"]
     pub fn get_error_container(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::error_container())
+        self.error_container().into()
     }
+    #[deprecated = "use `DynamicScheme::on_error_container` instead"]
     pub fn get_on_error_container(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::on_error_container())
+        self.on_error_container().into()
     }
+    #[deprecated = "use `DynamicScheme::primary_fixed` instead"]
     pub fn get_primary_fixed(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::primary_fixed())
+        self.primary_fixed().into()
     }
+    #[deprecated = "use `DynamicScheme::primary_fixed_dim` instead"]
     pub fn get_primary_fixed_dim(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::primary_fixed_dim())
+        self.primary_fixed_dim().into()
     }
+    #[deprecated = "use `DynamicScheme::on_primary_fixed` instead"]
     pub fn get_on_primary_fixed(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::on_primary_fixed())
+        self.on_primary_fixed().into()
     }
+    #[deprecated = "use `DynamicScheme::on_primary_fixed_variant` instead"]
     pub fn get_on_primary_fixed_variant(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::on_primary_fixed_variant())
+        self.on_primary_fixed_variant().into()
     }
+    #[deprecated = "use `DynamicScheme::secondary_fixed` instead"]
     pub fn get_secondary_fixed(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::secondary_fixed())
+        self.secondary_fixed().into()
     }
+    #[deprecated = "use `DynamicScheme::secondary_fixed_dim` instead"]
     pub fn get_secondary_fixed_dim(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::secondary_fixed_dim())
+        self.secondary_fixed_dim().into()
     }
+    #[deprecated = "use `DynamicScheme::on_secondary_fixed` instead"]
     pub fn get_on_secondary_fixed(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::on_secondary_fixed())
+        self.on_secondary_fixed().into()
     }
+    #[deprecated = "use `DynamicScheme::on_secondary_fixed_variant` instead"]
     pub fn get_on_secondary_fixed_variant(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::on_secondary_fixed_variant())
+        self.on_secondary_fixed_variant().into()
     }
+    #[deprecated = "use `DynamicScheme::tertiary_fixed` instead"]
     pub fn get_tertiary_fixed(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::tertiary_fixed())
+        self.tertiary_fixed().into()
     }
+    #[deprecated = "use `DynamicScheme::tertiary_fixed_dim` instead"]
     pub fn get_tertiary_fixed_dim(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::tertiary_fixed_dim())
+        self.tertiary_fixed_dim().into()
     }
+    #[deprecated = "use `DynamicScheme::on_tertiary_fixed` instead"]
     pub fn get_on_tertiary_fixed(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::on_tertiary_fixed())
+        self.on_tertiary_fixed().into()
     }
+    #[deprecated = "use `DynamicScheme::on_tertiary_fixed_variant` instead"]
     pub fn get_on_tertiary_fixed_variant(&self) -> i64 {
-        self.get_argb(&mut MaterialDynamicColors::on_tertiary_fixed_variant())
+        self.on_tertiary_fixed_variant().into()
     }
 }
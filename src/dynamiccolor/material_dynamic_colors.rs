@@ -1,11 +1,16 @@
-use crate::{dislike::dislike_analyzer::DislikeAnalyzer, hct::hct::Hct};
+use crate::{
+    dislike::dislike_analyzer::DislikeAnalyzer, hct::hct::Hct,
+    palettes::tonal_palette::TonalPalette,
+};
 
 use super::{
+    color_role::ColorRole,
+    color_spec_2025::ColorSpec2025,
     dynamic_color::DynamicColor,
     dynamic_scheme::DynamicScheme,
     src::{
         contrast_curve::ContrastCurve,
-        tone_delta_pair::{ToneDeltaPair, TonePolarity},
+        tone_delta_pair::{DeltaConstraint, ToneDeltaPair, TonePolarity},
     },
     variant::Variant,
 };
@@ -17,6 +22,34 @@ fn _is_monochrome(scheme: &DynamicScheme) -> bool {
     scheme.variant == Variant::Monochrome
 }
 
+/// The minimum tone difference between a dim role and its base role.
+const _DIM_DELTA: f64 = 5.0;
+
+/// Roles that follow the rules of the error roles with their own palette: a
+/// color, its container, and the colors on them.
+#[derive(Clone, Copy)]
+struct _ErrorFamily {
+    name: &'static str,
+    palette: fn(&DynamicScheme) -> &TonalPalette,
+}
+
+const _ERROR: _ErrorFamily = _ErrorFamily {
+    name: "error",
+    palette: |s| &s.error_palette,
+};
+const _SUCCESS: _ErrorFamily = _ErrorFamily {
+    name: "success",
+    palette: |s| &s.success_palette,
+};
+const _WARNING: _ErrorFamily = _ErrorFamily {
+    name: "warning",
+    palette: |s| &s.warning_palette,
+};
+const _INFO: _ErrorFamily = _ErrorFamily {
+    name: "info",
+    palette: |s| &s.info_palette,
+};
+
 pub struct MaterialDynamicColors {}
 impl MaterialDynamicColors {
     //static const double contentAccentToneDelta = 15.0;
@@ -28,6 +61,82 @@ impl MaterialDynamicColors {
         };
     }
 
+    /// The [DynamicColor] for [role].
+    pub fn for_role(role: ColorRole) -> DynamicColor {
+        match role {
+            ColorRole::PrimaryPaletteKeyColor => Self::primary_palette_key_color(),
+            ColorRole::SecondaryPaletteKeyColor => Self::secondary_palette_key_color(),
+            ColorRole::TertiaryPaletteKeyColor => Self::tertiary_palette_key_color(),
+            ColorRole::NeutralPaletteKeyColor => Self::neutral_palette_key_color(),
+            ColorRole::NeutralVariantPaletteKeyColor => Self::neutral_variant_palette_key_color(),
+            ColorRole::Background => Self::background(),
+            ColorRole::OnBackground => Self::on_background(),
+            ColorRole::Surface => Self::surface(),
+            ColorRole::SurfaceDim => Self::surface_dim(),
+            ColorRole::SurfaceBright => Self::surface_bright(),
+            ColorRole::SurfaceContainerLowest => Self::surface_container_lowest(),
+            ColorRole::SurfaceContainerLow => Self::surface_container_low(),
+            ColorRole::SurfaceContainer => Self::surface_container(),
+            ColorRole::SurfaceContainerHigh => Self::surface_container_high(),
+            ColorRole::SurfaceContainerHighest => Self::surface_container_highest(),
+            ColorRole::OnSurface => Self::on_surface(),
+            ColorRole::SurfaceVariant => Self::surface_variant(),
+            ColorRole::OnSurfaceVariant => Self::on_surface_variant(),
+            ColorRole::InverseSurface => Self::inverse_surface(),
+            ColorRole::InverseOnSurface => Self::inverse_on_surface(),
+            ColorRole::Outline => Self::outline(),
+            ColorRole::OutlineVariant => Self::outline_variant(),
+            ColorRole::Shadow => Self::shadow(),
+            ColorRole::Scrim => Self::scrim(),
+            ColorRole::SurfaceTint => Self::surface_tint(),
+            ColorRole::Primary => Self::primary(),
+            ColorRole::PrimaryDim => Self::primary_dim(),
+            ColorRole::OnPrimary => Self::on_primary(),
+            ColorRole::PrimaryContainer => Self::primary_container(),
+            ColorRole::OnPrimaryContainer => Self::on_primary_container(),
+            ColorRole::InversePrimary => Self::inverse_primary(),
+            ColorRole::Secondary => Self::secondary(),
+            ColorRole::SecondaryDim => Self::secondary_dim(),
+            ColorRole::OnSecondary => Self::on_secondary(),
+            ColorRole::SecondaryContainer => Self::secondary_container(),
+            ColorRole::OnSecondaryContainer => Self::on_secondary_container(),
+            ColorRole::Tertiary => Self::tertiary(),
+            ColorRole::TertiaryDim => Self::tertiary_dim(),
+            ColorRole::OnTertiary => Self::on_tertiary(),
+            ColorRole::TertiaryContainer => Self::tertiary_container(),
+            ColorRole::OnTertiaryContainer => Self::on_tertiary_container(),
+            ColorRole::Error => Self::error(),
+            ColorRole::ErrorDim => Self::error_dim(),
+            ColorRole::OnError => Self::on_error(),
+            ColorRole::ErrorContainer => Self::error_container(),
+            ColorRole::OnErrorContainer => Self::on_error_container(),
+            ColorRole::Success => Self::success(),
+            ColorRole::OnSuccess => Self::on_success(),
+            ColorRole::SuccessContainer => Self::success_container(),
+            ColorRole::OnSuccessContainer => Self::on_success_container(),
+            ColorRole::Warning => Self::warning(),
+            ColorRole::OnWarning => Self::on_warning(),
+            ColorRole::WarningContainer => Self::warning_container(),
+            ColorRole::OnWarningContainer => Self::on_warning_container(),
+            ColorRole::Info => Self::info(),
+            ColorRole::OnInfo => Self::on_info(),
+            ColorRole::InfoContainer => Self::info_container(),
+            ColorRole::OnInfoContainer => Self::on_info_container(),
+            ColorRole::PrimaryFixed => Self::primary_fixed(),
+            ColorRole::PrimaryFixedDim => Self::primary_fixed_dim(),
+            ColorRole::OnPrimaryFixed => Self::on_primary_fixed(),
+            ColorRole::OnPrimaryFixedVariant => Self::on_primary_fixed_variant(),
+            ColorRole::SecondaryFixed => Self::secondary_fixed(),
+            ColorRole::SecondaryFixedDim => Self::secondary_fixed_dim(),
+            ColorRole::OnSecondaryFixed => Self::on_secondary_fixed(),
+            ColorRole::OnSecondaryFixedVariant => Self::on_secondary_fixed_variant(),
+            ColorRole::TertiaryFixed => Self::tertiary_fixed(),
+            ColorRole::TertiaryFixedDim => Self::tertiary_fixed_dim(),
+            ColorRole::OnTertiaryFixed => Self::on_tertiary_fixed(),
+            ColorRole::OnTertiaryFixedVariant => Self::on_tertiary_fixed_variant(),
+        }
+    }
+
     pub fn primary_palette_key_color() -> DynamicColor {
         DynamicColor::from_palette(
             Some("primary_palette_key_color"),
@@ -97,7 +206,12 @@ impl MaterialDynamicColors {
         DynamicColor::from_palette(
             Some("background"),
             Box::new(|s| &s.neutral_palette),
-            Box::new(|s| if s.is_dark { 6.0 } else { 98.0 }),
+            Box::new(|s| {
+                if let Some(tone) = ColorSpec2025::surface_tone(s) {
+                    return tone;
+                }
+                return if s.is_dark { 6.0 } else { 98.0 };
+            }),
             Some(true),
             None,
             None,
@@ -123,7 +237,12 @@ impl MaterialDynamicColors {
         DynamicColor::from_palette(
             Some("surface"),
             Box::new(|s| &s.neutral_palette),
-            Box::new(|s| if s.is_dark { 6.0 } else { 98.0 }),
+            Box::new(|s| {
+                if let Some(tone) = ColorSpec2025::surface_tone(s) {
+                    return tone;
+                }
+                return if s.is_dark { 6.0 } else { 98.0 };
+            }),
             Some(true),
             None,
             None,
@@ -137,6 +256,9 @@ impl MaterialDynamicColors {
             Some("surface_dim"),
             Box::new(|s| &s.neutral_palette),
             Box::new(|s| {
+                if let Some(tone) = ColorSpec2025::surface_dim_tone(s) {
+                    return tone;
+                }
                 if s.is_dark {
                     6.0
                 } else {
@@ -156,6 +278,9 @@ impl MaterialDynamicColors {
             Some("surface_bright"),
             Box::new(|s| &s.neutral_palette),
             Box::new(|s| {
+                if let Some(tone) = ColorSpec2025::surface_bright_tone(s) {
+                    return tone;
+                }
                 if s.is_dark {
                     ContrastCurve::new(24.0, 24.0, 29.0, 34.0).get(s.contrast_level)
                 } else {
@@ -175,6 +300,9 @@ impl MaterialDynamicColors {
             Some("surface_container_lowest"),
             Box::new(|s| &s.neutral_palette),
             Box::new(|s| {
+                if let Some(tone) = ColorSpec2025::surface_container_lowest_tone(s) {
+                    return tone;
+                }
                 if s.is_dark {
                     ContrastCurve::new(4.0, 4.0, 2.0, 0.0).get(s.contrast_level)
                 } else {
@@ -194,6 +322,9 @@ impl MaterialDynamicColors {
             Some("surface_container_low"),
             Box::new(|s| &s.neutral_palette),
             Box::new(|s| {
+                if let Some(tone) = ColorSpec2025::surface_container_low_tone(s) {
+                    return tone;
+                }
                 if s.is_dark {
                     ContrastCurve::new(10.0, 10.0, 11.0, 12.0).get(s.contrast_level)
                 } else {
@@ -213,6 +344,9 @@ impl MaterialDynamicColors {
             Some("surface_container"),
             Box::new(|s| &s.neutral_palette),
             Box::new(|s| {
+                if let Some(tone) = ColorSpec2025::surface_container_tone(s) {
+                    return tone;
+                }
                 if s.is_dark {
                     ContrastCurve::new(12.0, 12.0, 16.0, 20.0).get(s.contrast_level)
                 } else {
@@ -232,6 +366,9 @@ impl MaterialDynamicColors {
             Some("surface_container_high"),
             Box::new(|s| &s.neutral_palette),
             Box::new(|s| {
+                if let Some(tone) = ColorSpec2025::surface_container_high_tone(s) {
+                    return tone;
+                }
                 if s.is_dark {
                     ContrastCurve::new(17.0, 17.0, 21.0, 25.0).get(s.contrast_level)
                 } else {
@@ -251,6 +388,9 @@ impl MaterialDynamicColors {
             Some("surface_container_highest"),
             Box::new(|s| &s.neutral_palette),
             Box::new(|s| {
+                if let Some(tone) = ColorSpec2025::surface_container_highest_tone(s) {
+                    return tone;
+                }
                 if s.is_dark {
                     ContrastCurve::new(22.0, 22.0, 26.0, 30.0).get(s.contrast_level)
                 } else {
@@ -400,6 +540,9 @@ impl MaterialDynamicColors {
             Some("primary"),
             Box::new(|s| &s.primary_palette),
             Box::new(|s| {
+                if let Some(tone) = ColorSpec2025::primary_tone(s) {
+                    return tone;
+                }
                 if _is_monochrome(s) {
                     return if s.is_dark { 100.0 } else { 0.0 };
                 }
@@ -421,6 +564,35 @@ impl MaterialDynamicColors {
         )
     }
 
+    /// A companion of [primary] at least [_DIM_DELTA] tones darker, for
+    /// surfaces that need a dimmer accent.
+    pub fn primary_dim() -> DynamicColor {
+        DynamicColor::from_palette(
+            Some("primary_dim"),
+            Box::new(|s| &s.primary_palette),
+            Box::new(|s| match s.variant {
+                Variant::Neutral => 85.0,
+                Variant::TonalSpot => ColorSpec2025::t_max_c(&s.primary_palette, 0.0, 90.0),
+                _ => ColorSpec2025::t_max_c(&s.primary_palette, 0.0, 100.0),
+            }),
+            Some(true),
+            Some(Box::new(|_| {
+                MaterialDynamicColors::surface_container_high()
+            })),
+            None,
+            Some(ContrastCurve::new(4.5, 4.5, 7.0, 11.0)),
+            Some(Box::new(|_| {
+                ToneDeltaPair::with_constraint(
+                    MaterialDynamicColors::primary_dim(),
+                    MaterialDynamicColors::primary(),
+                    _DIM_DELTA,
+                    TonePolarity::Darker,
+                    DeltaConstraint::Farther,
+                )
+            })),
+        )
+    }
+
     pub fn on_primary() -> DynamicColor {
         DynamicColor::from_palette(
             Some("on_primary"),
@@ -445,6 +617,9 @@ impl MaterialDynamicColors {
             Some("primary_container"),
             Box::new(|s| &s.primary_palette),
             Box::new(|s| {
+                if let Some(tone) = ColorSpec2025::primary_container_tone(s) {
+                    return tone;
+                }
                 if _is_fidelity(s) {
                     return s.source_color_hct.get_tone();
                 }
@@ -510,7 +685,12 @@ impl MaterialDynamicColors {
         DynamicColor::from_palette(
             Some("secondary"),
             Box::new(|s| &s.secondary_palette),
-            Box::new(|s| if s.is_dark { 80.0 } else { 40.0 }),
+            Box::new(|s| {
+                if let Some(tone) = ColorSpec2025::secondary_tone(s) {
+                    return tone;
+                }
+                return if s.is_dark { 80.0 } else { 40.0 };
+            }),
             Some(true),
             Some(Box::new(|s| MaterialDynamicColors::highest_surface(s))),
             None,
@@ -527,6 +707,34 @@ impl MaterialDynamicColors {
         )
     }
 
+    /// A companion of [secondary] at least [_DIM_DELTA] tones darker, for
+    /// surfaces that need a dimmer accent.
+    pub fn secondary_dim() -> DynamicColor {
+        DynamicColor::from_palette(
+            Some("secondary_dim"),
+            Box::new(|s| &s.secondary_palette),
+            Box::new(|s| match s.variant {
+                Variant::Neutral => 85.0,
+                _ => ColorSpec2025::t_max_c(&s.secondary_palette, 0.0, 90.0),
+            }),
+            Some(true),
+            Some(Box::new(|_| {
+                MaterialDynamicColors::surface_container_high()
+            })),
+            None,
+            Some(ContrastCurve::new(4.5, 4.5, 7.0, 11.0)),
+            Some(Box::new(|_| {
+                ToneDeltaPair::with_constraint(
+                    MaterialDynamicColors::secondary_dim(),
+                    MaterialDynamicColors::secondary(),
+                    _DIM_DELTA,
+                    TonePolarity::Darker,
+                    DeltaConstraint::Farther,
+                )
+            })),
+        )
+    }
+
     pub fn on_secondary() -> DynamicColor {
         DynamicColor::from_palette(
             Some("on_secondary"),
@@ -551,6 +759,9 @@ impl MaterialDynamicColors {
             Some("secondary_container"),
             Box::new(|s| &s.secondary_palette),
             Box::new(|s| {
+                if let Some(tone) = ColorSpec2025::secondary_container_tone(s) {
+                    return tone;
+                }
                 let initial_tone = if s.is_dark { 30.0 } else { 90.0 };
                 if _is_monochrome(s) {
                     return if s.is_dark { 30.0 } else { 85.0 };
@@ -613,6 +824,9 @@ impl MaterialDynamicColors {
             Some("tertiary"),
             Box::new(|s| &s.tertiary_palette),
             Box::new(|s| {
+                if let Some(tone) = ColorSpec2025::tertiary_tone(s) {
+                    return tone;
+                }
                 if _is_monochrome(s) {
                     return if s.is_dark { 90.0 } else { 25.0 };
                 }
@@ -634,6 +848,34 @@ impl MaterialDynamicColors {
         )
     }
 
+    /// A companion of [tertiary] at least [_DIM_DELTA] tones darker, for
+    /// surfaces that need a dimmer accent.
+    pub fn tertiary_dim() -> DynamicColor {
+        DynamicColor::from_palette(
+            Some("tertiary_dim"),
+            Box::new(|s| &s.tertiary_palette),
+            Box::new(|s| match s.variant {
+                Variant::TonalSpot => ColorSpec2025::t_max_c(&s.tertiary_palette, 0.0, 90.0),
+                _ => ColorSpec2025::t_max_c(&s.tertiary_palette, 0.0, 100.0),
+            }),
+            Some(true),
+            Some(Box::new(|_| {
+                MaterialDynamicColors::surface_container_high()
+            })),
+            None,
+            Some(ContrastCurve::new(4.5, 4.5, 7.0, 11.0)),
+            Some(Box::new(|_| {
+                ToneDeltaPair::with_constraint(
+                    MaterialDynamicColors::tertiary_dim(),
+                    MaterialDynamicColors::tertiary(),
+                    _DIM_DELTA,
+                    TonePolarity::Darker,
+                    DeltaConstraint::Farther,
+                )
+            })),
+        )
+    }
+
     pub fn on_tertiary() -> DynamicColor {
         DynamicColor::from_palette(
             Some("on_tertiary"),
@@ -657,6 +899,9 @@ impl MaterialDynamicColors {
             Some("tertiary_container"),
             Box::new(|s| &s.tertiary_palette),
             Box::new(|s| {
+                if let Some(tone) = ColorSpec2025::tertiary_container_tone(s) {
+                    return tone;
+                }
                 if _is_monochrome(s) {
                     return if s.is_dark { 60.0 } else { 49.0 };
                 }
@@ -707,18 +952,113 @@ impl MaterialDynamicColors {
     }
 
     pub fn error() -> DynamicColor {
+        return Self::_error_family_color(_ERROR);
+    }
+
+    /// A companion of [error] at least [_DIM_DELTA] tones darker, for
+    /// surfaces that need a dimmer accent.
+    pub fn error_dim() -> DynamicColor {
         DynamicColor::from_palette(
-            Some("error"),
+            Some("error_dim"),
             Box::new(|s| &s.error_palette),
-            Box::new(|s| if s.is_dark { 80.0 } else { 40.0 }),
+            Box::new(|s| ColorSpec2025::t_min_c(&s.error_palette, 0.0, 100.0)),
+            Some(true),
+            Some(Box::new(|_| {
+                MaterialDynamicColors::surface_container_high()
+            })),
+            None,
+            Some(ContrastCurve::new(4.5, 4.5, 7.0, 11.0)),
+            Some(Box::new(|_| {
+                ToneDeltaPair::with_constraint(
+                    MaterialDynamicColors::error_dim(),
+                    MaterialDynamicColors::error(),
+                    _DIM_DELTA,
+                    TonePolarity::Darker,
+                    DeltaConstraint::Farther,
+                )
+            })),
+        )
+    }
+
+    pub fn on_error() -> DynamicColor {
+        return Self::_on_error_family_color(_ERROR);
+    }
+
+    pub fn error_container() -> DynamicColor {
+        return Self::_error_family_container(_ERROR);
+    }
+
+    pub fn on_error_container() -> DynamicColor {
+        return Self::_on_error_family_container(_ERROR);
+    }
+
+    pub fn success() -> DynamicColor {
+        return Self::_error_family_color(_SUCCESS);
+    }
+
+    pub fn on_success() -> DynamicColor {
+        return Self::_on_error_family_color(_SUCCESS);
+    }
+
+    pub fn success_container() -> DynamicColor {
+        return Self::_error_family_container(_SUCCESS);
+    }
+
+    pub fn on_success_container() -> DynamicColor {
+        return Self::_on_error_family_container(_SUCCESS);
+    }
+
+    pub fn warning() -> DynamicColor {
+        return Self::_error_family_color(_WARNING);
+    }
+
+    pub fn on_warning() -> DynamicColor {
+        return Self::_on_error_family_color(_WARNING);
+    }
+
+    pub fn warning_container() -> DynamicColor {
+        return Self::_error_family_container(_WARNING);
+    }
+
+    pub fn on_warning_container() -> DynamicColor {
+        return Self::_on_error_family_container(_WARNING);
+    }
+
+    pub fn info() -> DynamicColor {
+        return Self::_error_family_color(_INFO);
+    }
+
+    pub fn on_info() -> DynamicColor {
+        return Self::_on_error_family_color(_INFO);
+    }
+
+    pub fn info_container() -> DynamicColor {
+        return Self::_error_family_container(_INFO);
+    }
+
+    pub fn on_info_container() -> DynamicColor {
+        return Self::_on_error_family_container(_INFO);
+    }
+
+    /// The color of [family], like [error].
+    fn _error_family_color(family: _ErrorFamily) -> DynamicColor {
+        DynamicColor::from_palette(
+            Some(family.name),
+            Box::new(move |s| (family.palette)(s)),
+            Box::new(move |s| {
+                if let Some(tone) = ColorSpec2025::error_tone(s, (family.palette)(s)) {
+                    return tone;
+                }
+                return if s.is_dark { 80.0 } else { 40.0 };
+            }),
             Some(true),
             Some(Box::new(|s| MaterialDynamicColors::highest_surface(s))),
             None,
             Some(ContrastCurve::new(3.0, 4.5, 7.0, 7.0)),
-            Some(Box::new(|_| {
+            Some(Box::new(move |_| {
                 ToneDeltaPair::new(
-                    MaterialDynamicColors::error_container(),
-                    MaterialDynamicColors::error(),
+                    MaterialDynamicColors::_error_family_container(family),
+                    MaterialDynamicColors::_error_family_color(family),
                     10.0,
                     TonePolarity::Nearer,
                     false,
@@ -727,32 +1067,41 @@ impl MaterialDynamicColors {
         )
     }
 
-    pub fn on_error() -> DynamicColor {
+    /// The color on the color of [family], like [on_error].
+    fn _on_error_family_color(family: _ErrorFamily) -> DynamicColor {
         DynamicColor::from_palette(
-            Some("on_error"),
-            Box::new(|s| &s.error_palette),
+            Some(&format!("on_{}", family.name)),
+            Box::new(move |s| (family.palette)(s)),
             Box::new(|s| if s.is_dark { 20.0 } else { 100.0 }),
             None,
-            Some(Box::new(|_| MaterialDynamicColors::error())),
+            Some(Box::new(move |_| {
+                MaterialDynamicColors::_error_family_color(family)
+            })),
             None,
             Some(ContrastCurve::new(4.5, 7.0, 11.0, 21.0)),
             None,
         )
     }
 
-    pub fn error_container() -> DynamicColor {
+    /// The container of [family], like [error_container].
+    fn _error_family_container(family: _ErrorFamily) -> DynamicColor {
         DynamicColor::from_palette(
-            Some("error_container"),
-            Box::new(|s| &s.error_palette),
-            Box::new(|s| if s.is_dark { 30.0 } else { 90.0 }),
+            Some(&format!("{}_container", family.name)),
+            Box::new(move |s| (family.palette)(s)),
+            Box::new(move |s| {
+                if let Some(tone) = ColorSpec2025::error_container_tone(s, (family.palette)(s)) {
+                    return tone;
+                }
+                return if s.is_dark { 30.0 } else { 90.0 };
+            }),
             Some(true),
             Some(Box::new(|s| MaterialDynamicColors::highest_surface(s))),
             None,
             Some(ContrastCurve::new(1.0, 1.0, 3.0, 4.5)),
-            Some(Box::new(|_| {
+            Some(Box::new(move |_| {
                 ToneDeltaPair::new(
-                    MaterialDynamicColors::error_container(),
-                    MaterialDynamicColors::error(),
+                    MaterialDynamicColors::_error_family_container(family),
+                    MaterialDynamicColors::_error_family_color(family),
                     10.0,
                     TonePolarity::Nearer,
                     false,
@@ -760,13 +1109,17 @@ impl MaterialDynamicColors {
             })),
         )
     }
-    pub fn on_error_container() -> DynamicColor {
+
+    /// The color on the container of [family], like [on_error_container].
+    fn _on_error_family_container(family: _ErrorFamily) -> DynamicColor {
         DynamicColor::from_palette(
-            Some("on_error_container"),
-            Box::new(|s| &s.error_palette),
+            Some(&format!("on_{}_container", family.name)),
+            Box::new(move |s| (family.palette)(s)),
             Box::new(|s| if s.is_dark { 90.0 } else { 10.0 }),
             None,
-            Some(Box::new(|_| MaterialDynamicColors::error_container())),
+            Some(Box::new(move |_| {
+                MaterialDynamicColors::_error_family_container(family)
+            })),
             None,
             Some(ContrastCurve::new(4.5, 7.0, 11.0, 21.0)),
             None,
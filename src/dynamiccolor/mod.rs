@@ -1,5 +1,9 @@
+pub mod color_role;
+pub mod color_spec_2025;
 pub mod dynamic_color;
 pub mod dynamic_scheme;
 pub mod material_dynamic_colors;
+pub mod resolved_scheme;
+pub mod spec_version;
 pub mod src;
 pub mod variant;
@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+
+use crate::{hct::hct::Hct, utils::argb::Argb};
+
+use super::{
+    color_role::ColorRole, dynamic_scheme::DynamicScheme,
+    material_dynamic_colors::MaterialDynamicColors,
+};
+
+/// Every [ColorRole] of a [DynamicScheme], resolved once.
+///
+/// Resolving roles one at a time solves shared backgrounds, such as the
+/// surfaces, again for every color drawn on them. [ResolvedScheme] solves each
+/// role exactly once, reusing the tones of roles it already solved, and stores
+/// the results in a table indexed by role.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ResolvedScheme {
+    _hcts: Vec<Hct>,
+}
+
+impl ResolvedScheme {
+    /// Resolves every role of [scheme].
+    pub fn from_scheme(scheme: &DynamicScheme) -> ResolvedScheme {
+        let mut tones: HashMap<String, f64> = HashMap::new();
+        let hcts = ColorRole::ALL
+            .iter()
+            .map(|role| {
+                let color = MaterialDynamicColors::for_role(role.for_spec(scheme.spec_version));
+                let tone = color.get_tone_cached(scheme, &mut tones);
+                (color.palette)(scheme).get_hct(tone)
+            })
+            .collect();
+        return ResolvedScheme { _hcts: hcts };
+    }
+
+    /// The color of [role].
+    pub fn get(&self, role: ColorRole) -> Argb {
+        self._hcts[role as usize].to_argb()
+    }
+
+    /// The color of [role] in HCT.
+    pub fn get_hct(&self, role: ColorRole) -> &Hct {
+        &self._hcts[role as usize]
+    }
+
+    /// Every role with its color, in [ColorRole::ALL] order.
+    pub fn iter(&self) -> impl Iterator<Item = (ColorRole, Argb)> + '_ {
+        ColorRole::ALL
+            .into_iter()
+            .zip(self._hcts.iter().map(|hct| hct.to_argb()))
+    }
+}
@@ -0,0 +1,50 @@
+/// The version of the Material color specification a [DynamicScheme]
+/// follows.
+///
+/// With the `serde` feature, a version is serialized as its year, e.g.
+/// `"2025"`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpecVersion {
+    /// The original Material You specification. Schemes keep looking the way
+    /// they always have.
+    #[default]
+    #[cfg_attr(feature = "serde", serde(rename = "2021"))]
+    Spec2021,
+    /// The Material 3 Expressive specification, with revised palettes and
+    /// tones for the [Variant::Neutral], [Variant::TonalSpot],
+    /// [Variant::Vibrant] and [Variant::Expressive] variants.
+    ///
+    /// Experimental, and only available with the `spec-2025` feature: the
+    /// port is partial, so schemes don't match the upstream 2025 ones yet.
+    /// Only the palettes, the surface tones, the tones of the primary,
+    /// secondary, tertiary and error roles and their containers, and the dim
+    /// roles are revised. These roles keep the 2021 rules, and differ from
+    /// upstream:
+    /// - the `on_*` roles,
+    /// - `surface_variant`, `outline`, `outline_variant` and `surface_tint`,
+    /// - `inverse_surface`, `inverse_on_surface` and `inverse_primary`,
+    /// - the fixed roles and the roles on them.
+    ///
+    /// Except for the dim roles, contrast curves and tone delta pairs also
+    /// keep the 2021 rules, for every platform.
+    #[cfg(feature = "spec-2025")]
+    #[cfg_attr(feature = "serde", serde(rename = "2025"))]
+    Spec2025,
+}
+
+/// The kind of device a [DynamicScheme] is displayed on. Only used by
+/// [SpecVersion::Spec2025].
+///
+/// With the `serde` feature, a platform is serialized as its snake_case name.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Platform {
+    #[default]
+    Phone,
+    Watch,
+}
@@ -6,6 +6,7 @@ use crate::utils::math_utils::MathUtils;
 /// background. The four values correspond to values for contrast levels
 /// -1.0, 0.0, 0.5, and 1.0, respectively.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ContrastCurve {
     pub low: f64,
     pub normal: f64,
@@ -8,6 +8,18 @@ pub enum TonePolarity {
     Farther,
 }
 
+/// How far the tone of a role of a [ToneDeltaPair] built with
+/// [ToneDeltaPair::with_constraint] may be from the tone of the other role.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DeltaConstraint {
+    /// Exactly the delta away.
+    Exact,
+    /// At most the delta away, on the side given by the polarity.
+    Nearer,
+    /// At least the delta away, on the side given by the polarity.
+    Farther,
+}
+
 /// Documents a constraint between two DynamicColors, in which their tones must
 /// have a certain distance from each other.
 ///
@@ -20,6 +32,9 @@ pub struct ToneDeltaPair {
     pub delta: f64,
     pub polarity: TonePolarity,
     pub stay_together: bool,
+    /// If set, only the role the pair belongs to is solved with the pair,
+    /// against the solved tone of the other role.
+    pub constraint: Option<DeltaConstraint>,
 }
 impl ToneDeltaPair {
     /// Documents a constraint in tone distance between two DynamicColors.
@@ -56,6 +71,37 @@ impl ToneDeltaPair {
             delta,
             polarity,
             stay_together,
+            constraint: None,
+        }
+    }
+
+    /// Documents a constraint in tone distance between two DynamicColors, where
+    /// one of them follows the other.
+    ///
+    /// Unlike [new], where both roles are solved together, the other role is
+    /// solved on its own first, and the role the pair belongs to is then moved
+    /// to satisfy [constraint] against it. The pair must therefore only belong
+    /// to one of its roles.
+    ///
+    /// For instance, ToneDeltaPair(A, B, 5, 'darker', 'farther') states that
+    /// A's tone should be at least 5 darker than the tone B is solved to.
+    ///
+    /// As in the 2025 spec, [stay_together] is set, but it has no effect: the
+    /// role is moved out of the "awkward zone" (T50-59) on its own.
+    pub fn with_constraint(
+        role_a: DynamicColor,
+        role_b: DynamicColor,
+        delta: f64,
+        polarity: TonePolarity,
+        constraint: DeltaConstraint,
+    ) -> ToneDeltaPair {
+        ToneDeltaPair {
+            role_a,
+            role_b,
+            delta,
+            polarity,
+            stay_together: true,
+            constraint: Some(constraint),
         }
     }
 }
@@ -1,7 +1,20 @@
 use core::hash::Hash;
 use std::hash::Hasher;
 
+use crate::hct::hct::Hct;
+
+use super::dynamic_scheme::DynamicScheme;
+
+/// The variant, or style, of a [DynamicScheme].
+///
+/// With the `serde` feature, a variant is serialized as its snake_case name,
+/// e.g. `"tonal_spot"`.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum Variant {
     /// All colors are grayscale, no chroma.
     Monochrome,
@@ -37,6 +50,25 @@ pub enum Variant {
     FruitSalad,
 }
 
+impl Variant {
+    /// Creates the scheme of this variant for [source_color_hct].
+    ///
+    /// See [DynamicScheme::from_variant].
+    pub fn build(
+        &self,
+        source_color_hct: Hct,
+        is_dark: bool,
+        contrast_level: f64,
+    ) -> DynamicScheme {
+        return DynamicScheme::from_variant(
+            self.clone(),
+            source_color_hct,
+            is_dark,
+            contrast_level,
+        );
+    }
+}
+
 impl Eq for Variant {}
 impl PartialEq for Variant {
     fn eq(&self, other: &Self) -> bool {
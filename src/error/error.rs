@@ -0,0 +1,76 @@
+use std::fmt;
+
+/// The error type for fallible operations in this crate.
+///
+/// Every constructor or lookup that would otherwise panic or return a magic
+/// value has a `try_*` variant returning this error.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// A hex string that isn't `RRGGBB` or `AARRGGBB`, with an optional
+    /// leading `#`.
+    InvalidHex(String),
+
+    /// An integer that doesn't fit in a 32-bit ARGB color.
+    InvalidArgb(i64),
+
+    /// A tone outside of 0 to 100.
+    ToneOutOfRange(f64),
+
+    /// A contrast ratio that can't be reached from the given tone.
+    UnreachableContrast { tone: f64, ratio: f64 },
+
+    /// A surround outside of 0 to 2.
+    SurroundOutOfRange(f64),
+
+    /// A list with the wrong number of entries.
+    InvalidLength { expected: usize, actual: usize },
+
+    /// Hue and rotation lists of different lengths.
+    MismatchedRotations { hues: usize, rotations: usize },
+
+    /// A name that isn't one of the [ColorRole] names.
+    UnknownColorRole(String),
+
+    /// A row stride shorter than the bytes of a row of pixels.
+    InvalidStride { stride: usize, row_bytes: usize },
+
+    /// A palette without colors.
+    EmptyPalette,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidHex(input) => write!(f, "invalid hex color: {:?}", input),
+            Error::InvalidArgb(value) => write!(f, "{} is not a 32-bit ARGB color", value),
+            Error::ToneOutOfRange(tone) => write!(f, "tone {} is outside of 0 to 100", tone),
+            Error::UnreachableContrast { tone, ratio } => {
+                write!(
+                    f,
+                    "contrast ratio {} can't be reached from tone {}",
+                    ratio, tone
+                )
+            }
+            Error::SurroundOutOfRange(surround) => {
+                write!(f, "surround {} is outside of 0 to 2", surround)
+            }
+            Error::InvalidLength { expected, actual } => {
+                write!(f, "expected {} entries, got {}", expected, actual)
+            }
+            Error::MismatchedRotations { hues, rotations } => {
+                write!(f, "{} hues don't match {} rotations", hues, rotations)
+            }
+            Error::UnknownColorRole(name) => write!(f, "unknown color role: {:?}", name),
+            Error::InvalidStride { stride, row_bytes } => {
+                write!(
+                    f,
+                    "stride {} is shorter than a row of {} bytes",
+                    stride, row_bytes
+                )
+            }
+            Error::EmptyPalette => write!(f, "the palette has no colors"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -1,4 +1,4 @@
-use crate::utils::{color_utils::ColorUtils, math_utils::MathUtils};
+use crate::utils::{argb::Argb, math_utils::MathUtils};
 
 use super::viewing_conditions::ViewingConditions;
 
@@ -107,23 +107,36 @@ impl Cam16 {
 
     /// Convert [argb] to CAM16, assuming the color was viewed in default viewing
     /// conditions.
+    pub fn from_argb(argb: Argb) -> Cam16 {
+        return Self::from_argb_in_viewing_conditions(argb, &ViewingConditions::s_rgb());
+    }
+
+    #[deprecated = "use `Cam16::from_argb` instead"]
     pub fn from_int(argb: i64) -> Cam16 {
-        return Self::from_int_in_viewing_conditions(argb, &ViewingConditions::s_rgb());
+        return Self::from_argb(Argb(argb as u32));
     }
 
     /// Given [viewingConditions], convert [argb] to CAM16.
-    pub fn from_int_in_viewing_conditions(
-        argb: i64,
+    pub fn from_argb_in_viewing_conditions(
+        argb: Argb,
         viewing_conditions: &ViewingConditions,
     ) -> Cam16 {
         // Transform ARGB int to XYZ
-        let xyz = ColorUtils::xyz_from_argb(argb);
+        let xyz = argb.to_xyz();
         let x = xyz[0];
         let y = xyz[1];
         let z = xyz[2];
         return Self::from_xyz_in_viewing_conditions(x, y, z, viewing_conditions);
     }
 
+    #[deprecated = "use `Cam16::from_argb_in_viewing_conditions` instead"]
+    pub fn from_int_in_viewing_conditions(
+        argb: i64,
+        viewing_conditions: &ViewingConditions,
+    ) -> Cam16 {
+        return Self::from_argb_in_viewing_conditions(Argb(argb as u32), viewing_conditions);
+    }
+
     /// Given color expressed in XYZ and viewed in [viewingConditions], convert to
     /// CAM16.
     pub fn from_xyz_in_viewing_conditions(
@@ -276,8 +289,13 @@ impl Cam16 {
 
     /// ARGB representation of color, assuming the color was viewed in default
     /// viewing conditions.
+    pub fn to_argb(&self) -> Argb {
+        return Self::viewed_argb(self, &ViewingConditions::s_rgb());
+    }
+
+    #[deprecated = "use `Cam16::to_argb` instead"]
     pub fn to_int(&self) -> i64 {
-        return Self::viewed(self, &ViewingConditions::s_rgb());
+        return self.to_argb().into();
     }
 
     // Avoid allocations during conversion by pre-allocating an array.
@@ -285,16 +303,21 @@ impl Cam16 {
 
     /// ARGB representation of a color, given the color was viewed in
     /// [viewingConditions]
-    pub fn viewed(&self, viewing_conditions: &ViewingConditions) -> i64 {
+    pub fn viewed_argb(&self, viewing_conditions: &ViewingConditions) -> Argb {
         let xyz = Self::xyz_in_viewing_conditions(
             self,
             viewing_conditions,
             Some(&Self::_VIEWED_ARRAY.to_vec()),
         );
-        let argb = ColorUtils::argb_from_xyz(xyz[0], xyz[1], xyz[2]);
+        let argb = Argb::from_xyz(xyz[0], xyz[1], xyz[2]);
         return argb;
     }
 
+    #[deprecated = "use `Cam16::viewed_argb` instead"]
+    pub fn viewed(&self, viewing_conditions: &ViewingConditions) -> i64 {
+        return self.viewed_argb(viewing_conditions).into();
+    }
+
     /// XYZ representation of CAM16 seen in [viewingConditions].
     pub fn xyz_in_viewing_conditions(
         &self,
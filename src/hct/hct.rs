@@ -1,4 +1,4 @@
-use crate::utils::color_utils::ColorUtils;
+use crate::utils::{argb::Argb, color_utils::ColorUtils};
 use core::hash::Hash;
 use std::hash::Hasher;
 
@@ -7,12 +7,20 @@ use super::{cam16::Cam16, src::hct_solver::HctSolver, viewing_conditions::Viewin
 /// HCT, hue, chroma, and tone. A color system that provides a perceptually
 /// accurate color measurement system that can also accurately render what
 /// colors will appear as in different lighting environments.
+///
+/// With the `serde` feature, an [Hct] is serialized as its [Argb]; hue,
+/// chroma and tone are recomputed on deserialization.
 #[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "Argb", into = "Argb")
+)]
 pub struct Hct {
     _hue: f64,
     _chroma: f64,
     _tone: f64,
-    _argb: i64,
+    _argb: Argb,
 }
 
 impl Eq for Hct {}
@@ -27,6 +35,17 @@ impl Hash for Hct {
     }
 }
 
+impl From<Argb> for Hct {
+    fn from(argb: Argb) -> Self {
+        Hct::from_argb(argb)
+    }
+}
+impl From<Hct> for Argb {
+    fn from(hct: Hct) -> Self {
+        hct.to_argb()
+    }
+}
+
 impl Hct {
     /// 0 <= [hue] < 360; invalid values are corrected.
     /// 0 <= [chroma] <= ?; Informally, colorfulness. The color returned may be
@@ -34,17 +53,35 @@ impl Hct {
     ///    given hue and tone.
     /// 0 <= [tone] <= 100; informally, lightness. Invalid values are corrected.
     pub fn from(hue: f64, chroma: f64, tone: f64) -> Hct {
-        let argb = HctSolver::solve_to_int(hue, chroma, tone);
-        return Hct::new(argb);
+        let argb = HctSolver::solve_to_argb(hue, chroma, tone);
+        return Hct::from_argb(argb);
     }
 
     /// HCT representation of [argb].
+    pub fn from_argb(argb: Argb) -> Hct {
+        let cam16 = Cam16::from_argb(argb);
+        Hct {
+            _hue: cam16.hue,
+            _chroma: cam16.chroma,
+            _tone: argb.lstar(),
+            _argb: argb,
+        }
+    }
+
+    /// HCT representation of [argb].
+    #[deprecated = "use `Hct::from_argb` instead"]
     pub fn from_int(argb: i64) -> Hct {
-        return Hct::new(argb);
+        return Hct::from_argb(Argb(argb as u32));
+    }
+
+    /// The ARGB representation of this color.
+    pub fn to_argb(&self) -> Argb {
+        self._argb
     }
 
+    #[deprecated = "use `Hct::to_argb` instead"]
     pub fn to_int(&self) -> i64 {
-        return self._argb;
+        return self._argb.into();
     }
 
     /// A number, in degrees, representing ex. red, orange, yellow, etc.
@@ -59,11 +96,11 @@ impl Hct {
     /// representation. If the HCT color is outside of the sRGB gamut, chroma
     /// will decrease until it is inside the gamut.
     pub fn set_hue(&mut self, new_hue: f64) {
-        self._argb = HctSolver::solve_to_int(new_hue, self.get_chroma(), self.get_tone());
-        let cam16 = Cam16::from_int(self._argb);
+        self._argb = HctSolver::solve_to_argb(new_hue, self.get_chroma(), self.get_tone());
+        let cam16 = Cam16::from_argb(self._argb);
         self._hue = cam16.hue;
         self._chroma = cam16.chroma;
-        self._tone = ColorUtils::lstar_from_argb(self._argb);
+        self._tone = self._argb.lstar();
     }
 
     pub fn get_chroma(&self) -> f64 {
@@ -76,11 +113,11 @@ impl Hct {
     /// representation. If the HCT color is outside of the sRGB gamut, chroma
     /// will decrease until it is inside the gamut.
     pub fn set_chroma(&mut self, new_chroma: f64) {
-        self._argb = HctSolver::solve_to_int(self.get_hue(), new_chroma, self.get_tone());
-        let cam16 = Cam16::from_int(self._argb);
+        self._argb = HctSolver::solve_to_argb(self.get_hue(), new_chroma, self.get_tone());
+        let cam16 = Cam16::from_argb(self._argb);
         self._hue = cam16.hue;
         self._chroma = cam16.chroma;
-        self._tone = ColorUtils::lstar_from_argb(self._argb);
+        self._tone = self._argb.lstar();
     }
 
     /// Lightness. Ranges from 0 to 100.
@@ -94,26 +131,16 @@ impl Hct {
     /// representation. If the HCT color is outside of the sRGB gamut, chroma
     /// will decrease until it is inside the gamut.
     pub fn set_tone(&mut self, new_tone: f64) {
-        self._argb = HctSolver::solve_to_int(self.get_hue(), self.get_chroma(), new_tone);
-        let cam16 = Cam16::from_int(self._argb);
+        self._argb = HctSolver::solve_to_argb(self.get_hue(), self.get_chroma(), new_tone);
+        let cam16 = Cam16::from_argb(self._argb);
         self._hue = cam16.hue;
         self._chroma = cam16.chroma;
-        self._tone = ColorUtils::lstar_from_argb(self._argb);
+        self._tone = self._argb.lstar();
     }
 
+    #[deprecated = "use `Hct::from_argb` instead"]
     pub fn new(argb: i64) -> Hct {
-        let _argb = argb;
-        let cam16 = Cam16::from_int(argb);
-        let _hue = cam16.hue;
-        let _chroma = cam16.chroma;
-        let _tone = ColorUtils::lstar_from_argb(_argb);
-
-        return Hct {
-            _hue,
-            _chroma,
-            _tone,
-            _argb,
-        };
+        return Hct::from_argb(Argb(argb as u32));
     }
     /// Translate a color into different [ViewingConditions].
     ///
@@ -129,7 +156,7 @@ impl Hct {
     /// See [ViewingConditions.make] for parameters affecting color appearance.
     pub fn in_viewing_conditions(&self, vc: &ViewingConditions) -> Hct {
         // 1. Use CAM16 to find XYZ coordinates of color in specified VC.
-        let cam16 = Cam16::from_int(self.to_int());
+        let cam16 = Cam16::from_argb(self.to_argb());
         let viewed_in_vc = cam16.xyz_in_viewing_conditions(vc, None);
 
         // 2. Create CAM16 of those XYZ coordinates in default VC.
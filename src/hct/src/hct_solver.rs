@@ -2,7 +2,7 @@ use std::vec;
 
 use crate::{
     hct::{cam16::Cam16, viewing_conditions::ViewingConditions},
-    utils::{color_utils::ColorUtils, math_utils::MathUtils},
+    utils::{argb::Argb, color_utils::ColorUtils, math_utils::MathUtils},
 };
 
 /// A class that solves the HCT equation.
@@ -563,8 +563,8 @@ impl HctSolver {
     /// Finds a color with the given hue, chroma, and Y.
     ///
     /// Returns a color with the desired [hueRadians], [chroma], and
-    /// [y] as a hexadecimal integer, if found; and returns 0 otherwise.
-    fn _find_result_by_j(hue_radians: f64, chroma: f64, y: f64) -> i64 {
+    /// [y], if found; and returns None otherwise.
+    fn _find_result_by_j(hue_radians: f64, chroma: f64, y: f64) -> Option<Argb> {
         // Initial estimate of j.
         let mut j = (y).sqrt() * 11.0;
         // ===========================================================
@@ -616,26 +616,26 @@ impl HctSolver {
             // Operations inlined from Cam16 to avoid repeated calculation
             // ===========================================================
             if linrgb[0] < 0.0 || linrgb[1] < 0.0 || linrgb[2] < 0.0 {
-                return 0;
+                return None;
             }
             let k_r = Self::_y_from_linrgb()[0];
             let k_g = Self::_y_from_linrgb()[1];
             let k_b = Self::_y_from_linrgb()[2];
             let fnj = k_r * linrgb[0] + k_g * linrgb[1] + k_b * linrgb[2];
             if fnj <= 0.0 {
-                return 0;
+                return None;
             }
             if iteration_round == 4 || (fnj - y).abs() < 0.002 {
                 if linrgb[0] > 100.01 || linrgb[1] > 100.01 || linrgb[2] > 100.01 {
-                    return 0;
+                    return None;
                 }
-                return ColorUtils::argb_from_linrgb(linrgb);
+                return Some(Argb::from_linrgb(&linrgb));
             }
             // Iterates with Newton method,
             // Using 2 * fn(j) / j as the approximation of fn'(j)
             j = j - (fnj - y) * j / (2.0 * fnj);
         }
-        return 0;
+        return None;
     }
 
     /// Finds an sRGB color with the given hue, chroma, and L*, if
@@ -646,19 +646,23 @@ impl HctSolver {
     /// [lstar], respectively. If it is impossible to satisfy all three
     /// constraints, the hue and L* will be sufficiently close, and the
     /// chroma will be maximized.
-    pub fn solve_to_int(hue_degrees: f64, chroma: f64, lstar: f64) -> i64 {
+    pub fn solve_to_argb(hue_degrees: f64, chroma: f64, lstar: f64) -> Argb {
         if chroma < 0.0001 || lstar < 0.0001 || lstar > 99.9999 {
-            return ColorUtils::argb_from_lstar(lstar);
+            return Argb::from_lstar(lstar);
         }
         let hue_degrees = MathUtils::sanitize_degrees_double(hue_degrees);
         let hue_radians = hue_degrees / 180.0 * std::f64::consts::PI;
         let y = ColorUtils::y_from_lstar(lstar);
-        let exact_answer = Self::_find_result_by_j(hue_radians, chroma, y);
-        if exact_answer != 0 {
+        if let Some(exact_answer) = Self::_find_result_by_j(hue_radians, chroma, y) {
             return exact_answer;
         }
         let linrgb = Self::_bisect_to_limit(y, hue_radians);
-        return ColorUtils::argb_from_linrgb(linrgb);
+        return Argb::from_linrgb(&linrgb);
+    }
+
+    #[deprecated = "use `HctSolver::solve_to_argb` instead"]
+    pub fn solve_to_int(hue_degrees: f64, chroma: f64, lstar: f64) -> i64 {
+        return Self::solve_to_argb(hue_degrees, chroma, lstar).into();
     }
 
     /// Finds a CAM16 object with the given hue, chroma, and L*, if
@@ -670,6 +674,6 @@ impl HctSolver {
     /// constraints, the hue and L* will be sufficiently close, and the
     /// chroma will be maximized.
     pub fn solve_to_cam(hue_degrees: f64, chroma: f64, lstar: f64) -> Cam16 {
-        return Cam16::from_int(Self::solve_to_int(hue_degrees, chroma, lstar));
+        return Cam16::from_argb(Self::solve_to_argb(hue_degrees, chroma, lstar));
     }
 }
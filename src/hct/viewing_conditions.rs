@@ -1,4 +1,7 @@
-use crate::utils::{color_utils::ColorUtils, math_utils::MathUtils};
+use crate::{
+    error::error::Error,
+    utils::{color_utils::ColorUtils, math_utils::MathUtils},
+};
 
 /// In traditional color spaces, a color can be identified solely by the
 /// observer's measurement of the color. Color appearance models such as CAM16
@@ -45,6 +48,8 @@ impl ViewingConditions {
     /// [backgroundLstar]: average luminance of 10 degrees around color.
     /// [surround]: brightness of the entire environment.
     /// [discountingIlluminant]: whether eyes have adjusted to lighting.
+    ///
+    /// Panics if [whitePoint] or [surround] are invalid, see [try_make].
     pub fn make(
         white_point: Option<Vec<f64>>,
         adapting_luminance: Option<f64>,
@@ -52,6 +57,28 @@ impl ViewingConditions {
         surround: Option<f64>,
         discounting_illuminant: Option<bool>,
     ) -> ViewingConditions {
+        return Self::try_make(
+            white_point,
+            adapting_luminance,
+            background_lstar,
+            surround,
+            discounting_illuminant,
+        )
+        .unwrap_or_else(|error| panic!("{}", error));
+    }
+
+    /// Convenience constructor for [ViewingConditions], see [make].
+    ///
+    /// Returns [Error::InvalidLength] if [whitePoint] doesn't have 3
+    /// coordinates, and [Error::SurroundOutOfRange] if [surround] is outside
+    /// of 0 to 2.
+    pub fn try_make(
+        white_point: Option<Vec<f64>>,
+        adapting_luminance: Option<f64>,
+        background_lstar: Option<f64>,
+        surround: Option<f64>,
+        discounting_illuminant: Option<bool>,
+    ) -> Result<ViewingConditions, Error> {
         let adapting_luminance = {
             let adapting_luminance = adapting_luminance.unwrap_or(-1.0);
             if adapting_luminance > 0.0 {
@@ -64,6 +91,15 @@ impl ViewingConditions {
         let surround = surround.unwrap_or(2.0);
         let discounting_illuminant = discounting_illuminant.unwrap_or(false);
         let white_point = white_point.unwrap_or(ColorUtils::white_point_d65());
+        if white_point.len() != 3 {
+            return Err(Error::InvalidLength {
+                expected: 3,
+                actual: white_point.len(),
+            });
+        }
+        if !(0.0..=2.0).contains(&surround) {
+            return Err(Error::SurroundOutOfRange(surround));
+        }
 
         // A background of pure black is non-physical and leads to infinities that
         // represent the idea that any color viewed in pure black can't be seen.
@@ -76,7 +112,6 @@ impl ViewingConditions {
         let b_w = xyz[0] * -0.002079 + xyz[1] * 0.048952 + xyz[2] * 0.953127;
 
         // Scale input surround, domain (0, 2), to CAM16 surround, domain (0.8, 1.0)
-        assert!(surround >= 0.0 && surround <= 2.0);
         let f = 0.8 + (surround / 10.0);
         // "Exponential non-linearity"
         let c = {
@@ -162,7 +197,7 @@ impl ViewingConditions {
 
         let aw = (40.0 * rgb_a[0] + 20.0 * rgb_a[1] + rgb_a[2]) / 20.0 * nbb;
 
-        ViewingConditions {
+        Ok(ViewingConditions {
             white_point,
             adapting_luminance,
             background_lstar,
@@ -179,6 +214,6 @@ impl ViewingConditions {
             fl,
             f_lroot: fl.powf(0.25),
             z,
-        }
+        })
     }
 }
@@ -2,15 +2,19 @@ pub mod blend;
 pub mod contrast;
 pub mod dislike;
 pub mod dynamiccolor;
+pub mod error;
 pub mod hct;
 pub mod palettes;
 pub mod quantize;
 pub mod scheme;
 pub mod score;
+pub mod swatch;
 pub mod temperature;
 mod tests;
 pub mod utils;
 
+pub use error::error::Error;
+
 #[cfg(test)]
 pub mod sanity {
     use image::GenericImageView;
@@ -21,16 +25,14 @@ pub mod sanity {
             variant::Variant,
         },
         palettes::core_palette::CorePalette,
-        quantize::{quantizer::Quantizer, quantizer_celebi::QuantizerCelebi},
-        score::score::Score,
-        utils::{color_utils::ColorUtils, string_utils::StringUtils},
+        utils::{argb::Argb, theme_from_image::ThemeFromImage},
     };
 
     #[test]
     fn primary() {
-        let dominant = 0xFF984061;
-        let core = CorePalette::of(dominant);
-        let scheme = DynamicScheme::new(
+        let dominant = Argb(0xFF984061);
+        let core = CorePalette::from_argb(dominant);
+        let scheme = DynamicScheme::from_palettes(
             dominant,
             Variant::TonalSpot,
             None,
@@ -42,43 +44,20 @@ pub mod sanity {
             core.neutral_variant,
         );
         //let scheme = DynamicScheme
-        println!(
-            "{}",
-            StringUtils::hex_from_argb(MaterialDynamicColors::primary().get_argb(&scheme), None)
-        )
+        println!("{}", MaterialDynamicColors::primary().get_color(&scheme))
     }
 
     #[test]
     #[ignore]
     fn surface() {
         let img = image::open("D:\\sample3.jpg").unwrap();
-        let mut pixels: Vec<i64> = Vec::new();
+        let mut pixels: Vec<Argb> = Vec::new();
         for (_x, _y, data) in img.pixels() {
-            pixels.push(ColorUtils::argb_from_rgb(
-                data[0] as i64,
-                data[1] as i64,
-                data[2] as i64,
-            ))
+            pixels.push(Argb::from_rgb(data[0], data[1], data[2]))
         }
-        let qr = QuantizerCelebi {}.quantize(&pixels, 128, None);
-        let score = Score::score(&qr.color_to_count, None, None, None);
-        let dominant = *score.first().unwrap();
-        let core = CorePalette::of(dominant);
-        let scheme = DynamicScheme::new(
-            dominant,
-            Variant::TonalSpot,
-            None,
-            true,
-            core.primary,
-            core.secondary,
-            core.tertiary,
-            core.neutral,
-            core.neutral_variant,
-        );
+        let theme = ThemeFromImage::new().is_dark(true).theme(&pixels);
+        let scheme = theme.scheme;
         //let scheme = DynamicScheme
-        println!(
-            "{}",
-            StringUtils::hex_from_argb(MaterialDynamicColors::primary().get_argb(&scheme), None)
-        )
+        println!("{}", MaterialDynamicColors::primary().get_color(&scheme))
     }
 }
@@ -1,6 +1,6 @@
 use std::hash::Hash;
 
-use crate::hct::cam16::Cam16;
+use crate::{error::error::Error, hct::cam16::Cam16, utils::argb::Argb};
 
 use super::tonal_palette::TonalPalette;
 
@@ -8,6 +8,7 @@ use super::tonal_palette::TonalPalette;
 /// color scheme. 5 tonal palettes are generated, all except one use the same
 /// hue as the key color, and all vary in chroma.
 #[derive(Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CorePalette {
     pub primary: TonalPalette,
     pub secondary: TonalPalette,
@@ -25,11 +26,16 @@ impl CorePalette {
     pub const SIZE: usize = 5;
 
     /// Create a [CorePalette] from a source ARGB color.
-    pub fn of(argb: i64) -> CorePalette {
-        let cam = Cam16::from_int(argb);
+    pub fn from_argb(argb: Argb) -> CorePalette {
+        let cam = Cam16::from_argb(argb);
         return CorePalette::__(cam.hue, cam.chroma);
     }
 
+    #[deprecated = "use `CorePalette::from_argb` instead"]
+    pub fn of(argb: i64) -> CorePalette {
+        return CorePalette::from_argb(Argb(argb as u32));
+    }
+
     fn __(hue: f64, chroma: f64) -> CorePalette {
         CorePalette {
             primary: TonalPalette::of(hue, (48.0f64).max(chroma)),
@@ -42,11 +48,16 @@ impl CorePalette {
     }
 
     /// Create a content [CorePalette] from a source ARGB color.
-    pub fn content_of(argb: i64) -> CorePalette {
-        let cam = Cam16::from_int(argb);
+    pub fn content_from_argb(argb: Argb) -> CorePalette {
+        let cam = Cam16::from_argb(argb);
         return CorePalette::_content_of(cam.hue, cam.chroma);
     }
 
+    #[deprecated = "use `CorePalette::content_from_argb` instead"]
+    pub fn content_of(argb: i64) -> CorePalette {
+        return CorePalette::content_from_argb(Argb(argb as u32));
+    }
+
     fn _content_of(hue: f64, chroma: f64) -> CorePalette {
         CorePalette {
             primary: TonalPalette::of(hue, chroma),
@@ -62,41 +73,87 @@ impl CorePalette {
     /// representing concatenated tonal palettes.
     ///
     /// Inverse of [asList].
+    #[deprecated = "use `CorePalette::from_argb_list` instead"]
     pub fn from_list(colors: &Vec<i64>) -> CorePalette {
-        assert!(colors.len() == Self::SIZE * TonalPalette::COMMON_SIZE);
-        CorePalette {
-            primary: TonalPalette::from_list(&_get_partition(colors, 0, TonalPalette::COMMON_SIZE)),
-            secondary: TonalPalette::from_list(&_get_partition(
+        let colors: Vec<Argb> = colors.iter().map(|argb| Argb(*argb as u32)).collect();
+        return CorePalette::from_argb_list(&colors);
+    }
+
+    /// Create a [CorePalette] from a fixed-size list of ARGB colors
+    /// representing concatenated tonal palettes.
+    ///
+    /// Inverse of [as_argb_list].
+    ///
+    /// Panics if [colors] doesn't have [SIZE] * [TonalPalette::COMMON_SIZE]
+    /// entries, see [try_from_argb_list].
+    pub fn from_argb_list(colors: &[Argb]) -> CorePalette {
+        return CorePalette::try_from_argb_list(colors).unwrap_or_else(|error| panic!("{}", error));
+    }
+
+    /// Create a [CorePalette] from a fixed-size list of ARGB colors
+    /// representing concatenated tonal palettes.
+    ///
+    /// Returns [Error::InvalidLength] if [colors] doesn't have [SIZE] *
+    /// [TonalPalette::COMMON_SIZE] entries.
+    pub fn try_from_argb_list(colors: &[Argb]) -> Result<CorePalette, Error> {
+        if colors.len() != Self::SIZE * TonalPalette::COMMON_SIZE {
+            return Err(Error::InvalidLength {
+                expected: Self::SIZE * TonalPalette::COMMON_SIZE,
+                actual: colors.len(),
+            });
+        }
+        Ok(CorePalette {
+            primary: TonalPalette::from_argb_list(_get_partition(
+                colors,
+                0,
+                TonalPalette::COMMON_SIZE,
+            )),
+            secondary: TonalPalette::from_argb_list(_get_partition(
                 colors,
                 1,
                 TonalPalette::COMMON_SIZE,
             )),
-            tertiary: TonalPalette::from_list(&_get_partition(
+            tertiary: TonalPalette::from_argb_list(_get_partition(
                 colors,
                 2,
                 TonalPalette::COMMON_SIZE,
             )),
-            neutral: TonalPalette::from_list(&_get_partition(colors, 3, TonalPalette::COMMON_SIZE)),
-            neutral_variant: TonalPalette::from_list(&_get_partition(
+            neutral: TonalPalette::from_argb_list(_get_partition(
+                colors,
+                3,
+                TonalPalette::COMMON_SIZE,
+            )),
+            neutral_variant: TonalPalette::from_argb_list(_get_partition(
                 colors,
                 4,
                 TonalPalette::COMMON_SIZE,
             )),
             error: Self::_default_error(),
-        }
+        })
     }
 
     /// Returns a list of ARGB color [int]s from concatenated tonal palettes.
     ///
     /// Inverse of [CorePalette.fromList].
-    pub fn as_list(&mut self) -> Vec<i64> {
-        let mut list: Vec<i64> = Vec::new();
+    #[deprecated = "use `CorePalette::as_argb_list` instead"]
+    pub fn as_list(&self) -> Vec<i64> {
+        self.as_argb_list()
+            .into_iter()
+            .map(|argb| argb.into())
+            .collect()
+    }
+
+    /// Returns a list of ARGB colors from concatenated tonal palettes.
+    ///
+    /// Inverse of [from_argb_list].
+    pub fn as_argb_list(&self) -> Vec<Argb> {
+        let mut list: Vec<Argb> = Vec::new();
 
-        list.extend(self.primary.get_as_list().iter());
-        list.extend(self.secondary.get_as_list().iter());
-        list.extend(self.tertiary.get_as_list().iter());
-        list.extend(self.neutral.get_as_list().iter());
-        list.extend(self.neutral_variant.get_as_list().iter());
+        list.extend(self.primary.get_argb_list().iter());
+        list.extend(self.secondary.get_argb_list().iter());
+        list.extend(self.tertiary.get_argb_list().iter());
+        list.extend(self.neutral.get_argb_list().iter());
+        list.extend(self.neutral_variant.get_argb_list().iter());
 
         return list;
     }
@@ -109,14 +166,12 @@ impl CorePalette {
 //
 // range.getPartition(0, 3) // [1, 2, 3]
 // range.getPartition(1, 3) // [4, 5, 6]
-fn _get_partition(list: &Vec<i64>, partition_number: usize, partition_size: usize) -> Vec<i64> {
+fn _get_partition(list: &[Argb], partition_number: usize, partition_size: usize) -> &[Argb] {
     /* return list.sublist(
         partitionNumber * partitionSize,
         (partitionNumber + 1) * partitionSize,
     ); */
-    let sublist =
-        &list[(partition_number * partition_size)..((partition_number + 1) * partition_size)];
-    return sublist.to_vec();
+    return &list[(partition_number * partition_size)..((partition_number + 1) * partition_size)];
 }
 
 impl ToString for CorePalette {
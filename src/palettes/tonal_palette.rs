@@ -1,6 +1,10 @@
-use crate::hct::hct::Hct;
+use crate::{error::error::Error, hct::hct::Hct, utils::argb::Argb};
 use core::hash::Hash;
-use std::{collections::HashMap, hash::Hasher};
+use std::{
+    collections::HashMap,
+    hash::Hasher,
+    sync::{OnceLock, PoisonError, RwLock},
+};
 
 /// A convenience class for retrieving colors that are constant in hue and
 /// chroma, but vary in tone.
@@ -11,7 +15,16 @@ use std::{collections::HashMap, hash::Hasher};
 /// representing ARBG colors. Correctness (constant hue and chroma) of the input
 /// is not enforced. [get] will only return the input colors, corresponding to
 /// [commonTones]. This also initializes the key color to black.
-#[derive(Clone, Debug)]
+///
+/// With the `serde` feature, a palette is serialized as its `hue`, `chroma`
+/// and `key_color`. Palettes created from a list also carry `colors`, the
+/// [COMMON_SIZE] colors they were created from.
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "TonalPaletteData", into = "TonalPaletteData")
+)]
 pub struct TonalPalette {
     pub hue: f64,
     pub chroma: f64,
@@ -19,8 +32,14 @@ pub struct TonalPalette {
     /// A cache containing keys-value pairs where:
     /// - keys are integers that represent tones, and
     /// - values are colors in ARGB format.
-    pub _cache: HashMap<i64, i64>,
+    ///
+    /// Behind a lock so tones can be cached through `&self`, letting palettes
+    /// be shared across threads.
+    pub _cache: RwLock<HashMap<i64, Argb>>,
     pub _is_from_cache: bool,
+    /// The results of [max_chroma_tone], searching from the lightest and from
+    /// the darkest tone.
+    _max_chroma_tones: [OnceLock<f64>; 2],
 }
 
 impl TonalPalette {
@@ -31,11 +50,12 @@ impl TonalPalette {
 
     fn _from_hct(hct: &Hct) -> TonalPalette {
         TonalPalette {
-            _cache: HashMap::new(),
+            _cache: RwLock::new(HashMap::new()),
             hue: hct.get_hue(),
             chroma: hct.get_chroma(),
             key_color: hct.clone(),
             _is_from_cache: false,
+            _max_chroma_tones: Default::default(),
         }
     }
 
@@ -43,19 +63,21 @@ impl TonalPalette {
         TonalPalette {
             hue,
             chroma,
-            _cache: HashMap::new(),
+            _cache: RwLock::new(HashMap::new()),
             key_color: Self::create_key_color(hue, chroma),
             _is_from_cache: false,
+            _max_chroma_tones: Default::default(),
         }
     }
 
-    fn _from_cache(cache: &HashMap<i64, i64>, hue: f64, chroma: f64) -> TonalPalette {
+    fn _from_cache(cache: &HashMap<i64, Argb>, hue: f64, chroma: f64) -> TonalPalette {
         TonalPalette {
             hue,
             chroma,
-            _cache: cache.clone(),
+            _cache: RwLock::new(cache.clone()),
             key_color: Self::create_key_color(hue, chroma),
             _is_from_cache: true,
+            _max_chroma_tones: Default::default(),
         }
     }
 
@@ -72,20 +94,36 @@ impl TonalPalette {
     /// Create colors from a fixed-size list of ARGB color ints.
     ///
     /// Inverse of [TonalPalette.asList].
+    #[deprecated = "use `TonalPalette::from_argb_list` instead"]
     pub fn from_list(colors: &Vec<i64>) -> TonalPalette {
-        assert!(colors.len() == Self::COMMON_SIZE);
-        let mut cache: HashMap<i64, i64> = HashMap::new();
+        let colors: Vec<Argb> = colors.iter().map(|argb| Argb(*argb as u32)).collect();
+        return TonalPalette::from_argb_list(&colors);
+    }
 
-        for (index, tone_value) in Self::COMMON_TONES.iter().enumerate() {
-            cache.insert(*tone_value, colors[index]);
-        }
+    /// Create colors from a fixed-size list of ARGB colors.
+    ///
+    /// Inverse of [get_argb_list].
+    ///
+    /// Panics if [colors] doesn't have [COMMON_SIZE] entries, see
+    /// [try_from_argb_list].
+    pub fn from_argb_list(colors: &[Argb]) -> TonalPalette {
+        return TonalPalette::try_from_argb_list(colors)
+            .unwrap_or_else(|error| panic!("{}", error));
+    }
+
+    /// Create colors from a fixed-size list of ARGB colors.
+    ///
+    /// Returns [Error::InvalidLength] if [colors] doesn't have [COMMON_SIZE]
+    /// entries.
+    pub fn try_from_argb_list(colors: &[Argb]) -> Result<TonalPalette, Error> {
+        let cache = Self::_cache_from_argb_list(colors)?;
         // Approximately deduces the original hue and chroma that generated this
         // list of colors.
         // Uses the hue and chroma of the provided color with the highest chroma.
         let mut best_hue = 0.0;
         let mut best_chroma = 0.0;
         for argb in colors {
-            let hct = Hct::from_int(*argb);
+            let hct = Hct::from_argb(*argb);
 
             // If the color is too close to white, its chroma may have been
             // affected by a known issue, so we ignore it.
@@ -101,7 +139,23 @@ impl TonalPalette {
             }
         }
 
-        return TonalPalette::_from_cache(&cache, best_hue, best_chroma);
+        return Ok(TonalPalette::_from_cache(&cache, best_hue, best_chroma));
+    }
+
+    /// The [COMMON_TONES] of [colors], keyed by tone.
+    fn _cache_from_argb_list(colors: &[Argb]) -> Result<HashMap<i64, Argb>, Error> {
+        if colors.len() != Self::COMMON_SIZE {
+            return Err(Error::InvalidLength {
+                expected: Self::COMMON_SIZE,
+                actual: colors.len(),
+            });
+        }
+        let mut cache: HashMap<i64, Argb> = HashMap::new();
+
+        for (index, tone_value) in Self::COMMON_TONES.iter().enumerate() {
+            cache.insert(*tone_value, colors[index]);
+        }
+        return Ok(cache);
     }
 
     /// Creates a key color from a [hue] and a [chroma].
@@ -148,10 +202,21 @@ impl TonalPalette {
     /// Returns a fixed-size list of ARGB color ints for common tone values.
     ///
     /// Inverse of [fromList].
-    pub fn get_as_list(&mut self) -> Vec<i64> {
+    #[deprecated = "use `TonalPalette::get_argb_list` instead"]
+    pub fn get_as_list(&self) -> Vec<i64> {
+        self.get_argb_list()
+            .into_iter()
+            .map(|argb| argb.into())
+            .collect()
+    }
+
+    /// Returns a fixed-size list of ARGB colors for common tone values.
+    ///
+    /// Inverse of [from_argb_list].
+    pub fn get_argb_list(&self) -> Vec<Argb> {
         Self::COMMON_TONES
             .iter()
-            .map(|tone| self.get(*tone))
+            .map(|tone| self.get_argb(*tone))
             .collect()
     }
 
@@ -164,11 +229,64 @@ impl TonalPalette {
     ///
     /// If the palette is constructed from a hue and chroma (i.e. using [of] or
     /// [fromHct]), the result is generated from the given [hue] and [chroma].
-    pub fn get(&mut self, tone: i64) -> i64 {
-        *self
+    pub fn get_argb(&self, tone: i64) -> Argb {
+        let cached = self
+            ._cache
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&tone)
+            .copied();
+        if let Some(argb) = cached {
+            return argb;
+        }
+        let argb = Hct::from(self.hue, self.chroma, tone as f64).to_argb();
+        return *self
             ._cache
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
             .entry(tone)
-            .or_insert_with(|| Hct::from(self.hue, self.chroma, tone as f64).to_int())
+            .or_insert(argb);
+    }
+
+    #[deprecated = "use `TonalPalette::get_argb` instead"]
+    pub fn get(&self, tone: i64) -> i64 {
+        self.get_argb(tone).into()
+    }
+
+    /// The tone where the palette reaches its chroma, or comes closest to it,
+    /// searching from the lightest tone if [by_decreasing_tone], or from the
+    /// darkest one.
+    ///
+    /// A search solves up to a hundred colors, so its result is cached.
+    pub fn max_chroma_tone(&self, by_decreasing_tone: bool) -> f64 {
+        let index = if by_decreasing_tone { 0 } else { 1 };
+        return *self._max_chroma_tones[index].get_or_init(|| {
+            let start_tone = if by_decreasing_tone { 100.0 } else { 0.0 };
+            Self::_find_best_tone_for_chroma(self.hue, self.chroma, start_tone, by_decreasing_tone)
+        });
+    }
+
+    fn _find_best_tone_for_chroma(
+        hue: f64,
+        chroma: f64,
+        tone: f64,
+        by_decreasing_tone: bool,
+    ) -> f64 {
+        let mut tone = tone;
+        let mut answer = tone;
+        let mut best_candidate = Hct::from(hue, chroma, answer);
+        while best_candidate.get_chroma() < chroma {
+            if !(0.0..=100.0).contains(&tone) {
+                break;
+            }
+            tone += if by_decreasing_tone { -1.0 } else { 1.0 };
+            let new_candidate = Hct::from(hue, chroma, tone);
+            if best_candidate.get_chroma() < new_candidate.get_chroma() {
+                best_candidate = new_candidate;
+                answer = tone;
+            }
+        }
+        return answer;
     }
 
     /// Returns the HCT color at the given [tone].
@@ -181,25 +299,94 @@ impl TonalPalette {
     /// If the palette is constructed from a hue and chroma (i.e. using [of] or
     /// [fromHct]), the result is generated from the given [hue] and [chroma].
     pub fn get_hct(&self, tone: f64) -> Hct {
-        let maybe_hct_int = self._cache.get(&(tone as i64));
+        let maybe_argb = self
+            ._cache
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&(tone as i64))
+            .copied();
 
-        match maybe_hct_int {
-            Some(hct_int) => Hct::from_int(*hct_int),
+        match maybe_argb {
+            Some(argb) => Hct::from_argb(argb),
             None => Hct::from(self.hue, self.chroma, tone),
         }
     }
 }
 
+/// The serialized form of a [TonalPalette].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct TonalPaletteData {
+    hue: f64,
+    chroma: f64,
+    key_color: Hct,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    colors: Option<Vec<Argb>>,
+}
+
+#[cfg(feature = "serde")]
+impl From<TonalPalette> for TonalPaletteData {
+    fn from(palette: TonalPalette) -> Self {
+        let colors = if palette._is_from_cache {
+            Some(palette.get_argb_list())
+        } else {
+            None
+        };
+        TonalPaletteData {
+            hue: palette.hue,
+            chroma: palette.chroma,
+            key_color: palette.key_color,
+            colors,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<TonalPaletteData> for TonalPalette {
+    type Error = Error;
+
+    /// Restores the serialized fields as they are, without searching for a
+    /// key color or deducing the hue and chroma of [TonalPaletteData::colors].
+    fn try_from(data: TonalPaletteData) -> Result<Self, Self::Error> {
+        let cache = match &data.colors {
+            Some(colors) => TonalPalette::_cache_from_argb_list(colors)?,
+            None => HashMap::new(),
+        };
+        return Ok(TonalPalette {
+            hue: data.hue,
+            chroma: data.chroma,
+            key_color: data.key_color,
+            _cache: RwLock::new(cache),
+            _is_from_cache: data.colors.is_some(),
+            _max_chroma_tones: Default::default(),
+        });
+    }
+}
+
+impl Clone for TonalPalette {
+    fn clone(&self) -> Self {
+        TonalPalette {
+            hue: self.hue,
+            chroma: self.chroma,
+            key_color: self.key_color.clone(),
+            _cache: RwLock::new(
+                self._cache
+                    .read()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .clone(),
+            ),
+            _is_from_cache: self._is_from_cache,
+            _max_chroma_tones: self._max_chroma_tones.clone(),
+        }
+    }
+}
+
 impl ToString for TonalPalette {
     fn to_string(&self) -> String {
-        let mut mutable_clone = self.clone();
         if self._is_from_cache {
-            return format!(
-                "TonalPalette.of({}, {})",
-                mutable_clone.hue, mutable_clone.chroma
-            );
+            return format!("TonalPalette.of({}, {})", self.hue, self.chroma);
         } else {
-            let list = mutable_clone.get_as_list();
+            let list = self.get_argb_list();
             let mp: Vec<String> = list.iter().map(|it| it.to_string()).collect();
             return format!("TonalPalette.fromList([{}])", mp.join(", "));
         }
@@ -213,9 +400,7 @@ impl PartialEq for TonalPalette {
             // Both created with .of or .fromHct
             return self.hue == other.hue && self.chroma == other.chroma;
         } else {
-            let mut self_cpy = self.clone();
-            let mut other_cpy = other.clone();
-            return ListEquality::equals(&self_cpy.get_as_list(), &other_cpy.get_as_list());
+            return ListEquality::equals(&self.get_argb_list(), &other.get_argb_list());
         }
     }
 }
@@ -225,15 +410,15 @@ impl Hash for TonalPalette {
             self.hue.to_ne_bytes().hash(state);
             self.chroma.to_ne_bytes().hash(state);
         } else {
-            let list = self.clone().get_as_list();
-            hash_vec_i64(&list, state);
+            let list = self.get_argb_list();
+            hash_vec_argb(&list, state);
         }
     }
 }
 
 struct ListEquality {}
 impl ListEquality {
-    pub fn equals(a: &Vec<i64>, b: &Vec<i64>) -> bool {
+    pub fn equals(a: &Vec<Argb>, b: &Vec<Argb>) -> bool {
         if a.len() == b.len() {
             for (ela, elb) in a.iter().zip(b.iter()) {
                 if ela != elb {
@@ -247,7 +432,7 @@ impl ListEquality {
     }
 }
 
-fn hash_vec_i64<H: Hasher>(vec: &Vec<i64>, state: &mut H) {
+fn hash_vec_argb<H: Hasher>(vec: &Vec<Argb>, state: &mut H) {
     for element in vec {
         element.hash(state);
     }
@@ -0,0 +1,132 @@
+use std::ops::Deref;
+
+use image::{imageops::FilterType, DynamicImage, GenericImageView, RgbaImage};
+
+use crate::utils::argb::Argb;
+
+use super::quantizer::{Quantizer, QuantizerResult};
+
+/// How an image is reduced before its pixels are quantized.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ImageSampling {
+    /// Every pixel of the image.
+    #[default]
+    Full,
+    /// Resizes the image with [filter], keeping its aspect ratio, so that
+    /// neither side exceeds [max_dimension]. Smaller images are kept as is.
+    Resize {
+        max_dimension: u32,
+        filter: FilterType,
+    },
+    /// Every n-th pixel of the image, in row-major order.
+    Stride(usize),
+}
+
+/// The opaque pixels of an image, as [Argb] colors.
+///
+/// Like [QuantizerMap](super::quantizer_map::QuantizerMap), pixels that aren't
+/// fully opaque are skipped. [ImagePixels] dereferences to `[Argb]`, so it can
+/// be passed to any [Quantizer] or to
+/// [ThemeFromImage](crate::utils::theme_from_image::ThemeFromImage).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImagePixels(pub Vec<Argb>);
+
+impl ImagePixels {
+    /// The pixels of [image], reduced as described by [sampling].
+    pub fn from_rgba_image(image: &RgbaImage, sampling: ImageSampling) -> ImagePixels {
+        if let ImageSampling::Resize {
+            max_dimension,
+            filter,
+        } = sampling
+        {
+            if let Some((width, height)) = Self::_resized_dimensions(image, max_dimension) {
+                let resized = image::imageops::resize(image, width, height, filter);
+                return Self::from_rgba_image(&resized, ImageSampling::Full);
+            }
+        }
+        let step = match sampling {
+            ImageSampling::Stride(step) => step.max(1),
+            _ => 1,
+        };
+        let pixels = image
+            .pixels()
+            .step_by(step)
+            .filter(|pixel| pixel[3] == 255)
+            .map(|pixel| Argb::new(pixel[3], pixel[0], pixel[1], pixel[2]))
+            .collect();
+        return ImagePixels(pixels);
+    }
+
+    /// The pixels of [image], reduced as described by [sampling].
+    ///
+    /// Images are resized before they are converted to 8-bit RGBA.
+    pub fn from_image(image: &DynamicImage, sampling: ImageSampling) -> ImagePixels {
+        if let ImageSampling::Resize {
+            max_dimension,
+            filter,
+        } = sampling
+        {
+            if let Some((width, height)) = Self::_resized_dimensions(image, max_dimension) {
+                let resized = image.resize_exact(width, height, filter);
+                return Self::from_rgba_image(&resized.to_rgba8(), ImageSampling::Full);
+            }
+        }
+        return Self::from_rgba_image(&image.to_rgba8(), sampling);
+    }
+
+    /// The dimensions of [image] once it fits in a [max_dimension] square, or
+    /// [None] if it already fits.
+    fn _resized_dimensions<I: GenericImageView>(
+        image: &I,
+        max_dimension: u32,
+    ) -> Option<(u32, u32)> {
+        let (width, height) = image.dimensions();
+        let max_dimension = max_dimension.max(1);
+        if width <= max_dimension && height <= max_dimension {
+            return None;
+        }
+        let scale = max_dimension as f64 / width.max(height) as f64;
+        let width = ((width as f64 * scale).round() as u32).max(1);
+        let height = ((height as f64 * scale).round() as u32).max(1);
+        return Some((width, height));
+    }
+}
+
+impl Deref for ImagePixels {
+    type Target = [Argb];
+
+    fn deref(&self) -> &[Argb] {
+        &self.0
+    }
+}
+
+impl From<&RgbaImage> for ImagePixels {
+    fn from(image: &RgbaImage) -> Self {
+        Self::from_rgba_image(image, ImageSampling::Full)
+    }
+}
+
+impl From<&DynamicImage> for ImagePixels {
+    fn from(image: &DynamicImage) -> Self {
+        Self::from_image(image, ImageSampling::Full)
+    }
+}
+
+/// Quantizes images with any [Quantizer].
+pub trait QuantizeImage {
+    /// Quantizes the opaque pixels of [image], see [ImagePixels].
+    ///
+    /// Pass [ImagePixels::from_image] to resize or sample the image first.
+    fn quantize_image(&mut self, image: impl Into<ImagePixels>, max_colors: i64)
+        -> QuantizerResult;
+}
+
+impl<Q: Quantizer> QuantizeImage for Q {
+    fn quantize_image(
+        &mut self,
+        image: impl Into<ImagePixels>,
+        max_colors: i64,
+    ) -> QuantizerResult {
+        return self.quantize(&image.into(), max_colors, None);
+    }
+}
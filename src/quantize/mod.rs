@@ -1,6 +1,14 @@
+#[cfg(feature = "image")]
+pub mod image_pixels;
+pub mod pixel_buffer;
+pub mod pixel_histogram;
 pub mod quantizer;
 pub mod quantizer_celebi;
 pub mod quantizer_map;
+pub mod quantizer_median_cut;
+pub mod quantizer_octree;
+pub mod quantizer_session;
 pub mod quantizer_wsmeans;
 pub mod quantizer_wu;
+pub mod remapper;
 pub mod src;
@@ -0,0 +1,116 @@
+use crate::{error::error::Error, utils::argb::Argb};
+
+/// The layout of a pixel in a [PixelBuffer].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PixelFormat {
+    /// Red, green, blue and alpha bytes.
+    Rgba8,
+    /// Blue, green, red and alpha bytes.
+    Bgra8,
+    /// Red, green and blue bytes, always opaque.
+    Rgb8,
+}
+
+impl PixelFormat {
+    /// The number of bytes of a pixel.
+    pub const fn bytes_per_pixel(self) -> usize {
+        match self {
+            PixelFormat::Rgba8 | PixelFormat::Bgra8 => 4,
+            PixelFormat::Rgb8 => 3,
+        }
+    }
+
+    /// The color of the pixel in [bytes], which must be
+    /// [bytes_per_pixel] long.
+    pub fn to_argb(self, bytes: &[u8]) -> Argb {
+        return match self {
+            PixelFormat::Rgba8 => Argb::new(bytes[3], bytes[0], bytes[1], bytes[2]),
+            PixelFormat::Bgra8 => Argb::new(bytes[3], bytes[2], bytes[1], bytes[0]),
+            PixelFormat::Rgb8 => Argb::from_rgb(bytes[0], bytes[1], bytes[2]),
+        };
+    }
+}
+
+/// Borrowed rows of pixels, such as a frame buffer, read as [Argb] colors
+/// without converting the whole buffer.
+///
+/// Rows are [stride] bytes apart, which may be more than the bytes of
+/// [width] pixels. The last row doesn't need its padding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PixelBuffer<'a> {
+    bytes: &'a [u8],
+    format: PixelFormat,
+    width: usize,
+    stride: usize,
+}
+
+impl<'a> PixelBuffer<'a> {
+    /// A buffer of [width] pixels per row in [format].
+    ///
+    /// [stride] defaults to tightly packed rows. Returns an error if [stride]
+    /// is shorter than a row, or if [bytes] ends within a row.
+    pub fn new(
+        bytes: &'a [u8],
+        format: PixelFormat,
+        width: usize,
+        stride: Option<usize>,
+    ) -> Result<PixelBuffer<'a>, Error> {
+        let row_bytes = width * format.bytes_per_pixel();
+        let stride = stride.unwrap_or(row_bytes);
+        if stride < row_bytes || stride == 0 {
+            return Err(Error::InvalidStride { stride, row_bytes });
+        }
+        let remainder = bytes.len() % stride;
+        if remainder != 0 && remainder < row_bytes {
+            return Err(Error::InvalidLength {
+                expected: bytes.len() - remainder + row_bytes,
+                actual: bytes.len(),
+            });
+        }
+        return Ok(PixelBuffer {
+            bytes,
+            format,
+            width,
+            stride,
+        });
+    }
+
+    pub fn format(&self) -> PixelFormat {
+        return self.format;
+    }
+
+    /// The number of pixels per row.
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    /// The number of bytes from the start of a row to the start of the next.
+    pub fn stride(&self) -> usize {
+        return self.stride;
+    }
+
+    /// The number of rows.
+    pub fn height(&self) -> usize {
+        return self.bytes.len().div_ceil(self.stride);
+    }
+
+    /// The number of pixels.
+    pub fn len(&self) -> usize {
+        return self.width * self.height();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+
+    /// Every pixel, row by row, skipping row padding.
+    pub fn pixels(&self) -> impl Iterator<Item = Argb> + 'a {
+        let format = self.format;
+        let row_bytes = self.width * format.bytes_per_pixel();
+        self.bytes.chunks(self.stride).flat_map(move |row| {
+            row[..row_bytes]
+                .chunks_exact(format.bytes_per_pixel())
+                .map(move |pixel| format.to_argb(pixel))
+        })
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+
+use crate::{error::error::Error, utils::argb::Argb};
+
+/// The distinct colors of an image with how often each appears, in the order
+/// they first appear.
+///
+/// Quantizers only depend on the distinct colors of an image and their
+/// counts, so a histogram can be built while pixels are read, without
+/// collecting them first. The first-appearance order keeps quantizers that
+/// pick random starting points, such as
+/// [QuantizerWsmeans](super::quantizer_wsmeans::QuantizerWsmeans),
+/// deterministic.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PixelHistogram {
+    _colors: Vec<Argb>,
+    _counts: Vec<i64>,
+    _index_by_color: HashMap<Argb, usize>,
+}
+
+impl PixelHistogram {
+    pub fn new() -> PixelHistogram {
+        return PixelHistogram::default();
+    }
+
+    /// Counts each of [pixels] as many times as its weight in [weights], a
+    /// mask such as a saliency map, so that heavier pixels weigh more in
+    /// quantization and in the populations given to
+    /// [Score](crate::score::score::Score).
+    ///
+    /// Pixels with a weight of 0 are left out. Returns
+    /// [Error::InvalidLength] if [weights] isn't as long as [pixels].
+    pub fn from_weighted(pixels: &[Argb], weights: &[u8]) -> Result<PixelHistogram, Error> {
+        if pixels.len() != weights.len() {
+            return Err(Error::InvalidLength {
+                expected: pixels.len(),
+                actual: weights.len(),
+            });
+        }
+        let mut histogram = PixelHistogram::new();
+        for (pixel, weight) in pixels.iter().zip(weights) {
+            if *weight > 0 {
+                histogram.add_count(*pixel, *weight as i64);
+            }
+        }
+        return Ok(histogram);
+    }
+
+    /// Counts the opaque color of each of [pixels] as many times as its
+    /// alpha, so translucent pixels weigh less instead of being dropped.
+    ///
+    /// Transparent pixels are left out.
+    pub fn from_alpha_weighted(pixels: &[Argb]) -> PixelHistogram {
+        let mut histogram = PixelHistogram::new();
+        for pixel in pixels {
+            if pixel.alpha() > 0 {
+                histogram.add_count(
+                    Argb::from_rgb(pixel.red(), pixel.green(), pixel.blue()),
+                    pixel.alpha() as i64,
+                );
+            }
+        }
+        return histogram;
+    }
+
+    /// Counts one more pixel of [color].
+    pub fn add(&mut self, color: Argb) {
+        self.add_count(color, 1);
+    }
+
+    /// Counts [count] more pixels of [color].
+    pub fn add_count(&mut self, color: Argb, count: i64) {
+        match self._index_by_color.get(&color) {
+            Some(index) => self._counts[*index] += count,
+            None => {
+                self._index_by_color.insert(color, self._colors.len());
+                self._colors.push(color);
+                self._counts.push(count);
+            }
+        }
+    }
+
+    /// The number of distinct colors.
+    pub fn len(&self) -> usize {
+        return self._colors.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self._colors.is_empty();
+    }
+
+    /// The distinct colors, in the order they first appeared.
+    pub fn colors(&self) -> &[Argb] {
+        return &self._colors;
+    }
+
+    /// The count of each of [colors].
+    pub fn counts(&self) -> &[i64] {
+        return &self._counts;
+    }
+
+    /// How often [color] appeared.
+    pub fn count_of(&self, color: Argb) -> i64 {
+        return match self._index_by_color.get(&color) {
+            Some(index) => self._counts[*index],
+            None => 0,
+        };
+    }
+
+    /// The number of pixels counted.
+    pub fn pixel_count(&self) -> i64 {
+        return self._counts.iter().sum();
+    }
+
+    /// Every distinct color with its count, in the order they first appeared.
+    pub fn iter(&self) -> impl Iterator<Item = (Argb, i64)> + '_ {
+        self._colors
+            .iter()
+            .copied()
+            .zip(self._counts.iter().copied())
+    }
+}
+
+impl Extend<Argb> for PixelHistogram {
+    fn extend<T: IntoIterator<Item = Argb>>(&mut self, iter: T) {
+        for color in iter {
+            self.add(color);
+        }
+    }
+}
+
+impl FromIterator<Argb> for PixelHistogram {
+    fn from_iter<T: IntoIterator<Item = Argb>>(iter: T) -> Self {
+        let mut histogram = PixelHistogram::new();
+        histogram.extend(iter);
+        histogram
+    }
+}
@@ -1,22 +1,117 @@
 use std::collections::HashMap;
 
+use crate::{error::error::Error, utils::argb::Argb};
+
+use super::{pixel_buffer::PixelBuffer, pixel_histogram::PixelHistogram};
+
 pub trait Quantizer {
     fn quantize(
         &mut self,
-        pixels: &Vec<i64>,
+        pixels: &[Argb],
         max_colors: i64,
         return_input_pixel_to_cluster_pixel: Option<bool>,
     ) -> QuantizerResult;
+
+    /// Quantizes the pixels counted in [histogram], with the same result as
+    /// quantizing the pixels themselves.
+    ///
+    /// The default implementation expands [histogram] back into pixels.
+    fn quantize_histogram(
+        &mut self,
+        histogram: &PixelHistogram,
+        max_colors: i64,
+        return_input_pixel_to_cluster_pixel: Option<bool>,
+    ) -> QuantizerResult {
+        let pixels: Vec<Argb> = histogram
+            .iter()
+            .flat_map(|(color, count)| std::iter::repeat_n(color, count as usize))
+            .collect();
+        return self.quantize(&pixels, max_colors, return_input_pixel_to_cluster_pixel);
+    }
+
+    /// Quantizes [pixels] with each pixel counted as many times as its weight
+    /// in [weights], see [PixelHistogram::from_weighted].
+    ///
+    /// The populations of the result are sums of weights.
+    fn quantize_weighted(
+        &mut self,
+        pixels: &[Argb],
+        weights: &[u8],
+        max_colors: i64,
+        return_input_pixel_to_cluster_pixel: Option<bool>,
+    ) -> Result<QuantizerResult, Error> {
+        let histogram = PixelHistogram::from_weighted(pixels, weights)?;
+        return Ok(self.quantize_histogram(
+            &histogram,
+            max_colors,
+            return_input_pixel_to_cluster_pixel,
+        ));
+    }
+
+    /// Quantizes the opaque colors of [pixels] with each pixel counted as many
+    /// times as its alpha, see [PixelHistogram::from_alpha_weighted].
+    ///
+    /// The input colors of [QuantizerResult::input_pixel_to_cluster_pixel] are
+    /// opaque.
+    fn quantize_alpha_weighted(
+        &mut self,
+        pixels: &[Argb],
+        max_colors: i64,
+        return_input_pixel_to_cluster_pixel: Option<bool>,
+    ) -> QuantizerResult {
+        let histogram = PixelHistogram::from_alpha_weighted(pixels);
+        return self.quantize_histogram(
+            &histogram,
+            max_colors,
+            return_input_pixel_to_cluster_pixel,
+        );
+    }
+
+    /// Quantizes [pixels] without collecting them, counting their colors as
+    /// they are read.
+    fn quantize_iter<I: IntoIterator<Item = Argb>>(
+        &mut self,
+        pixels: I,
+        max_colors: i64,
+        return_input_pixel_to_cluster_pixel: Option<bool>,
+    ) -> QuantizerResult
+    where
+        Self: Sized,
+    {
+        let histogram: PixelHistogram = pixels.into_iter().collect();
+        return self.quantize_histogram(
+            &histogram,
+            max_colors,
+            return_input_pixel_to_cluster_pixel,
+        );
+    }
+
+    /// Quantizes the pixels of [buffer] without converting it.
+    fn quantize_bytes(
+        &mut self,
+        buffer: &PixelBuffer,
+        max_colors: i64,
+        return_input_pixel_to_cluster_pixel: Option<bool>,
+    ) -> QuantizerResult
+    where
+        Self: Sized,
+    {
+        return self.quantize_iter(
+            buffer.pixels(),
+            max_colors,
+            return_input_pixel_to_cluster_pixel,
+        );
+    }
 }
 
 pub struct QuantizerResult {
-    pub color_to_count: HashMap<i64, i64>,
-    pub input_pixel_to_cluster_pixel: HashMap<i64, i64>,
+    pub color_to_count: HashMap<Argb, i64>,
+    pub input_pixel_to_cluster_pixel: HashMap<Argb, Argb>,
 }
 impl QuantizerResult {
     pub fn new(
-        color_to_count: HashMap<i64, i64>,
-        input_pixel_to_cluster_pixel: Option<HashMap<i64, i64>>,
+        color_to_count: HashMap<Argb, i64>,
+        input_pixel_to_cluster_pixel: Option<HashMap<Argb, Argb>>,
     ) -> QuantizerResult {
         let input_pixel_to_cluster_pixel = input_pixel_to_cluster_pixel.unwrap_or(HashMap::new());
         QuantizerResult {
@@ -1,28 +1,94 @@
-use crate::quantize::{
-    quantizer_wsmeans::QuantizerWsmeans, quantizer_wu::QuantizerWu,
-    src::point_provider_lab::PointProviderLab,
+use crate::{
+    quantize::{
+        quantizer_map::QuantizerMap,
+        quantizer_wsmeans::{QuantizerWsmeans, WsmeansOptions},
+        quantizer_wu::QuantizerWu,
+        src::point_provider_lab::PointProviderLab,
+    },
+    utils::argb::Argb,
 };
 
-use super::quantizer::{Quantizer, QuantizerResult};
+use super::{
+    pixel_histogram::PixelHistogram,
+    quantizer::{Quantizer, QuantizerResult},
+};
 
+/// Quantizes with [QuantizerWu], then refines the result with
+/// [QuantizerWsmeans], starting from the Wu colors in the order Wu created
+/// them so that results are deterministic.
 pub struct QuantizerCelebi {}
 impl Quantizer for QuantizerCelebi {
     fn quantize(
         &mut self,
-        pixels: &Vec<i64>,
+        pixels: &[Argb],
+        max_colors: i64,
+        return_input_pixel_to_cluster_pixel: Option<bool>,
+    ) -> QuantizerResult {
+        return self.quantize_with_options(
+            pixels,
+            max_colors,
+            &WsmeansOptions::default(),
+            return_input_pixel_to_cluster_pixel,
+        );
+    }
+
+    fn quantize_histogram(
+        &mut self,
+        histogram: &PixelHistogram,
+        max_colors: i64,
+        return_input_pixel_to_cluster_pixel: Option<bool>,
+    ) -> QuantizerResult {
+        return self.quantize_histogram_with_options(
+            histogram,
+            max_colors,
+            &WsmeansOptions::default(),
+            return_input_pixel_to_cluster_pixel,
+        );
+    }
+}
+
+impl QuantizerCelebi {
+    /// Like [quantize], refining the Wu colors with [options].
+    pub fn quantize_with_options(
+        &mut self,
+        pixels: &[Argb],
         max_colors: i64,
+        options: &WsmeansOptions,
         return_input_pixel_to_cluster_pixel: Option<bool>,
     ) -> QuantizerResult {
         let return_input_pixel_to_cluster_pixel =
             return_input_pixel_to_cluster_pixel.unwrap_or(false);
-        let mut wu = QuantizerWu::new();
-        let wu_result = wu.quantize(pixels, max_colors, None);
-        let wsmeans_result = QuantizerWsmeans::quantize(
+        let map_result = QuantizerMap {}.quantize(pixels, max_colors, None);
+        let wu_colors = QuantizerWu::new().quantize_colors(map_result.color_to_count, max_colors);
+        let wsmeans_result = QuantizerWsmeans::quantize_with_options(
             pixels,
             max_colors,
-            Some(&wu_result.color_to_count.keys().cloned().collect()),
+            Some(&wu_colors),
+            Some(&PointProviderLab::new()),
+            options,
+            Some(return_input_pixel_to_cluster_pixel),
+        );
+        return wsmeans_result;
+    }
+
+    /// Like [quantize_histogram], refining the Wu colors with [options].
+    pub fn quantize_histogram_with_options(
+        &mut self,
+        histogram: &PixelHistogram,
+        max_colors: i64,
+        options: &WsmeansOptions,
+        return_input_pixel_to_cluster_pixel: Option<bool>,
+    ) -> QuantizerResult {
+        let return_input_pixel_to_cluster_pixel =
+            return_input_pixel_to_cluster_pixel.unwrap_or(false);
+        let map_result = QuantizerMap {}.quantize_histogram(histogram, max_colors, None);
+        let wu_colors = QuantizerWu::new().quantize_colors(map_result.color_to_count, max_colors);
+        let wsmeans_result = QuantizerWsmeans::quantize_histogram(
+            histogram,
+            max_colors,
+            Some(&wu_colors),
             Some(&PointProviderLab::new()),
-            None,
+            options,
             Some(return_input_pixel_to_cluster_pixel),
         );
         return wsmeans_result;
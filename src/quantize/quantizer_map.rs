@@ -1,20 +1,18 @@
 use std::collections::HashMap;
 
-use crate::utils::color_utils::ColorUtils;
+use crate::utils::argb::Argb;
 
-use super::quantizer::{Quantizer, QuantizerResult};
+use super::{
+    pixel_histogram::PixelHistogram,
+    quantizer::{Quantizer, QuantizerResult},
+};
 
 pub struct QuantizerMap {}
 impl Quantizer for QuantizerMap {
-    fn quantize(
-        &mut self,
-        pixels: &Vec<i64>,
-        _max_colors: i64,
-        _: Option<bool>,
-    ) -> QuantizerResult {
-        let mut count_by_color: HashMap<i64, i64> = HashMap::new();
+    fn quantize(&mut self, pixels: &[Argb], _max_colors: i64, _: Option<bool>) -> QuantizerResult {
+        let mut count_by_color: HashMap<Argb, i64> = HashMap::new();
         for pixel in pixels {
-            let alpha = ColorUtils::alpha_from_argb(*pixel);
+            let alpha = pixel.alpha();
             if alpha < 255 {
                 continue;
             }
@@ -22,4 +20,17 @@ impl Quantizer for QuantizerMap {
         }
         QuantizerResult::new(count_by_color, None)
     }
+
+    fn quantize_histogram(
+        &mut self,
+        histogram: &PixelHistogram,
+        _max_colors: i64,
+        _: Option<bool>,
+    ) -> QuantizerResult {
+        let count_by_color: HashMap<Argb, i64> = histogram
+            .iter()
+            .filter(|(pixel, _)| pixel.is_opaque())
+            .collect();
+        QuantizerResult::new(count_by_color, None)
+    }
 }
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use crate::utils::argb::Argb;
+
+use super::{
+    pixel_histogram::PixelHistogram,
+    quantizer::{Quantizer, QuantizerResult},
+    quantizer_map::QuantizerMap,
+};
+
+/// An implementation of Heckbert's median cut algorithm.
+///
+/// The opaque colors of an image start in a single box. The box whose colors
+/// span the widest range along a channel is repeatedly split at the weighted
+/// median of that channel, until there are as many boxes as requested or
+/// every box holds a single color. Each box becomes the average of its
+/// colors, weighted by their pixel counts.
+pub struct QuantizerMedianCut {}
+
+impl Quantizer for QuantizerMedianCut {
+    /// Quantizes [pixels] into at most [max_colors] colors, with the number of
+    /// pixels in each.
+    ///
+    /// If [return_input_pixel_to_cluster_pixel] is true, the result also maps
+    /// every opaque input color to the color of its box.
+    fn quantize(
+        &mut self,
+        pixels: &[Argb],
+        max_colors: i64,
+        return_input_pixel_to_cluster_pixel: Option<bool>,
+    ) -> QuantizerResult {
+        let result = QuantizerMap {}.quantize(pixels, max_colors, None);
+        return Self::_quantize(
+            result.color_to_count,
+            max_colors,
+            return_input_pixel_to_cluster_pixel.unwrap_or(false),
+        );
+    }
+
+    fn quantize_histogram(
+        &mut self,
+        histogram: &PixelHistogram,
+        max_colors: i64,
+        return_input_pixel_to_cluster_pixel: Option<bool>,
+    ) -> QuantizerResult {
+        let result = QuantizerMap {}.quantize_histogram(histogram, max_colors, None);
+        return Self::_quantize(
+            result.color_to_count,
+            max_colors,
+            return_input_pixel_to_cluster_pixel.unwrap_or(false),
+        );
+    }
+}
+
+impl QuantizerMedianCut {
+    fn _quantize(
+        color_to_count: HashMap<Argb, i64>,
+        max_colors: i64,
+        return_input_pixel_to_cluster_pixel: bool,
+    ) -> QuantizerResult {
+        if max_colors <= 0 || color_to_count.is_empty() {
+            return QuantizerResult::new(HashMap::new(), None);
+        }
+        // Sorting makes the result independent of the map's iteration order.
+        let mut entries: Vec<(Argb, i64)> = color_to_count.into_iter().collect();
+        entries.sort();
+
+        let mut boxes: Vec<Vec<(Argb, i64)>> = vec![entries];
+        while (boxes.len() as i64) < max_colors {
+            let mut widest: Option<(usize, usize, u8)> = None;
+            for (index, colors) in boxes.iter().enumerate() {
+                if colors.len() < 2 {
+                    continue;
+                }
+                let (channel, range) = Self::_widest_channel(colors);
+                if widest.is_none_or(|(_, _, widest_range)| range > widest_range) {
+                    widest = Some((index, channel, range));
+                }
+            }
+            let Some((index, channel, _)) = widest else {
+                break;
+            };
+            let upper = Self::_split(&mut boxes[index], channel);
+            boxes.push(upper);
+        }
+
+        let mut cluster_to_count: HashMap<Argb, i64> = HashMap::new();
+        let mut input_pixel_to_cluster_pixel: HashMap<Argb, Argb> = HashMap::new();
+        for colors in &boxes {
+            let (average, population) = Self::_average(colors);
+            // Boxes can average to the same color, so their populations are
+            // added.
+            *cluster_to_count.entry(average).or_insert(0) += population;
+            if return_input_pixel_to_cluster_pixel {
+                for (color, _) in colors {
+                    input_pixel_to_cluster_pixel.insert(*color, average);
+                }
+            }
+        }
+        return QuantizerResult::new(cluster_to_count, Some(input_pixel_to_cluster_pixel));
+    }
+
+    /// The value of [color] in [channel], 0 for red, 1 for green and 2 for
+    /// blue.
+    fn _channel(color: Argb, channel: usize) -> u8 {
+        return match channel {
+            0 => color.red(),
+            1 => color.green(),
+            _ => color.blue(),
+        };
+    }
+
+    /// The channel along which [colors] span the widest range, and that range.
+    fn _widest_channel(colors: &[(Argb, i64)]) -> (usize, u8) {
+        let mut widest = (0, 0);
+        for channel in 0..3 {
+            let values = colors
+                .iter()
+                .map(|(color, _)| Self::_channel(*color, channel));
+            let min = values.clone().min().unwrap_or(0);
+            let max = values.max().unwrap_or(0);
+            if max - min > widest.1 {
+                widest = (channel, max - min);
+            }
+        }
+        return widest;
+    }
+
+    /// Sorts [colors] along [channel] and splits them at the weighted median,
+    /// keeping the lower half in [colors] and returning the upper half. Both
+    /// halves hold at least one color.
+    fn _split(colors: &mut Vec<(Argb, i64)>, channel: usize) -> Vec<(Argb, i64)> {
+        colors.sort_by_key(|(color, _)| (Self::_channel(*color, channel), *color));
+        let total: i64 = colors.iter().map(|(_, count)| count).sum();
+        let mut cumulative = 0;
+        let mut split = colors.len() - 1;
+        for (index, (_, count)) in colors.iter().enumerate() {
+            cumulative += count;
+            if cumulative * 2 >= total {
+                split = index + 1;
+                break;
+            }
+        }
+        let split = split.clamp(1, colors.len() - 1);
+        return colors.split_off(split);
+    }
+
+    /// The average of [colors] weighted by their counts, and the sum of their
+    /// counts.
+    fn _average(colors: &[(Argb, i64)]) -> (Argb, i64) {
+        let mut population = 0;
+        let mut red_sum = 0;
+        let mut green_sum = 0;
+        let mut blue_sum = 0;
+        for (color, count) in colors {
+            population += count;
+            red_sum += color.red() as i64 * count;
+            green_sum += color.green() as i64 * count;
+            blue_sum += color.blue() as i64 * count;
+        }
+        let average = |sum: i64| (sum as f64 / population as f64).round() as u8;
+        return (
+            Argb::from_rgb(average(red_sum), average(green_sum), average(blue_sum)),
+            population,
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+
+use crate::utils::argb::Argb;
+
+use super::{
+    pixel_histogram::PixelHistogram,
+    quantizer::{Quantizer, QuantizerResult},
+    quantizer_map::QuantizerMap,
+};
+
+/// An implementation of Gervautz and Purgathofer's octree quantizer.
+///
+/// Every opaque color is a leaf of a tree whose levels branch on one more bit
+/// of each channel, from the most significant one. While there are more
+/// leaves than requested, the deepest nodes with the fewest pixels are
+/// reduced, turning their children into one leaf. Each leaf becomes the
+/// average of its colors, weighted by their pixel counts.
+///
+/// A reduction can remove up to seven leaves, so there may be fewer colors
+/// than requested.
+pub struct QuantizerOctree {}
+
+impl Quantizer for QuantizerOctree {
+    /// Quantizes [pixels] into at most [max_colors] colors, with the number of
+    /// pixels in each.
+    ///
+    /// If [return_input_pixel_to_cluster_pixel] is true, the result also maps
+    /// every opaque input color to the color of its leaf.
+    fn quantize(
+        &mut self,
+        pixels: &[Argb],
+        max_colors: i64,
+        return_input_pixel_to_cluster_pixel: Option<bool>,
+    ) -> QuantizerResult {
+        let result = QuantizerMap {}.quantize(pixels, max_colors, None);
+        return Self::_quantize(
+            result.color_to_count,
+            max_colors,
+            return_input_pixel_to_cluster_pixel.unwrap_or(false),
+        );
+    }
+
+    fn quantize_histogram(
+        &mut self,
+        histogram: &PixelHistogram,
+        max_colors: i64,
+        return_input_pixel_to_cluster_pixel: Option<bool>,
+    ) -> QuantizerResult {
+        let result = QuantizerMap {}.quantize_histogram(histogram, max_colors, None);
+        return Self::_quantize(
+            result.color_to_count,
+            max_colors,
+            return_input_pixel_to_cluster_pixel.unwrap_or(false),
+        );
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct OctreeNode {
+    red_sum: i64,
+    green_sum: i64,
+    blue_sum: i64,
+    pixel_count: i64,
+    children: [Option<usize>; 8],
+    is_leaf: bool,
+}
+
+impl OctreeNode {
+    fn color(&self) -> Argb {
+        let average = |sum: i64| (sum as f64 / self.pixel_count as f64).round() as u8;
+        return Argb::from_rgb(
+            average(self.red_sum),
+            average(self.green_sum),
+            average(self.blue_sum),
+        );
+    }
+}
+
+impl QuantizerOctree {
+    /// The number of levels below the root, one per bit of a channel.
+    const DEPTH: usize = 8;
+
+    fn _quantize(
+        color_to_count: HashMap<Argb, i64>,
+        max_colors: i64,
+        return_input_pixel_to_cluster_pixel: bool,
+    ) -> QuantizerResult {
+        if max_colors <= 0 || color_to_count.is_empty() {
+            return QuantizerResult::new(HashMap::new(), None);
+        }
+        // Sorting makes the node indices, and so the reductions, independent
+        // of the map's iteration order.
+        let mut entries: Vec<(Argb, i64)> = color_to_count.into_iter().collect();
+        entries.sort();
+
+        // Every node on the path to a leaf holds the sums of the leaf, so a
+        // reduced node already holds the sums of its children.
+        let mut nodes: Vec<OctreeNode> = vec![OctreeNode::default()];
+        // The nodes at each level above the leaves, the ones that can be
+        // reduced.
+        let mut levels: Vec<Vec<usize>> = vec![Vec::new(); Self::DEPTH];
+        levels[0].push(0);
+        let mut leaf_count: i64 = 0;
+        for (color, count) in &entries {
+            let mut node = 0;
+            for level in 0..=Self::DEPTH {
+                nodes[node].red_sum += color.red() as i64 * count;
+                nodes[node].green_sum += color.green() as i64 * count;
+                nodes[node].blue_sum += color.blue() as i64 * count;
+                nodes[node].pixel_count += count;
+                if level == Self::DEPTH {
+                    if !nodes[node].is_leaf {
+                        nodes[node].is_leaf = true;
+                        leaf_count += 1;
+                    }
+                    break;
+                }
+                let child = Self::_child_index(*color, level);
+                node = match nodes[node].children[child] {
+                    Some(existing) => existing,
+                    None => {
+                        let created = nodes.len();
+                        nodes.push(OctreeNode::default());
+                        nodes[node].children[child] = Some(created);
+                        if level + 1 < Self::DEPTH {
+                            levels[level + 1].push(created);
+                        }
+                        created
+                    }
+                };
+            }
+        }
+
+        // Reduces the deepest levels first, where the colors of a node are
+        // closest to each other.
+        'reduce: for level in (0..Self::DEPTH).rev() {
+            let mut reducible: Vec<usize> = levels[level].clone();
+            reducible.sort_by_key(|index| (nodes[*index].pixel_count, *index));
+            for index in reducible {
+                if leaf_count <= max_colors {
+                    break 'reduce;
+                }
+                let children: Vec<usize> =
+                    nodes[index].children.iter().flatten().copied().collect();
+                for child in &children {
+                    nodes[*child].is_leaf = false;
+                }
+                nodes[index].children = [None; 8];
+                nodes[index].is_leaf = true;
+                leaf_count -= children.len() as i64 - 1;
+            }
+        }
+
+        let mut cluster_to_count: HashMap<Argb, i64> = HashMap::new();
+        for node in nodes.iter().filter(|node| node.is_leaf) {
+            // Leaves can average to the same color, so their populations are
+            // added.
+            *cluster_to_count.entry(node.color()).or_insert(0) += node.pixel_count;
+        }
+        let mut input_pixel_to_cluster_pixel: HashMap<Argb, Argb> = HashMap::new();
+        if return_input_pixel_to_cluster_pixel {
+            for (color, _) in &entries {
+                let mut node = 0;
+                for level in 0..Self::DEPTH {
+                    if nodes[node].is_leaf {
+                        break;
+                    }
+                    match nodes[node].children[Self::_child_index(*color, level)] {
+                        Some(child) => node = child,
+                        None => break,
+                    }
+                }
+                input_pixel_to_cluster_pixel.insert(*color, nodes[node].color());
+            }
+        }
+        return QuantizerResult::new(cluster_to_count, Some(input_pixel_to_cluster_pixel));
+    }
+
+    /// The child of a node at [level] that [color] belongs to, from the bit of
+    /// each channel at that level.
+    fn _child_index(color: Argb, level: usize) -> usize {
+        let shift = 7 - level;
+        let red = (color.red() as usize >> shift) & 1;
+        let green = (color.green() as usize >> shift) & 1;
+        let blue = (color.blue() as usize >> shift) & 1;
+        return (red << 2) | (green << 1) | blue;
+    }
+}
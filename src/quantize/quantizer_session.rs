@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+
+use crate::{
+    hct::hct::Hct,
+    score::score::Score,
+    utils::{argb::Argb, math_utils::MathUtils},
+};
+
+use super::{
+    pixel_histogram::PixelHistogram,
+    quantizer_celebi::QuantizerCelebi,
+    quantizer_wsmeans::{QuantizerWsmeans, WsmeansOptions},
+    src::point_provider_lab::PointProviderLab,
+};
+
+/// Options of a [QuantizerSession].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuantizerSessionOptions {
+    /// The number of clusters frames are quantized to.
+    pub max_colors: i64,
+    /// The share of the previous populations carried into the next frame,
+    /// from 0, which forgets previous frames, to 1, which never forgets them.
+    pub decay: f64,
+    /// The maximum number of ranked candidate colors.
+    pub candidate_count: i64,
+    /// The source color used when the footage has no suitable colors.
+    pub fallback_color: Argb,
+    /// Top candidates within this many degrees of hue, and within
+    /// [chroma_threshold] and [tone_threshold], of the source color don't
+    /// replace it.
+    pub hue_threshold: f64,
+    /// The chroma difference from the source color a top candidate must
+    /// exceed to replace it, when its hue is within [hue_threshold].
+    pub chroma_threshold: f64,
+    /// The tone difference from the source color a top candidate must exceed
+    /// to replace it, when its hue is within [hue_threshold].
+    pub tone_threshold: f64,
+    /// The number of consecutive frames a top candidate must stay beyond the
+    /// thresholds from the source color before it replaces it.
+    ///
+    /// A [fallback_color] source is replaced by the first real candidate,
+    /// without waiting.
+    pub hold_frames: usize,
+    /// Options of the [QuantizerWsmeans] run on each frame.
+    pub wsmeans: WsmeansOptions,
+}
+
+impl Default for QuantizerSessionOptions {
+    fn default() -> Self {
+        QuantizerSessionOptions {
+            max_colors: 128,
+            decay: 0.5,
+            candidate_count: 4,
+            fallback_color: Argb(0xff4285f4),
+            hue_threshold: 15.0,
+            chroma_threshold: 20.0,
+            tone_threshold: 20.0,
+            hold_frames: 3,
+            wsmeans: WsmeansOptions::default(),
+        }
+    }
+}
+
+/// Quantizes successive frames of a video, carrying clusters and populations
+/// from frame to frame so that the source color stays stable.
+///
+/// The first frame is quantized with [QuantizerCelebi]. The clusters of each
+/// frame are the starting clusters of [QuantizerWsmeans] on the next one,
+/// where they are also counted as pixels with their populations scaled by
+/// [QuantizerSessionOptions::decay]. The populations are ranked with [Score],
+/// and the source color only follows the top candidate once it has moved in
+/// hue, chroma or tone by more than the thresholds of
+/// [QuantizerSessionOptions] for [QuantizerSessionOptions::hold_frames] frames
+/// in a row.
+pub struct QuantizerSession {
+    options: QuantizerSessionOptions,
+    populations: HashMap<Argb, i64>,
+    clusters: Vec<Argb>,
+    candidates: Vec<Argb>,
+    source_color: Option<Argb>,
+    source_is_fallback: bool,
+    pending_frames: usize,
+}
+
+impl QuantizerSession {
+    pub fn new(options: QuantizerSessionOptions) -> QuantizerSession {
+        QuantizerSession {
+            options,
+            populations: HashMap::new(),
+            clusters: Vec::new(),
+            candidates: Vec::new(),
+            source_color: None,
+            source_is_fallback: false,
+            pending_frames: 0,
+        }
+    }
+
+    pub fn options(&self) -> &QuantizerSessionOptions {
+        return &self.options;
+    }
+
+    /// Adds a frame and returns the stabilized source color.
+    ///
+    /// Pixels that aren't fully opaque are skipped.
+    pub fn add_frame(&mut self, pixels: &[Argb]) -> Argb {
+        let histogram: PixelHistogram = pixels.iter().copied().collect();
+        return self.add_frame_histogram(&histogram);
+    }
+
+    /// Adds a frame whose pixels are counted in [histogram] and returns the
+    /// stabilized source color.
+    pub fn add_frame_histogram(&mut self, histogram: &PixelHistogram) -> Argb {
+        let mut combined = PixelHistogram::new();
+        for (color, count) in histogram.iter() {
+            if color.is_opaque() && count > 0 {
+                combined.add_count(color, count);
+            }
+        }
+        for cluster in &self.clusters {
+            let population = self.populations[cluster] as f64 * self.options.decay;
+            let population = population.round() as i64;
+            if population > 0 {
+                combined.add_count(*cluster, population);
+            }
+        }
+
+        let result = if self.clusters.is_empty() {
+            QuantizerCelebi {}.quantize_histogram_with_options(
+                &combined,
+                self.options.max_colors,
+                &self.options.wsmeans,
+                None,
+            )
+        } else {
+            QuantizerWsmeans::quantize_histogram(
+                &combined,
+                self.options.max_colors,
+                Some(&self.clusters),
+                Some(&PointProviderLab::new()),
+                &self.options.wsmeans,
+                None,
+            )
+        };
+        self.populations = result.color_to_count;
+        // Most populous first, so that the order doesn't depend on the map.
+        let mut clusters: Vec<Argb> = self.populations.keys().copied().collect();
+        clusters.sort_by(|a, b| self.populations[b].cmp(&self.populations[a]).then(a.cmp(b)));
+        self.clusters = clusters;
+
+        self.candidates = Score::score_argb(
+            &self.populations,
+            Some(self.options.candidate_count),
+            Some(self.options.fallback_color),
+            None,
+        );
+        return self._update_source_color();
+    }
+
+    /// The population of each cluster, including the decayed populations of
+    /// previous frames.
+    pub fn populations(&self) -> &HashMap<Argb, i64> {
+        return &self.populations;
+    }
+
+    /// The ranked candidates of the last frame, without hysteresis.
+    pub fn candidates(&self) -> &[Argb] {
+        return &self.candidates;
+    }
+
+    /// The stabilized source color, or [None] before the first frame.
+    pub fn source_color(&self) -> Option<Argb> {
+        return self.source_color;
+    }
+
+    /// Forgets every frame, as after a cut to unrelated footage.
+    pub fn reset(&mut self) {
+        self.populations.clear();
+        self.clusters.clear();
+        self.candidates.clear();
+        self.source_color = None;
+        self.source_is_fallback = false;
+        self.pending_frames = 0;
+    }
+
+    fn _update_source_color(&mut self) -> Argb {
+        let top = self.candidates[0];
+        // [Score] only ranks colors of the populations, or falls back.
+        let top_is_fallback = !self.populations.contains_key(&top);
+        let source_color = match self.source_color {
+            Some(source_color) if !self.source_is_fallback || top_is_fallback => source_color,
+            _ => return self._replace_source_color(top, top_is_fallback),
+        };
+        if !self._has_moved(top, source_color) {
+            self.pending_frames = 0;
+            return source_color;
+        }
+        self.pending_frames += 1;
+        if self.pending_frames < self.options.hold_frames {
+            return source_color;
+        }
+        return self._replace_source_color(top, top_is_fallback);
+    }
+
+    fn _replace_source_color(&mut self, source_color: Argb, is_fallback: bool) -> Argb {
+        self.source_color = Some(source_color);
+        self.source_is_fallback = is_fallback;
+        self.pending_frames = 0;
+        return source_color;
+    }
+
+    /// Whether [candidate] is beyond any of the thresholds from
+    /// [source_color].
+    fn _has_moved(&self, candidate: Argb, source_color: Argb) -> bool {
+        let candidate = Hct::from_argb(candidate);
+        let source = Hct::from_argb(source_color);
+        let hue_difference = MathUtils::difference_degrees(candidate.get_hue(), source.get_hue());
+        return hue_difference > self.options.hue_threshold
+            || (candidate.get_chroma() - source.get_chroma()).abs()
+                > self.options.chroma_threshold
+            || (candidate.get_tone() - source.get_tone()).abs() > self.options.tone_threshold;
+    }
+}
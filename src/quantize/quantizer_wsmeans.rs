@@ -1,8 +1,13 @@
 use std::{collections::HashMap, time::Instant};
 
-use rand::{Rng, SeedableRng};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::utils::argb::Argb;
 
 use super::{
+    pixel_histogram::PixelHistogram,
     quantizer::QuantizerResult,
     src::{point_provider::PointProvider, point_provider_lab::PointProviderLab},
 };
@@ -30,6 +35,36 @@ impl Ord for DistanceAndIndex {
     }
 }
 
+/// Options of [QuantizerWsmeans].
+///
+/// The defaults match the reference implementation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WsmeansOptions {
+    /// Seed of the random number generator that picks the starting clusters
+    /// not given by the caller.
+    pub seed: u64,
+    /// Whether starting clusters are picked with k-means++, which favors
+    /// points far from the clusters picked so far, instead of uniformly.
+    pub kmeans_plus_plus: bool,
+    /// The maximum number of k-means iterations.
+    pub max_iterations: i64,
+    /// Iterations stop once no cluster moves by more than this distance, as
+    /// measured by the point provider. 0 disables the threshold, so iterations
+    /// only stop once no point changes cluster.
+    pub convergence_threshold: f64,
+}
+
+impl Default for WsmeansOptions {
+    fn default() -> Self {
+        WsmeansOptions {
+            seed: 0x42688,
+            kmeans_plus_plus: false,
+            max_iterations: 5,
+            convergence_threshold: 0.0,
+        }
+    }
+}
+
 /*
 class QuantizerWsmeans {*/
 pub struct QuantizerWsmeans {}
@@ -42,6 +77,34 @@ impl QuantizerWsmeans {
         }
     }
 
+    /// Clusters [input_pixels] into at most [max_colors] colors with weighted
+    /// k-means.
+    ///
+    /// Distances are measured by [point_provider], which defaults to
+    /// [PointProviderLab].
+    pub fn quantize_argb(
+        input_pixels: &[Argb],
+        max_colors: i64,
+        starting_clusters: Option<&[Argb]>,
+        point_provider: Option<&dyn PointProvider>,
+        max_iterations: Option<i64>,
+        return_input_pixel_to_cluster_pixel: Option<bool>,
+    ) -> QuantizerResult {
+        let options = WsmeansOptions {
+            max_iterations: max_iterations.unwrap_or(5),
+            ..WsmeansOptions::default()
+        };
+        return Self::quantize_with_options(
+            input_pixels,
+            max_colors,
+            starting_clusters,
+            point_provider,
+            &options,
+            return_input_pixel_to_cluster_pixel,
+        );
+    }
+
+    #[deprecated = "use `QuantizerWsmeans::quantize_argb` instead"]
     pub fn quantize(
         input_pixels: &Vec<i64>,
         max_colors: i64,
@@ -50,48 +113,92 @@ impl QuantizerWsmeans {
         max_iterations: Option<i64>,
         return_input_pixel_to_cluster_pixel: Option<bool>,
     ) -> QuantizerResult {
-        let starting_clusters_default = Vec::new();
-        let starting_clusters = starting_clusters.unwrap_or(&starting_clusters_default);
+        let input_pixels: Vec<Argb> = input_pixels.iter().map(|argb| Argb(*argb as u32)).collect();
+        let starting_clusters: Option<Vec<Argb>> = starting_clusters
+            .map(|clusters| clusters.iter().map(|argb| Argb(*argb as u32)).collect());
+        return Self::quantize_argb(
+            &input_pixels,
+            max_colors,
+            starting_clusters.as_deref(),
+            point_provider.map(|provider| provider as &dyn PointProvider),
+            max_iterations,
+            return_input_pixel_to_cluster_pixel,
+        );
+    }
+
+    /// Like [quantize_argb], with every option of [WsmeansOptions].
+    pub fn quantize_with_options(
+        input_pixels: &[Argb],
+        max_colors: i64,
+        starting_clusters: Option<&[Argb]>,
+        point_provider: Option<&dyn PointProvider>,
+        options: &WsmeansOptions,
+        return_input_pixel_to_cluster_pixel: Option<bool>,
+    ) -> QuantizerResult {
+        let histogram: PixelHistogram = input_pixels.iter().copied().collect();
+        return Self::quantize_histogram(
+            &histogram,
+            max_colors,
+            starting_clusters,
+            point_provider,
+            options,
+            return_input_pixel_to_cluster_pixel,
+        );
+    }
+
+    /// Quantizes the pixels counted in [histogram], see [quantize_with_options].
+    pub fn quantize_histogram(
+        histogram: &PixelHistogram,
+        max_colors: i64,
+        starting_clusters: Option<&[Argb]>,
+        point_provider: Option<&dyn PointProvider>,
+        options: &WsmeansOptions,
+        return_input_pixel_to_cluster_pixel: Option<bool>,
+    ) -> QuantizerResult {
+        let starting_clusters = starting_clusters.unwrap_or(&[]);
         let point_provider_default = PointProviderLab::new();
         let point_provider = point_provider.unwrap_or(&point_provider_default);
-        let max_iterations = max_iterations.unwrap_or(5);
+        let max_iterations = options.max_iterations;
         let return_input_pixel_to_cluster_pixel =
             return_input_pixel_to_cluster_pixel.unwrap_or(false);
-        //
 
-        let mut pixel_to_count: HashMap<i64, i64> = HashMap::new();
-        let mut points: Vec<Vec<f64>> = Vec::new();
-        let mut pixels: Vec<i64> = Vec::new();
-        let mut point_count = 0;
-        for input_pixel in input_pixels {
-            let pixel_count = pixel_to_count
-                .entry(*input_pixel)
-                .and_modify(|value| *value += 1)
-                .or_insert(1);
-            if pixel_count.clone() == 1 {
-                point_count += 1;
-                points.push(point_provider.from_int(*input_pixel));
-                pixels.push(*input_pixel);
-            }
-        }
-
-        let mut counts: Vec<i64> = vec![0; point_count];
-        for i in 0..point_count {
-            let pixel = pixels[i];
-            let count = pixel_to_count.get(&pixel).unwrap();
-            counts[i] = *count;
-        }
+        let point_count = histogram.len();
+        let pixels = histogram.colors();
+        let counts = histogram.counts();
+        #[cfg(not(feature = "rayon"))]
+        let points: Vec<Vec<f64>> = pixels
+            .iter()
+            .map(|pixel| point_provider.from_argb(*pixel))
+            .collect();
+        #[cfg(feature = "rayon")]
+        let points: Vec<Vec<f64>> = pixels
+            .par_iter()
+            .map(|pixel| point_provider.from_argb(*pixel))
+            .collect();
 
         let cluster_count = max_colors.min(point_count as i64);
+        if cluster_count <= 0 {
+            return QuantizerResult::new(HashMap::new(), None);
+        }
 
         let mut clusters: Vec<Vec<f64>> = starting_clusters
             .iter()
-            .map(|e| point_provider.from_int(*e))
+            .map(|e| point_provider.from_argb(*e))
             .collect();
         let additional_clusters_needed = cluster_count - clusters.len() as i64;
-        if additional_clusters_needed > 0 {
+        if additional_clusters_needed > 0 && options.kmeans_plus_plus {
+            let mut random = rand::rngs::StdRng::seed_from_u64(options.seed);
+            Self::_kmeans_plus_plus(
+                &points,
+                counts,
+                &mut clusters,
+                additional_clusters_needed as usize,
+                point_provider,
+                &mut random,
+            );
+        } else if additional_clusters_needed > 0 {
             // let random = math.Random(0x42688);
-            let mut random = rand::rngs::StdRng::seed_from_u64(0x42688);
+            let mut random = rand::rngs::StdRng::seed_from_u64(options.seed);
             let mut indices: Vec<i64> = Vec::new();
             for _ in 0..additional_clusters_needed {
                 // Use existing points rather than generating random centroids.
@@ -183,12 +290,11 @@ impl QuantizerWsmeans {
                 }
             }
 
-            for i in 0..point_count {
+            let nearest_cluster_index = |i: usize| -> i64 {
                 let point = &points[i];
                 let previous_cluster_index = cluster_indices[i];
                 let previous_cluster = &clusters[previous_cluster_index as usize];
-                let previous_distance =
-                    point_provider.distance(&point.to_vec(), &previous_cluster.to_vec());
+                let previous_distance = point_provider.distance(point, previous_cluster);
                 let mut minimum_distance = previous_distance;
                 let mut new_cluster_index = -1;
                 for j in 0..cluster_count {
@@ -198,18 +304,26 @@ impl QuantizerWsmeans {
                     {
                         continue;
                     }
-                    let distance = point_provider.distance(
-                        &point.to_vec(),
-                        &clusters[j as usize].iter().map(|it| *it).collect(),
-                    );
+                    let distance = point_provider.distance(point, &clusters[j as usize]);
                     if distance < minimum_distance {
                         minimum_distance = distance;
                         new_cluster_index = j;
                     }
                 }
-                if new_cluster_index != -1 {
+                return new_cluster_index;
+            };
+            #[cfg(not(feature = "rayon"))]
+            let new_cluster_indices: Vec<i64> =
+                (0..point_count).map(nearest_cluster_index).collect();
+            #[cfg(feature = "rayon")]
+            let new_cluster_indices: Vec<i64> = (0..point_count)
+                .into_par_iter()
+                .map(nearest_cluster_index)
+                .collect();
+            for i in 0..point_count {
+                if new_cluster_indices[i] != -1 {
                     points_moved += 1;
-                    cluster_indices[i] = new_cluster_index;
+                    cluster_indices[i] = new_cluster_indices[i];
                 }
             }
 
@@ -223,36 +337,37 @@ impl QuantizerWsmeans {
                 iteration + 1,
                 points_moved
             ));
-            let mut component_a_sums = vec![0.0; cluster_count as usize];
-            let mut component_b_sums = vec![0.0; cluster_count as usize];
-            let mut component_c_sums = vec![0.0; cluster_count as usize];
-
-            for i in 0..cluster_count {
-                pixel_count_sums[i as usize] = 0;
-            }
-            for i in 0..point_count {
-                let cluster_index = cluster_indices[i];
-                let point = &points[i];
-                let count = counts[i];
-                pixel_count_sums[cluster_index as usize] += count;
-                component_a_sums[cluster_index as usize] += point[0] * count as f64;
-                component_b_sums[cluster_index as usize] += point[1] * count as f64;
-                component_c_sums[cluster_index as usize] += point[2] * count as f64;
-            }
+            let (count_sums, component_a_sums, component_b_sums, component_c_sums) =
+                Self::_cluster_sums(&points, counts, &cluster_indices, cluster_count as usize);
+            pixel_count_sums = count_sums;
+            let mut max_movement: f64 = 0.0;
             for i in 0..cluster_count {
                 let count = pixel_count_sums[i as usize];
-                if count == 0 {
-                    clusters[i as usize] = [0.0, 0.0, 0.0].to_vec();
-                    continue;
+                let cluster = if count == 0 {
+                    [0.0, 0.0, 0.0].to_vec()
+                } else {
+                    let a = component_a_sums[i as usize] / count as f64;
+                    let b = component_b_sums[i as usize] / count as f64;
+                    let c = component_c_sums[i as usize] / count as f64;
+                    [a, b, c].to_vec()
+                };
+                if options.convergence_threshold > 0.0 {
+                    max_movement =
+                        max_movement.max(point_provider.distance(&clusters[i as usize], &cluster));
                 }
-                let a = component_a_sums[i as usize] / count as f64;
-                let b = component_b_sums[i as usize] / count as f64;
-                let c = component_c_sums[i as usize] / count as f64;
-                clusters[i as usize] = [a, b, c].to_vec();
+                clusters[i as usize] = cluster;
+            }
+            if options.convergence_threshold > 0.0 && max_movement <= options.convergence_threshold
+            {
+                Self::debug_log(format!(
+                    "converged after {} k-means iterations",
+                    iteration + 1
+                ));
+                break;
             }
         }
 
-        let mut cluster_argbs: Vec<i64> = vec![];
+        let mut cluster_argbs: Vec<Argb> = vec![];
         let mut cluster_populations: Vec<i64> = vec![];
         for i in 0..cluster_count {
             let count = pixel_count_sums[i as usize];
@@ -261,7 +376,7 @@ impl QuantizerWsmeans {
             }
 
             let possible_new_cluster =
-                point_provider.to_int(&clusters[i as usize].iter().map(|it| *it).collect());
+                point_provider.to_argb(&clusters[i as usize].iter().map(|it| *it).collect());
             if cluster_argbs.contains(&possible_new_cluster) {
                 continue;
             }
@@ -275,14 +390,14 @@ impl QuantizerWsmeans {
             cluster_count
         ));
 
-        let mut input_pixel_to_cluster_pixel: HashMap<i64, i64> = HashMap::new();
+        let mut input_pixel_to_cluster_pixel: HashMap<Argb, Argb> = HashMap::new();
         if return_input_pixel_to_cluster_pixel {
             let stopwatch = Stopwatch::new().start();
             for i in 0..pixels.len() {
                 let input_pixel = pixels[i];
                 let cluster_index = cluster_indices[i as usize];
                 let cluster = &clusters[cluster_index as usize];
-                let cluster_pixel = point_provider.to_int(&cluster.to_vec());
+                let cluster_pixel = point_provider.to_argb(&cluster.to_vec());
                 input_pixel_to_cluster_pixel.insert(input_pixel, cluster_pixel);
             }
             Self::debug_log(format!(
@@ -291,7 +406,7 @@ impl QuantizerWsmeans {
             ));
         }
 
-        let color_to_count: HashMap<i64, i64> = cluster_argbs
+        let color_to_count: HashMap<Argb, i64> = cluster_argbs
             .into_iter()
             .zip(cluster_populations)
             .map(|(argb, population)| (argb, population))
@@ -304,6 +419,125 @@ impl QuantizerWsmeans {
     }
 }
 
+impl QuantizerWsmeans {
+    /// Adds [count] clusters picked among [points] with k-means++: each point
+    /// is picked with a probability proportional to its pixel count times its
+    /// distance to the nearest cluster picked so far.
+    fn _kmeans_plus_plus(
+        points: &[Vec<f64>],
+        counts: &[i64],
+        clusters: &mut Vec<Vec<f64>>,
+        count: usize,
+        point_provider: &dyn PointProvider,
+        random: &mut StdRng,
+    ) {
+        // The distance of each point to its nearest cluster.
+        let mut distances: Vec<f64> = vec![f64::INFINITY; points.len()];
+        for cluster in clusters.iter() {
+            for (i, point) in points.iter().enumerate() {
+                distances[i] = distances[i].min(point_provider.distance(point, cluster));
+            }
+        }
+        for _ in 0..count {
+            let weights: Vec<f64> = if clusters.is_empty() {
+                counts.iter().map(|count| *count as f64).collect()
+            } else {
+                (0..points.len())
+                    .map(|i| counts[i] as f64 * distances[i])
+                    .collect()
+            };
+            let total: f64 = weights.iter().sum();
+            let index = if total > 0.0 && total.is_finite() {
+                let target = random.gen_range(0.0..total);
+                let mut sum = 0.0;
+                weights
+                    .iter()
+                    .position(|weight| {
+                        sum += weight;
+                        sum > target
+                    })
+                    .unwrap_or(points.len() - 1)
+            } else {
+                // Every point already is a cluster.
+                random.gen_range(0..points.len())
+            };
+            let cluster = points[index].clone();
+            for (i, point) in points.iter().enumerate() {
+                distances[i] = distances[i].min(point_provider.distance(point, &cluster));
+            }
+            clusters.push(cluster);
+        }
+    }
+
+    /// The pixel count of each cluster, and the sums of the components of
+    /// their points weighted by count.
+    #[cfg(not(feature = "rayon"))]
+    fn _cluster_sums(
+        points: &[Vec<f64>],
+        counts: &[i64],
+        cluster_indices: &[i64],
+        cluster_count: usize,
+    ) -> (Vec<i64>, Vec<f64>, Vec<f64>, Vec<f64>) {
+        let mut pixel_count_sums = vec![0; cluster_count];
+        let mut component_a_sums = vec![0.0; cluster_count];
+        let mut component_b_sums = vec![0.0; cluster_count];
+        let mut component_c_sums = vec![0.0; cluster_count];
+        for i in 0..points.len() {
+            let cluster_index = cluster_indices[i];
+            let point = &points[i];
+            let count = counts[i];
+            pixel_count_sums[cluster_index as usize] += count;
+            component_a_sums[cluster_index as usize] += point[0] * count as f64;
+            component_b_sums[cluster_index as usize] += point[1] * count as f64;
+            component_c_sums[cluster_index as usize] += point[2] * count as f64;
+        }
+        return (
+            pixel_count_sums,
+            component_a_sums,
+            component_b_sums,
+            component_c_sums,
+        );
+    }
+
+    /// Sums every cluster on its own thread, see the `rayon` feature.
+    ///
+    /// The points of each cluster are added in the same order as the
+    /// sequential sums, so the floating point sums are identical.
+    #[cfg(feature = "rayon")]
+    fn _cluster_sums(
+        points: &[Vec<f64>],
+        counts: &[i64],
+        cluster_indices: &[i64],
+        cluster_count: usize,
+    ) -> (Vec<i64>, Vec<f64>, Vec<f64>, Vec<f64>) {
+        let mut members: Vec<Vec<usize>> = vec![Vec::new(); cluster_count];
+        for (i, cluster_index) in cluster_indices.iter().enumerate() {
+            members[*cluster_index as usize].push(i);
+        }
+        let sums: Vec<(i64, f64, f64, f64)> = members
+            .par_iter()
+            .map(|members| {
+                let mut sums = (0, 0.0, 0.0, 0.0);
+                for i in members {
+                    let point = &points[*i];
+                    let count = counts[*i];
+                    sums.0 += count;
+                    sums.1 += point[0] * count as f64;
+                    sums.2 += point[1] * count as f64;
+                    sums.3 += point[2] * count as f64;
+                }
+                sums
+            })
+            .collect();
+        return (
+            sums.iter().map(|sums| sums.0).collect(),
+            sums.iter().map(|sums| sums.1).collect(),
+            sums.iter().map(|sums| sums.2).collect(),
+            sums.iter().map(|sums| sums.3).collect(),
+        );
+    }
+}
+
 #[derive(Clone, Copy)]
 struct Stopwatch {
     data: Instant,
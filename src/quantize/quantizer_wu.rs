@@ -1,8 +1,12 @@
 use std::collections::HashMap;
 
-use crate::utils::color_utils::ColorUtils;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::utils::argb::Argb;
 
 use super::{
+    pixel_histogram::PixelHistogram,
     quantizer::{Quantizer, QuantizerResult},
     quantizer_map::QuantizerMap,
 };
@@ -16,20 +20,37 @@ pub struct QuantizerWu {
     pub cubes: Vec<Box>,
 }
 impl Quantizer for QuantizerWu {
+    /// Quantizes [pixels] into at most [color_count] colors, with the number of
+    /// pixels in each.
+    ///
+    /// If [return_input_pixel_to_cluster_pixel] is true, the result also maps
+    /// every opaque input color to the color of its box.
     fn quantize(
         &mut self,
-        pixels: &Vec<i64>,
+        pixels: &[Argb],
         color_count: i64,
-        _: Option<bool>,
+        return_input_pixel_to_cluster_pixel: Option<bool>,
     ) -> QuantizerResult {
         let result = QuantizerMap {}.quantize(pixels, color_count, None);
-        self.construct_histogram(result.color_to_count);
-        self.compute_moments();
-        let create_boxes_result = self.create_boxes(color_count);
-        let results = self.create_result(create_boxes_result.result_count);
-        let color_to_count: HashMap<i64, i64> =
-            results.iter().map(|e| (e.clone(), 0 as i64)).collect();
-        return QuantizerResult::new(color_to_count, None);
+        return self._quantize(
+            result.color_to_count,
+            color_count,
+            return_input_pixel_to_cluster_pixel.unwrap_or(false),
+        );
+    }
+
+    fn quantize_histogram(
+        &mut self,
+        histogram: &PixelHistogram,
+        color_count: i64,
+        return_input_pixel_to_cluster_pixel: Option<bool>,
+    ) -> QuantizerResult {
+        let result = QuantizerMap {}.quantize_histogram(histogram, color_count, None);
+        return self._quantize(
+            result.color_to_count,
+            color_count,
+            return_input_pixel_to_cluster_pixel.unwrap_or(false),
+        );
     }
     /* @override
     Future<QuantizerResult> quantize(Iterable<int> pixels, int colorCount) async {
@@ -56,6 +77,75 @@ impl QuantizerWu {
         }
     }
 
+    /// The colors of the boxes [color_to_count] is cut into, in the order the
+    /// boxes were created.
+    pub(crate) fn quantize_colors(
+        &mut self,
+        color_to_count: HashMap<Argb, i64>,
+        color_count: i64,
+    ) -> Vec<Argb> {
+        if color_count <= 0 {
+            return Vec::new();
+        }
+        self.construct_histogram(color_to_count);
+        self.compute_moments();
+        let create_boxes_result = self.create_boxes(color_count);
+        return self.create_result(create_boxes_result.result_count);
+    }
+
+    fn _quantize(
+        &mut self,
+        color_to_count: HashMap<Argb, i64>,
+        color_count: i64,
+        return_input_pixel_to_cluster_pixel: bool,
+    ) -> QuantizerResult {
+        if color_count <= 0 {
+            return QuantizerResult::new(HashMap::new(), None);
+        }
+        let input_pixels: Vec<Argb> = if return_input_pixel_to_cluster_pixel {
+            color_to_count.keys().copied().collect()
+        } else {
+            Vec::new()
+        };
+        self.construct_histogram(color_to_count);
+        self.compute_moments();
+        let create_boxes_result = self.create_boxes(color_count);
+        let clusters = self._create_clusters(create_boxes_result.result_count);
+
+        // Boxes can average to the same color, so their populations are added.
+        let mut cluster_to_count: HashMap<Argb, i64> = HashMap::new();
+        for (_, color, population) in &clusters {
+            *cluster_to_count.entry(*color).or_insert(0) += population;
+        }
+
+        let mut input_pixel_to_cluster_pixel: HashMap<Argb, Argb> = HashMap::new();
+        if return_input_pixel_to_cluster_pixel {
+            let mut cell_to_cluster: Vec<Option<Argb>> = vec![None; Self::TOTAL_SIZE as usize];
+            for (cube_index, color, _) in &clusters {
+                let cube = &self.cubes[*cube_index];
+                for r in (cube.r0 + 1)..=cube.r1 {
+                    for g in (cube.g0 + 1)..=cube.g1 {
+                        for b in (cube.b0 + 1)..=cube.b1 {
+                            cell_to_cluster[Self::get_index(r, g, b) as usize] = Some(*color);
+                        }
+                    }
+                }
+            }
+            let bits_to_remove = 8 - Self::INDEX_BITS;
+            for pixel in input_pixels {
+                let index = Self::get_index(
+                    (pixel.red() as i64 >> bits_to_remove) + 1,
+                    (pixel.green() as i64 >> bits_to_remove) + 1,
+                    (pixel.blue() as i64 >> bits_to_remove) + 1,
+                );
+                if let Some(cluster) = cell_to_cluster[index as usize] {
+                    input_pixel_to_cluster_pixel.insert(pixel, cluster);
+                }
+            }
+        }
+        return QuantizerResult::new(cluster_to_count, Some(input_pixel_to_cluster_pixel));
+    }
+
     pub fn get_index(r: i64, g: i64, b: i64) -> i64 {
         return (r << (Self::INDEX_BITS * 2))
             + (r << (Self::INDEX_BITS + 1))
@@ -65,16 +155,17 @@ impl QuantizerWu {
             + b;
     }
 
-    pub fn construct_histogram(&mut self, pixels: HashMap<i64, i64>) -> () {
+    #[cfg(not(feature = "rayon"))]
+    pub fn construct_histogram(&mut self, pixels: HashMap<Argb, i64>) -> () {
         self.weights = vec![0; Self::TOTAL_SIZE as usize];
         self.moments_r = vec![0; Self::TOTAL_SIZE as usize];
         self.moments_g = vec![0; Self::TOTAL_SIZE as usize];
         self.moments_b = vec![0; Self::TOTAL_SIZE as usize];
         self.moments = vec![0.0; Self::TOTAL_SIZE as usize];
         for (pixel, count) in pixels {
-            let red = ColorUtils::red_from_argb(pixel);
-            let green = ColorUtils::green_from_argb(pixel);
-            let blue = ColorUtils::blue_from_argb(pixel);
+            let red = pixel.red() as i64;
+            let green = pixel.green() as i64;
+            let blue = pixel.blue() as i64;
             let bits_to_remove = 8 - Self::INDEX_BITS;
             let i_r = (red >> bits_to_remove) + 1;
             let i_g = (green >> bits_to_remove) + 1;
@@ -89,6 +180,107 @@ impl QuantizerWu {
         }
     }
 
+    /// Builds the histogram on every thread, see the `rayon` feature.
+    ///
+    /// Every entry is a sum of integers, so the histogram doesn't depend on the
+    /// order pixels are added in.
+    #[cfg(feature = "rayon")]
+    pub fn construct_histogram(&mut self, pixels: HashMap<Argb, i64>) -> () {
+        let size = Self::TOTAL_SIZE as usize;
+        let empty = || {
+            (
+                vec![0i64; size],
+                vec![0i64; size],
+                vec![0i64; size],
+                vec![0i64; size],
+                vec![0i64; size],
+            )
+        };
+        let pixels: Vec<(Argb, i64)> = pixels.into_iter().collect();
+        let chunk_size = pixels.len().div_ceil(rayon::current_num_threads()).max(1);
+        let (weights, moments_r, moments_g, moments_b, moments) = pixels
+            .par_chunks(chunk_size)
+            .map(|chunk| {
+                let mut histogram = empty();
+                for (pixel, count) in chunk {
+                    let red = pixel.red() as i64;
+                    let green = pixel.green() as i64;
+                    let blue = pixel.blue() as i64;
+                    let bits_to_remove = 8 - Self::INDEX_BITS;
+                    let i_r = (red >> bits_to_remove) + 1;
+                    let i_g = (green >> bits_to_remove) + 1;
+                    let i_b = (blue >> bits_to_remove) + 1;
+                    let index = Self::get_index(i_r, i_g, i_b) as usize;
+                    histogram.0[index] += count;
+                    histogram.1[index] += red * count;
+                    histogram.2[index] += green * count;
+                    histogram.3[index] += blue * count;
+                    histogram.4[index] += count * ((red * red) + (green * green) + (blue * blue));
+                }
+                histogram
+            })
+            .reduce(empty, |mut a, b| {
+                for i in 0..size {
+                    a.0[i] += b.0[i];
+                    a.1[i] += b.1[i];
+                    a.2[i] += b.2[i];
+                    a.3[i] += b.3[i];
+                    a.4[i] += b.4[i];
+                }
+                a
+            });
+        self.weights = weights;
+        self.moments_r = moments_r;
+        self.moments_g = moments_g;
+        self.moments_b = moments_b;
+        self.moments = moments.into_iter().map(|moment| moment as f64).collect();
+    }
+
+    /// Sums the histogram on every thread, see the `rayon` feature.
+    ///
+    /// The sums are taken along blue, then green, then red, instead of line by
+    /// line. Every entry is a sum of integers, so the order doesn't change the
+    /// result.
+    #[cfg(feature = "rayon")]
+    pub fn compute_moments(&mut self) -> () {
+        Self::_prefix_sums(&mut self.weights);
+        Self::_prefix_sums(&mut self.moments_r);
+        Self::_prefix_sums(&mut self.moments_g);
+        Self::_prefix_sums(&mut self.moments_b);
+        Self::_prefix_sums(&mut self.moments);
+    }
+
+    /// Replaces every entry of the [values] cube with the sum of the entries
+    /// that are at most as far along each side.
+    #[cfg(feature = "rayon")]
+    fn _prefix_sums<T: Copy + std::ops::AddAssign + Send + Sync>(values: &mut [T]) {
+        let side = Self::SIDE_LENGTH as usize;
+        let plane = side * side;
+        values.par_chunks_mut(side).for_each(|line| {
+            for b in 1..side {
+                let previous = line[b - 1];
+                line[b] += previous;
+            }
+        });
+        values.par_chunks_mut(plane).for_each(|plane| {
+            for g in 1..side {
+                let (previous, current) = plane.split_at_mut(g * side);
+                let previous = &previous[(g - 1) * side..];
+                for b in 0..side {
+                    current[b] += previous[b];
+                }
+            }
+        });
+        for r in 1..side {
+            let (previous, current) = values.split_at_mut(r * plane);
+            current[..plane]
+                .par_iter_mut()
+                .zip(previous[(r - 1) * plane..].par_iter())
+                .for_each(|(current, previous)| *current += *previous);
+        }
+    }
+
+    #[cfg(not(feature = "rayon"))]
     pub fn compute_moments(&mut self) -> () {
         for r in 1..Self::SIDE_LENGTH {
             let mut area: Vec<i64> = vec![0; Self::SIDE_LENGTH as usize];
@@ -185,8 +377,18 @@ impl QuantizerWu {
         return CreateBoxesResult::new(max_color_count, generated_color_count);
     }
 
-    pub fn create_result(&self, color_count: i64) -> Vec<i64> {
-        let mut colors: Vec<i64> = Vec::new();
+    pub fn create_result(&self, color_count: i64) -> Vec<Argb> {
+        return self
+            ._create_clusters(color_count)
+            .into_iter()
+            .map(|(_, color, _)| color)
+            .collect();
+    }
+
+    /// The index, average color and pixel count of every box with pixels, in
+    /// the order the boxes were created.
+    fn _create_clusters(&self, color_count: i64) -> Vec<(usize, Argb, i64)> {
+        let mut clusters: Vec<(usize, Argb, i64)> = Vec::new();
         for i in 0..color_count {
             let cube = &self.cubes[i as usize];
             let weight: i64 = Self::volume(cube.clone(), self.weights.clone());
@@ -197,11 +399,11 @@ impl QuantizerWu {
                     .round() as i64;
                 let b: i64 = ((Self::volume(cube.clone(), self.moments_b.clone()) / weight) as f64)
                     .round() as i64;
-                let color: i64 = ColorUtils::argb_from_rgb(r, g, b);
-                colors.push(color);
+                let color = Argb::from_rgb(r as u8, g as u8, b as u8);
+                clusters.push((i as usize, color, weight));
             }
         }
-        return colors;
+        return clusters;
     }
 
     pub fn variance(&self, cube: Box) -> f64 {
@@ -0,0 +1,268 @@
+use std::collections::HashMap;
+
+use crate::{error::error::Error, utils::argb::Argb};
+
+use super::{
+    quantizer::QuantizerResult,
+    src::{point_provider::PointProvider, point_provider_lab::PointProviderLab},
+};
+
+static POINT_PROVIDER_LAB: PointProviderLab = PointProviderLab {};
+
+/// How a [Remapper] spreads the difference between a pixel and its palette
+/// color over the image.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Dithering {
+    /// Every pixel becomes its nearest palette color.
+    #[default]
+    None,
+    /// Floyd–Steinberg error diffusion, which spreads the whole error to the
+    /// next pixel and the three pixels below.
+    FloydSteinberg,
+    /// Atkinson error diffusion, which spreads three quarters of the error
+    /// over two rows, keeping more contrast than [Dithering::FloydSteinberg].
+    Atkinson,
+    /// Ordered dithering with a 4x4 Bayer matrix. Each pixel only depends on
+    /// its own color and position, so it doesn't need its neighbors.
+    Ordered,
+}
+
+/// Maps the pixels of an image onto a palette, such as the colors of a
+/// [QuantizerResult].
+///
+/// Pixels become the palette color they are nearest to, as measured by the
+/// point provider, which defaults to [PointProviderLab]. Alpha is ignored when
+/// colors are compared, and remapped pixels keep their own alpha.
+pub struct Remapper<'a> {
+    palette: Vec<Argb>,
+    points: Vec<Vec<f64>>,
+    point_provider: &'a dyn PointProvider,
+    dithering: Dithering,
+}
+
+impl Remapper<'static> {
+    /// Remaps onto [palette], in the given order.
+    ///
+    /// Returns [Error::EmptyPalette] if [palette] has no colors.
+    pub fn new(palette: Vec<Argb>) -> Result<Remapper<'static>, Error> {
+        if palette.is_empty() {
+            return Err(Error::EmptyPalette);
+        }
+        let points = palette
+            .iter()
+            .map(|color| POINT_PROVIDER_LAB.from_argb(*color))
+            .collect();
+        return Ok(Remapper {
+            palette,
+            points,
+            point_provider: &POINT_PROVIDER_LAB,
+            dithering: Dithering::None,
+        });
+    }
+
+    /// Remaps onto the colors of [result], most common first.
+    ///
+    /// Returns [Error::EmptyPalette] if [result] has no colors.
+    pub fn from_result(result: &QuantizerResult) -> Result<Remapper<'static>, Error> {
+        let mut entries: Vec<(Argb, i64)> = result
+            .color_to_count
+            .iter()
+            .map(|(color, count)| (*color, *count))
+            .collect();
+        entries.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        return Self::new(entries.into_iter().map(|(color, _)| color).collect());
+    }
+}
+
+impl<'a> Remapper<'a> {
+    /// The color space nearest colors are searched in, such as
+    /// [PointProviderCam16Ucs](super::src::point_provider_cam16_ucs::PointProviderCam16Ucs).
+    pub fn point_provider<'b>(self, point_provider: &'b dyn PointProvider) -> Remapper<'b> {
+        let points = self
+            .palette
+            .iter()
+            .map(|color| point_provider.from_argb(*color))
+            .collect();
+        return Remapper {
+            palette: self.palette,
+            points,
+            point_provider,
+            dithering: self.dithering,
+        };
+    }
+
+    /// How the error between pixels and palette colors is spread.
+    pub fn dithering(mut self, dithering: Dithering) -> Remapper<'a> {
+        self.dithering = dithering;
+        self
+    }
+
+    pub fn palette(&self) -> &[Argb] {
+        return &self.palette;
+    }
+
+    /// The index of the palette color nearest to [color].
+    pub fn nearest_index(&self, color: Argb) -> usize {
+        let point =
+            self.point_provider
+                .from_argb(Argb::from_rgb(color.red(), color.green(), color.blue()));
+        let mut nearest = 0;
+        let mut nearest_distance = f64::INFINITY;
+        for (index, palette_point) in self.points.iter().enumerate() {
+            let distance = self.point_provider.distance(&point, palette_point);
+            if distance < nearest_distance {
+                nearest = index;
+                nearest_distance = distance;
+            }
+        }
+        return nearest;
+    }
+
+    /// The palette color nearest to [color].
+    pub fn nearest(&self, color: Argb) -> Argb {
+        return self.palette[self.nearest_index(color)];
+    }
+
+    /// The palette index of every pixel of an image [width] pixels wide, in
+    /// row-major order.
+    ///
+    /// [width] is only used by dithering; 0 treats [pixels] as a single row.
+    pub fn indexed(&self, pixels: &[Argb], width: usize) -> Vec<usize> {
+        let width = if width == 0 { pixels.len() } else { width };
+        let mut nearest_by_color: HashMap<Argb, usize> = HashMap::new();
+        let mut nearest = |color: Argb| -> usize {
+            return *nearest_by_color
+                .entry(color)
+                .or_insert_with(|| self.nearest_index(color));
+        };
+        return match self.dithering {
+            Dithering::None => pixels.iter().map(|pixel| nearest(*pixel)).collect(),
+            Dithering::Ordered => self._ordered(pixels, width, &mut nearest),
+            Dithering::FloydSteinberg => self._diffuse(
+                pixels,
+                width,
+                &[(1, 0, 7.0), (-1, 1, 3.0), (0, 1, 5.0), (1, 1, 1.0)],
+                16.0,
+                &mut nearest,
+            ),
+            Dithering::Atkinson => self._diffuse(
+                pixels,
+                width,
+                &[
+                    (1, 0, 1.0),
+                    (2, 0, 1.0),
+                    (-1, 1, 1.0),
+                    (0, 1, 1.0),
+                    (1, 1, 1.0),
+                    (0, 2, 1.0),
+                ],
+                8.0,
+                &mut nearest,
+            ),
+        };
+    }
+
+    /// Every pixel of an image [width] pixels wide replaced by its palette
+    /// color, keeping its alpha.
+    ///
+    /// [width] is only used by dithering; 0 treats [pixels] as a single row.
+    pub fn remap(&self, pixels: &[Argb], width: usize) -> Vec<Argb> {
+        return self
+            .indexed(pixels, width)
+            .into_iter()
+            .zip(pixels)
+            .map(|(index, pixel)| {
+                let color = self.palette[index];
+                Argb::new(pixel.alpha(), color.red(), color.green(), color.blue())
+            })
+            .collect();
+    }
+
+    fn _ordered(
+        &self,
+        pixels: &[Argb],
+        width: usize,
+        nearest: &mut impl FnMut(Argb) -> usize,
+    ) -> Vec<usize> {
+        const BAYER: [[f64; 4]; 4] = [
+            [0.0, 8.0, 2.0, 10.0],
+            [12.0, 4.0, 14.0, 6.0],
+            [3.0, 11.0, 1.0, 9.0],
+            [15.0, 7.0, 13.0, 5.0],
+        ];
+        // The distance between the levels of a channel in an evenly spaced
+        // palette of as many colors.
+        let spread = 255.0 / (self.palette.len() as f64).cbrt();
+        return pixels
+            .iter()
+            .enumerate()
+            .map(|(i, pixel)| {
+                let threshold = (BAYER[(i / width) % 4][(i % width) % 4] + 0.5) / 16.0 - 0.5;
+                let offset = threshold * spread;
+                let channel = |value: u8| (value as f64 + offset).round().clamp(0.0, 255.0) as u8;
+                nearest(Argb::from_rgb(
+                    channel(pixel.red()),
+                    channel(pixel.green()),
+                    channel(pixel.blue()),
+                ))
+            })
+            .collect();
+    }
+
+    /// Error diffusion, spreading the error of each pixel to the pixels at
+    /// the (x, y) offsets of [weights], each getting weight / [divisor] of it.
+    ///
+    /// Transparent pixels neither receive nor spread errors.
+    fn _diffuse(
+        &self,
+        pixels: &[Argb],
+        width: usize,
+        weights: &[(isize, usize, f64)],
+        divisor: f64,
+        nearest: &mut impl FnMut(Argb) -> usize,
+    ) -> Vec<usize> {
+        let mut errors: Vec<[f64; 3]> = vec![[0.0; 3]; pixels.len()];
+        let mut indices: Vec<usize> = Vec::with_capacity(pixels.len());
+        for (i, pixel) in pixels.iter().enumerate() {
+            if pixel.alpha() == 0 {
+                indices.push(nearest(*pixel));
+                continue;
+            }
+            let value = [
+                (pixel.red() as f64 + errors[i][0]).clamp(0.0, 255.0),
+                (pixel.green() as f64 + errors[i][1]).clamp(0.0, 255.0),
+                (pixel.blue() as f64 + errors[i][2]).clamp(0.0, 255.0),
+            ];
+            let index = nearest(Argb::from_rgb(
+                value[0].round() as u8,
+                value[1].round() as u8,
+                value[2].round() as u8,
+            ));
+            indices.push(index);
+
+            let color = self.palette[index];
+            let error = [
+                value[0] - color.red() as f64,
+                value[1] - color.green() as f64,
+                value[2] - color.blue() as f64,
+            ];
+            let (x, y) = (i % width, i / width);
+            for (dx, dy, weight) in weights {
+                let Some(target_x) = x.checked_add_signed(*dx) else {
+                    continue;
+                };
+                if target_x >= width {
+                    continue;
+                }
+                let target = (y + dy) * width + target_x;
+                if target >= pixels.len() || pixels[target].alpha() == 0 {
+                    continue;
+                }
+                for channel in 0..3 {
+                    errors[target][channel] += error[channel] * weight / divisor;
+                }
+            }
+        }
+        return indices;
+    }
+}
@@ -1,2 +1,4 @@
 pub mod point_provider;
+pub mod point_provider_cam16_ucs;
 pub mod point_provider_lab;
+pub mod point_provider_oklab;
@@ -1,5 +1,10 @@
-pub trait PointProvider {
-    fn from_int(&self, argb: i64) -> Vec<f64>;
-    fn to_int(&self, point: &Vec<f64>) -> i64;
+use crate::utils::argb::Argb;
+
+/// A color space quantizers measure distances between colors in.
+///
+/// Providers are shared between threads when the `rayon` feature is enabled.
+pub trait PointProvider: Send + Sync {
+    fn from_argb(&self, argb: Argb) -> Vec<f64>;
+    fn to_argb(&self, point: &Vec<f64>) -> Argb;
     fn distance(&self, a: &Vec<f64>, b: &Vec<f64>) -> f64;
 }
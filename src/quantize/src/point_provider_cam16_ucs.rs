@@ -0,0 +1,31 @@
+use crate::{hct::cam16::Cam16, utils::argb::Argb};
+
+use super::point_provider::PointProvider;
+
+/// Points in CAM16-UCS, the perceptually uniform space HCT is based on.
+#[derive(Default)]
+pub struct PointProviderCam16Ucs {}
+impl PointProviderCam16Ucs {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+impl PointProvider for PointProviderCam16Ucs {
+    fn from_argb(&self, argb: Argb) -> Vec<f64> {
+        let cam = Cam16::from_argb(argb);
+        return [cam.jstar, cam.astar, cam.bstar].to_vec();
+    }
+
+    fn to_argb(&self, point: &Vec<f64>) -> Argb {
+        Cam16::from_ucs(point[0], point[1], point[2]).to_argb()
+    }
+
+    fn distance(&self, one: &Vec<f64>, two: &Vec<f64>) -> f64 {
+        let d_j = one[0] - two[0];
+        let d_a = one[1] - two[1];
+        let d_b = one[2] - two[2];
+        // Like [PointProviderLab], the square root is skipped: CAM16-UCS
+        // distance only has to preserve the ordering of distances.
+        return d_j * d_j + d_a * d_a + d_b * d_b;
+    }
+}
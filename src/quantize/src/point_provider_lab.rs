@@ -1,4 +1,4 @@
-use crate::utils::color_utils::ColorUtils;
+use crate::utils::argb::Argb;
 
 use super::point_provider::PointProvider;
 
@@ -9,12 +9,12 @@ impl PointProviderLab {
     }
 }
 impl PointProvider for PointProviderLab {
-    fn from_int(&self, argb: i64) -> Vec<f64> {
-        return ColorUtils::lab_from_argb(argb);
+    fn from_argb(&self, argb: Argb) -> Vec<f64> {
+        return argb.to_lab();
     }
 
-    fn to_int(&self, lab: &Vec<f64>) -> i64 {
-        ColorUtils::argb_from_lab(lab[0], lab[1], lab[2])
+    fn to_argb(&self, lab: &Vec<f64>) -> Argb {
+        Argb::from_lab(lab[0], lab[1], lab[2])
     }
 
     fn distance(&self, one: &Vec<f64>, two: &Vec<f64>) -> f64 {
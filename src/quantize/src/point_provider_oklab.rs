@@ -0,0 +1,55 @@
+use crate::utils::{argb::Argb, color_utils::ColorUtils};
+
+use super::point_provider::PointProvider;
+
+/// Points in OKLab, a perceptually uniform space that is cheaper to compute
+/// than CAM16-UCS.
+///
+/// See https://bottosson.github.io/posts/oklab/.
+#[derive(Default)]
+pub struct PointProviderOklab {}
+impl PointProviderOklab {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+impl PointProvider for PointProviderOklab {
+    fn from_argb(&self, argb: Argb) -> Vec<f64> {
+        let r = ColorUtils::linearized(argb.red() as i64) / 100.0;
+        let g = ColorUtils::linearized(argb.green() as i64) / 100.0;
+        let b = ColorUtils::linearized(argb.blue() as i64) / 100.0;
+
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+        return [
+            0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+        ]
+        .to_vec();
+    }
+
+    fn to_argb(&self, point: &Vec<f64>) -> Argb {
+        let l = (point[0] + 0.3963377774 * point[1] + 0.2158037573 * point[2]).powi(3);
+        let m = (point[0] - 0.1055613458 * point[1] - 0.0638541728 * point[2]).powi(3);
+        let s = (point[0] - 0.0894841775 * point[1] - 1.2914855480 * point[2]).powi(3);
+
+        let r = 4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s;
+        let g = -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s;
+        let b = -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s;
+        Argb::from_rgb(
+            ColorUtils::delinearized(r * 100.0) as u8,
+            ColorUtils::delinearized(g * 100.0) as u8,
+            ColorUtils::delinearized(b * 100.0) as u8,
+        )
+    }
+
+    fn distance(&self, one: &Vec<f64>, two: &Vec<f64>) -> f64 {
+        let d_l = one[0] - two[0];
+        let d_a = one[1] - two[1];
+        let d_b = one[2] - two[2];
+        return d_l * d_l + d_a * d_a + d_b * d_b;
+    }
+}
@@ -1,4 +1,5 @@
 pub mod scheme;
+pub mod scheme_builder;
 pub mod scheme_content;
 pub mod scheme_expressive;
 pub mod scheme_fidelity;
@@ -53,7 +53,7 @@ impl Scheme {
     pub fn dark_content(color: i64) -> Scheme {
         Self::dark_from_core_palette(CorePalette::content_of(color))
     }
-    pub fn light_from_core_palette(mut palette: CorePalette) -> Scheme {
+    pub fn light_from_core_palette(palette: CorePalette) -> Scheme {
         Scheme {
             primary: palette.primary.get(40),
             on_primary: palette.primary.get(100),
@@ -86,7 +86,7 @@ impl Scheme {
             inverse_primary: palette.primary.get(80),
         }
     }
-    pub fn dark_from_core_palette(mut palette: CorePalette) -> Scheme {
+    pub fn dark_from_core_palette(palette: CorePalette) -> Scheme {
         Scheme {
             primary: palette.primary.get(80),
             on_primary: palette.primary.get(20),
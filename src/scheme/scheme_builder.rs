@@ -0,0 +1,178 @@
+use crate::{
+    dynamiccolor::{
+        dynamic_scheme::DynamicScheme,
+        spec_version::{Platform, SpecVersion},
+        variant::Variant,
+    },
+    hct::hct::Hct,
+    palettes::tonal_palette::TonalPalette,
+};
+
+/// Builds a [DynamicScheme] of any [Variant], optionally replacing some of
+/// the palettes the variant would generate.
+///
+/// Defaults to a light [Variant::TonalSpot] scheme at standard contrast,
+/// following [SpecVersion::Spec2021] on a [Platform::Phone].
+#[derive(Clone, Debug)]
+pub struct SchemeBuilder {
+    source_color_hct: Hct,
+    variant: Variant,
+    is_dark: bool,
+    contrast_level: f64,
+    spec_version: SpecVersion,
+    platform: Platform,
+    primary_palette: Option<TonalPalette>,
+    secondary_palette: Option<TonalPalette>,
+    tertiary_palette: Option<TonalPalette>,
+    neutral_palette: Option<TonalPalette>,
+    neutral_variant_palette: Option<TonalPalette>,
+    error_palette: Option<TonalPalette>,
+    success_palette: Option<TonalPalette>,
+    warning_palette: Option<TonalPalette>,
+    info_palette: Option<TonalPalette>,
+}
+
+impl SchemeBuilder {
+    pub fn new(source_color_hct: Hct) -> SchemeBuilder {
+        SchemeBuilder {
+            source_color_hct,
+            variant: Variant::TonalSpot,
+            is_dark: false,
+            contrast_level: 0.0,
+            spec_version: SpecVersion::Spec2021,
+            platform: Platform::Phone,
+            primary_palette: None,
+            secondary_palette: None,
+            tertiary_palette: None,
+            neutral_palette: None,
+            neutral_variant_palette: None,
+            error_palette: None,
+            success_palette: None,
+            warning_palette: None,
+            info_palette: None,
+        }
+    }
+
+    /// The variant, or style, of the scheme.
+    pub fn variant(mut self, variant: Variant) -> SchemeBuilder {
+        self.variant = variant;
+        self
+    }
+
+    /// Whether the scheme is in dark mode.
+    pub fn is_dark(mut self, is_dark: bool) -> SchemeBuilder {
+        self.is_dark = is_dark;
+        self
+    }
+
+    /// Value from -1 to 1, see [DynamicScheme.contrast_level].
+    pub fn contrast_level(mut self, contrast_level: f64) -> SchemeBuilder {
+        self.contrast_level = contrast_level;
+        self
+    }
+
+    /// The version of the specification the scheme follows.
+    pub fn spec_version(mut self, spec_version: SpecVersion) -> SchemeBuilder {
+        self.spec_version = spec_version;
+        self
+    }
+
+    /// The kind of device the scheme is displayed on.
+    pub fn platform(mut self, platform: Platform) -> SchemeBuilder {
+        self.platform = platform;
+        self
+    }
+
+    /// Replaces the primary palette generated by the variant.
+    pub fn primary_palette(mut self, palette: TonalPalette) -> SchemeBuilder {
+        self.primary_palette = Some(palette);
+        self
+    }
+
+    /// Replaces the secondary palette generated by the variant.
+    pub fn secondary_palette(mut self, palette: TonalPalette) -> SchemeBuilder {
+        self.secondary_palette = Some(palette);
+        self
+    }
+
+    /// Replaces the tertiary palette generated by the variant.
+    pub fn tertiary_palette(mut self, palette: TonalPalette) -> SchemeBuilder {
+        self.tertiary_palette = Some(palette);
+        self
+    }
+
+    /// Replaces the neutral palette generated by the variant.
+    pub fn neutral_palette(mut self, palette: TonalPalette) -> SchemeBuilder {
+        self.neutral_palette = Some(palette);
+        self
+    }
+
+    /// Replaces the neutral variant palette generated by the variant.
+    pub fn neutral_variant_palette(mut self, palette: TonalPalette) -> SchemeBuilder {
+        self.neutral_variant_palette = Some(palette);
+        self
+    }
+
+    /// Replaces the default error palette.
+    pub fn error_palette(mut self, palette: TonalPalette) -> SchemeBuilder {
+        self.error_palette = Some(palette);
+        self
+    }
+
+    /// Replaces the default success palette.
+    pub fn success_palette(mut self, palette: TonalPalette) -> SchemeBuilder {
+        self.success_palette = Some(palette);
+        self
+    }
+
+    /// Replaces the default warning palette.
+    pub fn warning_palette(mut self, palette: TonalPalette) -> SchemeBuilder {
+        self.warning_palette = Some(palette);
+        self
+    }
+
+    /// Replaces the default info palette.
+    pub fn info_palette(mut self, palette: TonalPalette) -> SchemeBuilder {
+        self.info_palette = Some(palette);
+        self
+    }
+
+    pub fn build(self) -> DynamicScheme {
+        let mut scheme = DynamicScheme::from_spec(
+            self.variant,
+            self.source_color_hct,
+            self.is_dark,
+            self.contrast_level,
+            self.spec_version,
+            self.platform,
+        );
+        if let Some(palette) = self.primary_palette {
+            scheme.primary_palette = palette;
+        }
+        if let Some(palette) = self.secondary_palette {
+            scheme.secondary_palette = palette;
+        }
+        if let Some(palette) = self.tertiary_palette {
+            scheme.tertiary_palette = palette;
+        }
+        if let Some(palette) = self.neutral_palette {
+            scheme.neutral_palette = palette;
+        }
+        if let Some(palette) = self.neutral_variant_palette {
+            scheme.neutral_variant_palette = palette;
+        }
+        if let Some(palette) = self.error_palette {
+            scheme.error_palette = palette;
+        }
+        if let Some(palette) = self.success_palette {
+            scheme.success_palette = palette;
+        }
+        if let Some(palette) = self.warning_palette {
+            scheme.warning_palette = palette;
+        }
+        if let Some(palette) = self.info_palette {
+            scheme.info_palette = palette;
+        }
+        return scheme;
+    }
+}
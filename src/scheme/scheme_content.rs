@@ -21,10 +21,11 @@ pub struct SchemeContent {
 impl SchemeContent {
     pub fn new(source_color_hct: Hct, is_dark: bool, contrast_level: f64) -> SchemeContent {
         let binding = TemperatureCache::new(source_color_hct.clone()).analogous(Some(3), Some(6));
-        let temp = binding.last().unwrap();
+        // [analogous] always includes the source color, so it's never empty.
+        let temp = binding.last().unwrap_or(&source_color_hct);
         SchemeContent {
-            dynamic_scheme: DynamicScheme::new(
-                source_color_hct.to_int(),
+            dynamic_scheme: DynamicScheme::from_palettes(
+                source_color_hct.to_argb(),
                 Variant::Content,
                 Some(contrast_level),
                 is_dark,
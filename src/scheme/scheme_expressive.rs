@@ -24,8 +24,8 @@ impl SchemeExpressive {
 
     pub fn new(source_color_hct: Hct, is_dark: bool, contrast_level: f64) -> SchemeExpressive {
         SchemeExpressive {
-            dynamic_scheme: DynamicScheme::new(
-                source_color_hct.to_int(),
+            dynamic_scheme: DynamicScheme::from_palettes(
+                source_color_hct.to_argb(),
                 Variant::Expressive,
                 Some(contrast_level),
                 is_dark,
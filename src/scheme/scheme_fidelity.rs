@@ -22,8 +22,8 @@ impl SchemeFidelity {
         let hue_of_source_color = source_color_hct.get_hue();
         let chroma_of_source_color = source_color_hct.get_chroma();
         SchemeFidelity {
-            dynamic_scheme: DynamicScheme::new(
-                source_color_hct.to_int(),
+            dynamic_scheme: DynamicScheme::from_palettes(
+                source_color_hct.to_argb(),
                 Variant::Fidelity,
                 Some(contrast_level),
                 is_dark,
@@ -12,8 +12,8 @@ pub struct SchemeFruitSalad {
 impl SchemeFruitSalad {
     pub fn new(source_color_hct: Hct, is_dark: bool, contrast_level: f64) -> SchemeFruitSalad {
         SchemeFruitSalad {
-            dynamic_scheme: DynamicScheme::new(
-                source_color_hct.to_int(),
+            dynamic_scheme: DynamicScheme::from_palettes(
+                source_color_hct.to_argb(),
                 Variant::FruitSalad,
                 Some(contrast_level),
                 is_dark,
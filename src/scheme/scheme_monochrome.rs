@@ -11,8 +11,8 @@ pub struct SchemeMonochrome {
 impl SchemeMonochrome {
     pub fn new(source_color_hct: Hct, is_dark: bool, contrast_level: f64) -> SchemeMonochrome {
         SchemeMonochrome {
-            dynamic_scheme: DynamicScheme::new(
-                source_color_hct.to_int(),
+            dynamic_scheme: DynamicScheme::from_palettes(
+                source_color_hct.to_argb(),
                 Variant::Monochrome,
                 Some(contrast_level),
                 is_dark,
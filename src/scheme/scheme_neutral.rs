@@ -11,8 +11,8 @@ pub struct SchemeNeutral {
 impl SchemeNeutral {
     pub fn new(source_color_hct: Hct, is_dark: bool, contrast_level: f64) -> SchemeNeutral {
         SchemeNeutral {
-            dynamic_scheme: DynamicScheme::new(
-                source_color_hct.to_int(),
+            dynamic_scheme: DynamicScheme::from_palettes(
+                source_color_hct.to_argb(),
                 Variant::Neutral,
                 Some(contrast_level),
                 is_dark,
@@ -12,8 +12,8 @@ pub struct SchemeRainbow {
 impl SchemeRainbow {
     pub fn new(source_color_hct: Hct, is_dark: bool, contrast_level: f64) -> SchemeRainbow {
         SchemeRainbow {
-            dynamic_scheme: DynamicScheme::new(
-                source_color_hct.to_int(),
+            dynamic_scheme: DynamicScheme::from_palettes(
+                source_color_hct.to_argb(),
                 Variant::Rainbow,
                 Some(contrast_level),
                 is_dark,
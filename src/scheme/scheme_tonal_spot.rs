@@ -14,8 +14,8 @@ pub struct SchemeTonalSpot {
 impl SchemeTonalSpot {
     pub fn new(source_color_hct: Hct, is_dark: bool, contrast_level: f64) -> SchemeTonalSpot {
         SchemeTonalSpot {
-            dynamic_scheme: DynamicScheme::new(
-                source_color_hct.to_int(),
+            dynamic_scheme: DynamicScheme::from_palettes(
+                source_color_hct.to_argb(),
                 Variant::TonalSpot,
                 Some(contrast_level),
                 is_dark,
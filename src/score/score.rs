@@ -1,10 +1,14 @@
 use std::collections::HashMap;
 
-use crate::{hct::hct::Hct, utils::math_utils::MathUtils};
+use crate::{
+    hct::hct::Hct,
+    utils::{argb::Argb, math_utils::MathUtils},
+};
 
 struct _ScoredHCT {
     hct: Hct,
     score: f64,
+    index: usize,
 }
 impl _ScoredHCT {
     pub fn _compare_to(&self, other: &_ScoredHCT) -> i64 {
@@ -44,6 +48,177 @@ impl Ord for _ScoredHCT {
     }
 }
 
+/// A range of hues in degrees, from [start] to [end] inclusive.
+///
+/// Ranges with [start] greater than [end] wrap around 0, so 330 to 30 covers
+/// the reds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HueRange {
+    pub start: f64,
+    pub end: f64,
+}
+
+impl HueRange {
+    pub fn new(start: f64, end: f64) -> HueRange {
+        HueRange {
+            start: MathUtils::sanitize_degrees_double(start),
+            end: MathUtils::sanitize_degrees_double(end),
+        }
+    }
+
+    /// Whether [hue] is within the range.
+    pub fn contains(&self, hue: f64) -> bool {
+        let hue = MathUtils::sanitize_degrees_double(hue);
+        if self.start <= self.end {
+            return self.start <= hue && hue <= self.end;
+        }
+        return hue >= self.start || hue <= self.end;
+    }
+}
+
+/// Hues that colors must, or must not, have to be ranked by [Score].
+#[derive(Clone, Debug, PartialEq)]
+pub enum HueFilter {
+    /// Only colors with a hue in one of the ranges are ranked.
+    Allow(Vec<HueRange>),
+    /// Colors with a hue in one of the ranges aren't ranked.
+    Deny(Vec<HueRange>),
+}
+
+impl HueFilter {
+    /// Whether colors with [hue] can be ranked.
+    pub fn allows(&self, hue: f64) -> bool {
+        return match self {
+            HueFilter::Allow(ranges) => ranges.iter().any(|range| range.contains(hue)),
+            HueFilter::Deny(ranges) => !ranges.iter().any(|range| range.contains(hue)),
+        };
+    }
+}
+
+/// Options of [Score::score_with].
+///
+/// The defaults match [Score::score_argb].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoreOptions {
+    /// The maximum number of colors returned.
+    pub desired: i64,
+    /// The color returned if no other options are available.
+    pub fallback_color: Argb,
+    /// Whether colors below [cutoff_chroma] or [cutoff_excited_proportion]
+    /// are filtered out.
+    pub filter: bool,
+    /// The chroma colors are scored against. Colors above it score higher.
+    pub target_chroma: f64,
+    /// The weight of the proportion of colors with a nearby hue.
+    pub weight_proportion: f64,
+    /// The weight of the chroma of colors above [target_chroma].
+    pub weight_chroma_above: f64,
+    /// The weight of the chroma of colors below [target_chroma].
+    pub weight_chroma_below: f64,
+    /// Colors with less chroma are filtered out.
+    pub cutoff_chroma: f64,
+    /// Colors whose hue is shared by this proportion of the image or less are
+    /// filtered out.
+    pub cutoff_excited_proportion: f64,
+    /// The population of a hue counts towards the hues up to
+    /// [hue_excitation_radius] - 1 degrees below and [hue_excitation_radius]
+    /// degrees above it.
+    pub hue_excitation_radius: i64,
+    /// The hue difference chosen colors start with. It shrinks by a degree at
+    /// a time until enough colors are chosen.
+    pub max_hue_separation: i64,
+    /// The smallest hue difference allowed between chosen colors. Values
+    /// above [max_hue_separation] are treated as [max_hue_separation].
+    pub min_hue_separation: i64,
+    /// Hues that are, or aren't, ranked. Colors whose hue is filtered out
+    /// still count towards the proportions of nearby hues.
+    pub hue_filter: Option<HueFilter>,
+}
+
+impl Default for ScoreOptions {
+    fn default() -> Self {
+        ScoreOptions {
+            desired: 4,
+            fallback_color: Argb(0xff4285f4),
+            filter: true,
+            target_chroma: 48.0, // A1 Chroma
+            weight_proportion: 0.7,
+            weight_chroma_above: 0.3,
+            weight_chroma_below: 0.1,
+            cutoff_chroma: 5.0,
+            cutoff_excited_proportion: 0.01,
+            hue_excitation_radius: 15,
+            max_hue_separation: 90,
+            min_hue_separation: 15,
+            hue_filter: None,
+        }
+    }
+}
+
+/// The cutoff a color was filtered out by in [Score::score_detailed].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ScoreCutoff {
+    /// Its hue is denied, or not allowed, by [ScoreOptions::hue_filter].
+    Hue,
+    /// Its chroma is below [ScoreOptions::cutoff_chroma].
+    Chroma,
+    /// The proportion of its hue is at most
+    /// [ScoreOptions::cutoff_excited_proportion].
+    ExcitedProportion,
+}
+
+/// What became of a color in [Score::score_detailed].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ScoreOutcome {
+    /// The color was chosen, at this index of the ranked colors.
+    Chosen(usize),
+    /// The color was filtered out before being ranked.
+    Filtered(ScoreCutoff),
+    /// The hue of the color is too close to a chosen color with a higher
+    /// score, see [ScoreDetails::hue_separation].
+    DuplicateHue,
+    /// Enough colors with a higher score were chosen before this one was
+    /// considered.
+    NotNeeded,
+}
+
+/// An input color of [Score::score_detailed] with how it was scored.
+#[derive(Clone, Debug)]
+pub struct ScoredColor {
+    pub argb: Argb,
+    pub hct: Hct,
+    /// How often the color appears.
+    pub population: i64,
+    /// [population] as a proportion of every input color.
+    pub proportion: f64,
+    /// The proportion of colors with a hue near this color's, see
+    /// [ScoreOptions::hue_excitation_radius].
+    pub excited_proportion: f64,
+    /// The part of [score] from [excited_proportion].
+    pub proportion_score: f64,
+    /// The part of [score] from the chroma of the color.
+    pub chroma_score: f64,
+    /// The total score, also computed for filtered out colors.
+    pub score: f64,
+    pub outcome: ScoreOutcome,
+}
+
+/// The ranked colors of [Score::score_detailed], with how every input color
+/// was scored.
+#[derive(Clone, Debug)]
+pub struct ScoreDetails {
+    /// The same colors as [Score::score_with] returns.
+    pub ranked: Vec<Argb>,
+    /// Every input color, highest score first.
+    pub colors: Vec<ScoredColor>,
+    /// The smallest hue difference between the chosen colors that was
+    /// enforced, in degrees.
+    pub hue_separation: i64,
+    /// Whether [ranked] is only the fallback color, because no color was
+    /// chosen.
+    pub used_fallback: bool,
+}
+
 /// Given a large set of colors, remove colors that are unsuitable for a UI
 /// theme, and rank the rest based on suitability.
 ///
@@ -52,13 +227,6 @@ impl Ord for _ScoredHCT {
 ///  smaller number of appropriate choices.
 pub struct Score {}
 impl Score {
-    const _TARGET_CHROMA: f64 = 48.0; // A1 Chroma
-    const _WEIGHT_PROPORTION: f64 = 0.7;
-    const _WEIGHT_CHROMA_ABOVE: f64 = 0.3;
-    const _WEIGHT_CHROMA_BELOW: f64 = 0.1;
-    const _CUTOFF_CHROMA: f64 = 5.0;
-    const _CUTOFF_EXCITED_PROPORTION: f64 = 0.01;
-
     /// Given a map with keys of colors and values of how often the color appears,
     /// rank the colors based on suitability for being used for a UI theme.
     ///
@@ -74,38 +242,84 @@ impl Score {
     /// a default fallback color will be provided, Google Blue. The default
     /// number of colors returned is 4, simply because thats the # of colors
     /// display in Android 12's wallpaper picker.
+    pub fn score_argb(
+        colors_to_population: &HashMap<Argb, i64>,
+        desired: Option<i64>,
+        fallback_color_argb: Option<Argb>,
+        filter: Option<bool>,
+    ) -> Vec<Argb> {
+        let defaults = ScoreOptions::default();
+        let options = ScoreOptions {
+            desired: desired.unwrap_or(defaults.desired),
+            fallback_color: fallback_color_argb.unwrap_or(defaults.fallback_color),
+            filter: filter.unwrap_or(defaults.filter),
+            ..defaults
+        };
+        return Self::score_with(colors_to_population, &options);
+    }
+
+    #[deprecated = "use `Score::score_argb` instead"]
     pub fn score(
         colors_to_population: &HashMap<i64, i64>,
         desired: Option<i64>,
         fallback_color_argb: Option<i64>,
         filter: Option<bool>,
     ) -> Vec<i64> {
-        let desired = desired.unwrap_or(4);
-        let fallback_color_argb = fallback_color_argb.unwrap_or(0xff4285f4);
-        let filter = filter.unwrap_or(true);
+        let colors_to_population: HashMap<Argb, i64> = colors_to_population
+            .iter()
+            .map(|(argb, population)| (Argb(*argb as u32), *population))
+            .collect();
+        return Self::score_argb(
+            &colors_to_population,
+            desired,
+            fallback_color_argb.map(|argb| Argb(argb as u32)),
+            filter,
+        )
+        .into_iter()
+        .map(|argb| argb.into())
+        .collect();
+    }
+
+    /// Like [score_argb], with every constant of the ranking taken from [options].
+    pub fn score_with(
+        colors_to_population: &HashMap<Argb, i64>,
+        options: &ScoreOptions,
+    ) -> Vec<Argb> {
+        return Self::score_detailed(colors_to_population, options).ranked;
+    }
+
+    /// Like [score_with], also explaining how every color of
+    /// [colors_to_population] was scored and why it was or wasn't chosen.
+    pub fn score_detailed(
+        colors_to_population: &HashMap<Argb, i64>,
+        options: &ScoreOptions,
+    ) -> ScoreDetails {
+        let desired = options.desired;
+        let filter = options.filter;
 
         // Get the HCT color for each Argb value, while finding the per hue count and
         // total count.
-        let mut colors_hct: Vec<Hct> = Vec::new();
+        let mut colors_hct: Vec<(Argb, Hct, i64)> = Vec::new();
         let mut hue_population = [0; 360]; //List<int>.filled(360, 0);
         let mut population_sum = 0;
         for (key, value) in colors_to_population {
             let argb = key;
             let population = value;
-            let hct = Hct::from_int(*argb);
-            colors_hct.push(hct.clone());
+            let hct = Hct::from_argb(*argb);
+            colors_hct.push((*argb, hct.clone(), *population));
             let hue = hct.get_hue().floor();
             hue_population[hue as usize] += population;
             population_sum += population;
         }
 
-        // Hues with more usage in neighboring 30 degree slice get a larger number.
+        // Hues with more usage in the neighboring slice of
+        // 2 * [hue_excitation_radius] degrees get a larger number.
         let mut hue_excited_proportions = [0.0; 360];
         let mut hue: i64 = 0;
         while hue < 360 {
             let proportion = (hue_population[hue as usize] as f64) / (population_sum as f64);
-            let mut i: i64 = hue - 14;
-            while i < hue + 16 {
+            let mut i: i64 = hue - (options.hue_excitation_radius - 1);
+            while i < hue + options.hue_excitation_radius + 1 {
                 let neighbor_hue = MathUtils::sanitize_degrees_int(i);
                 hue_excited_proportions[neighbor_hue as usize] += proportion as f64;
                 i += 1;
@@ -115,50 +329,85 @@ impl Score {
 
         // Scores each HCT color based on usage and chroma, while optionally
         // filtering out values that do not have enough chroma or usage.
+        let mut details: Vec<ScoredColor> = Vec::new();
         let mut scored_hcts: Vec<_ScoredHCT> = Vec::new();
-        for hct in colors_hct {
+        for (argb, hct, population) in colors_hct {
             let hue = MathUtils::sanitize_degrees_int(hct.get_hue().round() as i64);
             let proportion = hue_excited_proportions[hue as usize];
-            if filter
-                && (hct.get_chroma() < Self::_CUTOFF_CHROMA
-                    || proportion <= Self::_CUTOFF_EXCITED_PROPORTION)
+            let cutoff = if options
+                .hue_filter
+                .as_ref()
+                .is_some_and(|hue_filter| !hue_filter.allows(hct.get_hue()))
             {
-                continue;
-            }
+                Some(ScoreCutoff::Hue)
+            } else if filter && hct.get_chroma() < options.cutoff_chroma {
+                Some(ScoreCutoff::Chroma)
+            } else if filter && proportion <= options.cutoff_excited_proportion {
+                Some(ScoreCutoff::ExcitedProportion)
+            } else {
+                None
+            };
 
-            let proportion_score = proportion * 100.0 * Self::_WEIGHT_PROPORTION;
+            let proportion_score = proportion * 100.0 * options.weight_proportion;
             let chroma_weight = {
-                if hct.get_chroma() < Self::_TARGET_CHROMA {
-                    Self::_WEIGHT_CHROMA_BELOW
+                if hct.get_chroma() < options.target_chroma {
+                    options.weight_chroma_below
                 } else {
-                    Self::_WEIGHT_CHROMA_ABOVE
+                    options.weight_chroma_above
                 }
             };
 
-            let chroma_score = (hct.get_chroma() - Self::_TARGET_CHROMA) * chroma_weight;
+            let chroma_score = (hct.get_chroma() - options.target_chroma) * chroma_weight;
             let score = proportion_score + chroma_score;
-            scored_hcts.push(_ScoredHCT { hct, score })
+            if cutoff.is_none() {
+                scored_hcts.push(_ScoredHCT {
+                    hct: hct.clone(),
+                    score,
+                    index: details.len(),
+                });
+            }
+            details.push(ScoredColor {
+                argb,
+                hct,
+                population,
+                proportion: population as f64 / population_sum as f64,
+                excited_proportion: proportion,
+                proportion_score,
+                chroma_score,
+                score,
+                outcome: match cutoff {
+                    Some(cutoff) => ScoreOutcome::Filtered(cutoff),
+                    None => ScoreOutcome::NotNeeded,
+                },
+            });
         }
         // Sorted so that colors with higher scores come first.
         scored_hcts.sort();
 
         // Iterates through potential hue differences in degrees in order to select
         // the colors with the largest distribution of hues possible. Starting at
-        // 90 degrees(maximum difference for 4 colors) then decreasing down to a
-        // 15 degree minimum.
-        let mut chosen_colors: Vec<&Hct> = Vec::new();
-        let mut difference_degrees: i64 = 90;
-        while difference_degrees >= 15 {
+        // [max_hue_separation] then decreasing down to [min_hue_separation].
+        let mut chosen_colors: Vec<&_ScoredHCT> = Vec::new();
+        let mut duplicate_colors: Vec<&_ScoredHCT> = Vec::new();
+        let mut difference_degrees: i64 = options.max_hue_separation;
+        let mut hue_separation = difference_degrees;
+        // Clamped so that at least one hue difference is tried.
+        let min_hue_separation = options.min_hue_separation.min(options.max_hue_separation);
+        while difference_degrees >= min_hue_separation {
             chosen_colors.clear();
+            duplicate_colors.clear();
+            hue_separation = difference_degrees;
             for entry in &scored_hcts {
                 let hct = &entry.hct;
-                let duplicate_hue = chosen_colors.iter().find(|chosen_hct| {
-                    MathUtils::difference_degrees(hct.get_hue(), chosen_hct.get_hue())
+                let duplicate_hue = chosen_colors.iter().find(|chosen| {
+                    MathUtils::difference_degrees(hct.get_hue(), chosen.hct.get_hue())
                         < difference_degrees as f64
                 });
 
                 if duplicate_hue.is_none() {
-                    chosen_colors.push(hct);
+                    chosen_colors.push(entry);
+                } else {
+                    duplicate_colors.push(entry);
                 }
                 if chosen_colors.len() as i64 >= desired {
                     break;
@@ -169,13 +418,29 @@ impl Score {
             }
             difference_degrees -= 1;
         }
-        let mut colors: Vec<i64> = Vec::new();
+        let mut colors: Vec<Argb> = Vec::new();
         if chosen_colors.is_empty() {
-            colors.push(fallback_color_argb);
+            colors.push(options.fallback_color);
         }
-        for chosen_hct in chosen_colors {
-            colors.push(chosen_hct.to_int());
+        for (rank, chosen) in chosen_colors.iter().enumerate() {
+            colors.push(chosen.hct.to_argb());
+            details[chosen.index].outcome = ScoreOutcome::Chosen(rank);
         }
-        return colors;
+        for duplicate in &duplicate_colors {
+            details[duplicate.index].outcome = ScoreOutcome::DuplicateHue;
+        }
+
+        // Highest score first, keeping the ranking order for equal scores.
+        details.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        return ScoreDetails {
+            used_fallback: chosen_colors.is_empty(),
+            ranked: colors,
+            colors: details,
+            hue_separation,
+        };
     }
 }
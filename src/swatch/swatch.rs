@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+
+use crate::{
+    contrast::contrast::Contrast,
+    dynamiccolor::dynamic_color::DynamicColor,
+    hct::hct::Hct,
+    quantize::{
+        quantizer::{Quantizer, QuantizerResult},
+        quantizer_celebi::QuantizerCelebi,
+    },
+    utils::argb::Argb,
+};
+
+/// A color of an image with how often it appears, and text colors that can be
+/// drawn over it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Swatch {
+    pub argb: Argb,
+    pub population: i64,
+    /// A neutral color with a contrast ratio of at least
+    /// [Swatch::TITLE_TEXT_RATIO] against [argb].
+    pub title_text_color: Argb,
+    /// A neutral color with a contrast ratio of at least
+    /// [Swatch::BODY_TEXT_RATIO] against [argb].
+    pub body_text_color: Argb,
+}
+
+impl Swatch {
+    /// The minimum contrast ratio of title text, which is large.
+    pub const TITLE_TEXT_RATIO: f64 = 3.0;
+    /// The minimum contrast ratio of body text.
+    pub const BODY_TEXT_RATIO: f64 = 4.5;
+
+    pub fn new(argb: Argb, population: i64) -> Swatch {
+        let tone = argb.lstar();
+        Swatch {
+            argb,
+            population,
+            title_text_color: Argb::from_lstar(Self::text_tone(tone, Self::TITLE_TEXT_RATIO)),
+            body_text_color: Argb::from_lstar(Self::text_tone(tone, Self::BODY_TEXT_RATIO)),
+        }
+    }
+
+    pub fn hct(&self) -> Hct {
+        return Hct::from_argb(self.argb);
+    }
+
+    /// The tone of text with a contrast ratio of at least [ratio] against
+    /// [tone].
+    ///
+    /// Light text is preferred where [DynamicColor::tone_prefers_light_foreground]
+    /// does. If neither light nor dark text reaches [ratio], which is only
+    /// possible above a ratio of 4.5, the one with more contrast is used.
+    pub fn text_tone(tone: f64, ratio: f64) -> f64 {
+        let lighter = Contrast::try_lighter(tone, ratio);
+        let darker = Contrast::try_darker(tone, ratio);
+        let (preferred, other) = if DynamicColor::tone_prefers_light_foreground(tone) {
+            (lighter, darker)
+        } else {
+            (darker, lighter)
+        };
+        if let Ok(text_tone) = preferred.or(other) {
+            return text_tone;
+        }
+        if Contrast::ratio_of_tones(tone, 100.0) >= Contrast::ratio_of_tones(tone, 0.0) {
+            return 100.0;
+        }
+        return 0.0;
+    }
+}
+
+/// The chroma and tone a [SwatchKind] looks for, in HCT.
+///
+/// Swatches outside of the minimum and maximum are never picked. The others
+/// are scored by how close their chroma and tone are to the targets and by
+/// their population, each with its weight.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SwatchTarget {
+    pub min_chroma: f64,
+    pub target_chroma: f64,
+    pub max_chroma: f64,
+    pub min_tone: f64,
+    pub target_tone: f64,
+    pub max_tone: f64,
+    pub chroma_weight: f64,
+    pub tone_weight: f64,
+    pub population_weight: f64,
+}
+
+impl SwatchTarget {
+    /// Whether [hct] is within the limits of the target.
+    pub fn accepts(&self, hct: &Hct) -> bool {
+        return (self.min_chroma..=self.max_chroma).contains(&hct.get_chroma())
+            && (self.min_tone..=self.max_tone).contains(&hct.get_tone());
+    }
+
+    /// How well [hct], with [population] out of [max_population] pixels,
+    /// fits the target. Higher is better.
+    pub fn score(&self, hct: &Hct, population: i64, max_population: i64) -> f64 {
+        let chroma_score = 1.0 - (hct.get_chroma() - self.target_chroma).abs() / 100.0;
+        let tone_score = 1.0 - (hct.get_tone() - self.target_tone).abs() / 100.0;
+        let population_score = population as f64 / max_population.max(1) as f64;
+        return chroma_score * self.chroma_weight
+            + tone_score * self.tone_weight
+            + population_score * self.population_weight;
+    }
+}
+
+/// The swatches picked by a [SwatchPalette], like the targets of the AndroidX
+/// Palette library.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SwatchKind {
+    LightVibrant,
+    Vibrant,
+    DarkVibrant,
+    LightMuted,
+    Muted,
+    DarkMuted,
+}
+
+impl SwatchKind {
+    /// Every kind, in the order swatches are picked.
+    pub const ALL: [SwatchKind; 6] = [
+        SwatchKind::LightVibrant,
+        SwatchKind::Vibrant,
+        SwatchKind::DarkVibrant,
+        SwatchKind::LightMuted,
+        SwatchKind::Muted,
+        SwatchKind::DarkMuted,
+    ];
+
+    /// The chroma and tone swatches of this kind are picked by.
+    ///
+    /// The tones and weights are those of AndroidX Palette, with tone in place
+    /// of lightness. Vibrant swatches have a chroma of at least 32, muted ones
+    /// at most 32.
+    pub fn target(self) -> SwatchTarget {
+        let (min_chroma, target_chroma, max_chroma) = match self {
+            SwatchKind::LightVibrant | SwatchKind::Vibrant | SwatchKind::DarkVibrant => {
+                (32.0, 72.0, f64::INFINITY)
+            }
+            SwatchKind::LightMuted | SwatchKind::Muted | SwatchKind::DarkMuted => (0.0, 16.0, 32.0),
+        };
+        let (min_tone, target_tone, max_tone) = match self {
+            SwatchKind::LightVibrant | SwatchKind::LightMuted => (55.0, 74.0, 100.0),
+            SwatchKind::Vibrant | SwatchKind::Muted => (30.0, 50.0, 70.0),
+            SwatchKind::DarkVibrant | SwatchKind::DarkMuted => (0.0, 26.0, 45.0),
+        };
+        return SwatchTarget {
+            min_chroma,
+            target_chroma,
+            max_chroma,
+            min_tone,
+            target_tone,
+            max_tone,
+            chroma_weight: 0.24,
+            tone_weight: 0.52,
+            population_weight: 0.24,
+        };
+    }
+}
+
+/// The swatches of an image, with a vibrant, light vibrant, dark vibrant,
+/// muted, light muted and dark muted swatch picked among them.
+///
+/// Kinds are picked in the order of [SwatchKind::ALL], and a swatch is picked
+/// for one kind at most. A kind has no swatch if no remaining swatch is within
+/// its [SwatchTarget].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SwatchPalette {
+    swatches: Vec<Swatch>,
+    picked: HashMap<SwatchKind, Swatch>,
+}
+
+impl SwatchPalette {
+    /// The default number of colors images are quantized to, as in AndroidX
+    /// Palette.
+    pub const DEFAULT_MAX_COLORS: i64 = 16;
+
+    /// Quantizes [pixels] with [QuantizerCelebi] to at most [max_colors]
+    /// colors, [DEFAULT_MAX_COLORS] by default, and picks swatches among them.
+    pub fn from_pixels(pixels: &[Argb], max_colors: Option<i64>) -> SwatchPalette {
+        let max_colors = max_colors.unwrap_or(Self::DEFAULT_MAX_COLORS);
+        let result = QuantizerCelebi {}.quantize(pixels, max_colors, None);
+        return Self::from_result(&result);
+    }
+
+    /// Picks swatches among the colors of [result].
+    pub fn from_result(result: &QuantizerResult) -> SwatchPalette {
+        return Self::from_populations(&result.color_to_count);
+    }
+
+    /// Picks swatches among the colors of [colors_to_population].
+    pub fn from_populations(colors_to_population: &HashMap<Argb, i64>) -> SwatchPalette {
+        let mut swatches: Vec<Swatch> = colors_to_population
+            .iter()
+            .map(|(argb, population)| Swatch::new(*argb, *population))
+            .collect();
+        // Most populous first, so that ties don't depend on the map.
+        swatches.sort_by(|a, b| b.population.cmp(&a.population).then(a.argb.cmp(&b.argb)));
+        let hcts: Vec<Hct> = swatches.iter().map(|swatch| swatch.hct()).collect();
+        let max_population = swatches.first().map_or(0, |swatch| swatch.population);
+
+        let mut picked: HashMap<SwatchKind, Swatch> = HashMap::new();
+        let mut used = vec![false; swatches.len()];
+        for kind in SwatchKind::ALL {
+            let target = kind.target();
+            let mut best: Option<(usize, f64)> = None;
+            for (index, swatch) in swatches.iter().enumerate() {
+                if used[index] || !target.accepts(&hcts[index]) {
+                    continue;
+                }
+                let score = target.score(&hcts[index], swatch.population, max_population);
+                if best.is_none_or(|(_, best_score)| score > best_score) {
+                    best = Some((index, score));
+                }
+            }
+            if let Some((index, _)) = best {
+                used[index] = true;
+                picked.insert(kind, swatches[index]);
+            }
+        }
+        return SwatchPalette { swatches, picked };
+    }
+
+    /// Every swatch, most populous first.
+    pub fn swatches(&self) -> &[Swatch] {
+        return &self.swatches;
+    }
+
+    /// The most populous swatch.
+    pub fn dominant(&self) -> Option<&Swatch> {
+        return self.swatches.first();
+    }
+
+    /// The swatch picked for [kind].
+    pub fn get(&self, kind: SwatchKind) -> Option<&Swatch> {
+        return self.picked.get(&kind);
+    }
+
+    pub fn vibrant(&self) -> Option<&Swatch> {
+        return self.get(SwatchKind::Vibrant);
+    }
+
+    pub fn light_vibrant(&self) -> Option<&Swatch> {
+        return self.get(SwatchKind::LightVibrant);
+    }
+
+    pub fn dark_vibrant(&self) -> Option<&Swatch> {
+        return self.get(SwatchKind::DarkVibrant);
+    }
+
+    pub fn muted(&self) -> Option<&Swatch> {
+        return self.get(SwatchKind::Muted);
+    }
+
+    pub fn light_muted(&self) -> Option<&Swatch> {
+        return self.get(SwatchKind::LightMuted);
+    }
+
+    pub fn dark_muted(&self) -> Option<&Swatch> {
+        return self.get(SwatchKind::DarkMuted);
+    }
+}
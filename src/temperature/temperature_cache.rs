@@ -1,44 +1,48 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::OnceLock};
 
-use crate::{
-    hct::hct::Hct,
-    utils::{color_utils::ColorUtils, math_utils::MathUtils},
-};
+use crate::{hct::hct::Hct, utils::math_utils::MathUtils};
 
 /// Design utilities using color temperature theory.
 ///
 /// Analogous colors, complementary color, and cache to efficiently, lazily,
 /// generate data for calculations when needed.
+///
+/// The lazily generated data is computed at most once, through `&self`, so a
+/// cache can be shared across threads.
 pub struct TemperatureCache {
     pub input: Hct,
-    _hcts_by_temp: Vec<Hct>,
-    _hcts_by_hue: Vec<Hct>,
-    _temps_by_hct: HashMap<Hct, f64>,
-    _input_relative_temperature: f64,
-    _complement: Option<Hct>,
+    _hcts_by_temp: OnceLock<Vec<Hct>>,
+    _hcts_by_hue: OnceLock<Vec<Hct>>,
+    _temps_by_hct: OnceLock<HashMap<Hct, f64>>,
+    _input_relative_temperature: OnceLock<f64>,
+    _complement: OnceLock<Hct>,
 }
 impl TemperatureCache {
     pub fn new(input: Hct) -> TemperatureCache {
         TemperatureCache {
             input,
-            _hcts_by_temp: Vec::new(),
-            _hcts_by_hue: Vec::new(),
-            _temps_by_hct: HashMap::new(),
-            _input_relative_temperature: -1.0,
-            _complement: None,
+            _hcts_by_temp: OnceLock::new(),
+            _hcts_by_hue: OnceLock::new(),
+            _temps_by_hct: OnceLock::new(),
+            _input_relative_temperature: OnceLock::new(),
+            _complement: OnceLock::new(),
         }
     }
-    pub fn get_warmest(&mut self) -> Hct {
-        self.get_hcts_by_temp()
+    /// The warmest color with the same chroma and tone as the input.
+    pub fn get_warmest(&self) -> Hct {
+        // [hctsByTemp] always contains the input, so it's never empty.
+        self._hcts_by_temp_ref()
             .last()
-            .expect("Cannot get Warmest Hct")
-            .clone()
+            .cloned()
+            .unwrap_or_else(|| self.input.clone())
     }
-    pub fn get_coldest(&mut self) -> Hct {
-        self.get_hcts_by_temp()
+
+    /// The coldest color with the same chroma and tone as the input.
+    pub fn get_coldest(&self) -> Hct {
+        self._hcts_by_temp_ref()
             .first()
-            .expect("Cannot get Coldest hct")
-            .clone()
+            .cloned()
+            .unwrap_or_else(|| self.input.clone())
     }
 
     /// A set of colors with differing hues, equidistant in temperature.
@@ -52,12 +56,14 @@ impl TemperatureCache {
     ///
     /// [count] The number of colors to return, includes the input color.
     /// [divisions] The number of divisions on the color wheel.
-    pub fn analogous(&mut self, count: Option<i64>, divisions: Option<i64>) -> Vec<Hct> {
+    pub fn analogous(&self, count: Option<i64>, divisions: Option<i64>) -> Vec<Hct> {
         let count = count.unwrap_or(5);
         let divisions = divisions.unwrap_or(12);
 
-        let start_hue = self.input.get_hue().round() as i64;
-        let start_hct = &self.get_hcts_by_hue()[start_hue as usize];
+        // Hues just below 360 round up to 360, which wraps around to 0.
+        let start_hue = MathUtils::sanitize_degrees_int(self.input.get_hue().round() as i64);
+        let hcts_by_hue = self._hcts_by_hue_ref();
+        let start_hct = &hcts_by_hue[start_hue as usize];
         let mut last_temp = self.relative_temperature(start_hct);
         let mut all_colors: Vec<Hct> = Vec::new();
         all_colors.push(start_hct.clone());
@@ -65,7 +71,7 @@ impl TemperatureCache {
         let mut absolute_total_temp_delta = 0.0;
         for i in 0..360 {
             let hue = MathUtils::sanitize_degrees_int(start_hue + i);
-            let hct = &self.get_hcts_by_hue()[hue as usize];
+            let hct = &hcts_by_hue[hue as usize];
             let temp = self.relative_temperature(hct);
             let temp_delta = (temp - last_temp).abs();
             last_temp = temp;
@@ -77,7 +83,7 @@ impl TemperatureCache {
         last_temp = self.relative_temperature(start_hct);
         while (all_colors.len() as i64) < divisions {
             let hue = MathUtils::sanitize_degrees_int(start_hue + hue_addend);
-            let hct = &self.get_hcts_by_hue()[hue as usize];
+            let hct = &hcts_by_hue[hue as usize];
             let temp = self.relative_temperature(hct);
             let temp_delta = (temp - last_temp).abs();
             total_temp_delta += temp_delta;
@@ -147,15 +153,21 @@ impl TemperatureCache {
     /// In art, this is usually described as being across the color wheel.
     /// History of this shows intent as a color that is just as cool-warm as the
     /// input color is warm-cool.
-    pub fn get_complement(&mut self) -> Hct {
-        if let Some(_compliment) = &self._complement {
-            return _compliment.clone();
-        }
+    pub fn get_complement(&self) -> Hct {
+        return self
+            ._complement
+            .get_or_init(|| self._compute_complement())
+            .clone();
+    }
+
+    fn _compute_complement(&self) -> Hct {
+        let temps_by_hct = self._temps_by_hct_ref();
+        let hcts_by_hue = self._hcts_by_hue_ref();
         let coldest_hue = self.get_coldest().get_hue();
-        let coldest_temp = self.get_temps_by_hct()[&self.get_coldest()];
+        let coldest_temp = temps_by_hct[&self.get_coldest()];
 
         let warmest_hue = self.get_warmest().get_hue();
-        let warmest_temp = self.get_temps_by_hct()[&self.get_warmest()];
+        let warmest_temp = temps_by_hct[&self.get_warmest()];
         let range = warmest_temp - coldest_temp;
         let start_hue_is_coldest_to_warmest =
             Self::is_between(self.input.get_hue(), coldest_hue, warmest_hue);
@@ -175,7 +187,9 @@ impl TemperatureCache {
         };
         let direction_of_rotation = 1.0;
         let mut smallest_error = 1000.0;
-        let mut answer = self._hcts_by_hue[self.input.get_hue().round() as usize].clone();
+        let mut answer = hcts_by_hue
+            [MathUtils::sanitize_degrees_int(self.input.get_hue().round() as i64) as usize]
+            .clone();
 
         let complement_relative_temp = 1.0 - self.get_input_relative_temperature();
         // Find the color in the other section, closest to the inverse percentile
@@ -187,25 +201,24 @@ impl TemperatureCache {
             if !Self::is_between(hue, start_hue, end_hue) {
                 continue;
             }
-            let possible_answer = &self._hcts_by_hue[hue.round() as usize];
-            let relative_temp = (self._temps_by_hct[&possible_answer] - coldest_temp) / range;
+            let possible_answer =
+                &hcts_by_hue[MathUtils::sanitize_degrees_int(hue.round() as i64) as usize];
+            let relative_temp = (temps_by_hct[possible_answer] - coldest_temp) / range;
             let error = (complement_relative_temp - relative_temp).abs();
             if error < smallest_error {
                 smallest_error = error;
                 answer = possible_answer.clone();
             }
         }
-        self._complement = Some(answer.clone());
-        return answer.clone();
+        return answer;
     }
 
     /// Temperature relative to all colors with the same chroma and tone.
     /// Value on a scale from 0 to 1.
-    pub fn relative_temperature(&mut self, hct: &Hct) -> f64 {
-        let range = self.get_temps_by_hct()[&self.get_warmest().clone()]
-            - self.get_temps_by_hct()[&self.get_coldest()];
-        let difference_from_coldest =
-            self.get_temps_by_hct()[&hct] - self.get_temps_by_hct()[&self.get_coldest()];
+    pub fn relative_temperature(&self, hct: &Hct) -> f64 {
+        let temps_by_hct = self._temps_by_hct_ref();
+        let range = temps_by_hct[&self.get_warmest()] - temps_by_hct[&self.get_coldest()];
+        let difference_from_coldest = temps_by_hct[hct] - temps_by_hct[&self.get_coldest()];
         // Handle when there's no difference in temperature between warmest and
         // coldest: for example, at T100, only one color is available, white.
         if range == 0.0 {
@@ -215,83 +228,77 @@ impl TemperatureCache {
     }
 
     /// Relative temperature of the input color. See [relativeTemperature].
-    pub fn get_input_relative_temperature(&mut self) -> f64 {
-        if self._input_relative_temperature >= 0.0 {
-            return self._input_relative_temperature;
-        }
+    pub fn get_input_relative_temperature(&self) -> f64 {
+        return *self._input_relative_temperature.get_or_init(|| {
+            let temps_by_hct = self._temps_by_hct_ref();
+            let coldest_temp = temps_by_hct[&self.get_coldest()];
 
-        let coldest_temp = self.get_temps_by_hct()[&self.get_coldest()];
-
-        let range = self.get_temps_by_hct()[&self.get_warmest()] - coldest_temp;
-        let difference_from_coldest = self.get_temps_by_hct()[&self.input] - coldest_temp;
-        let input_relative_temp = {
+            let range = temps_by_hct[&self.get_warmest()] - coldest_temp;
+            let difference_from_coldest = temps_by_hct[&self.input] - coldest_temp;
             if range == 0.0 {
                 0.5
             } else {
                 difference_from_coldest / range
             }
-        };
-
-        self._input_relative_temperature = input_relative_temp;
-        return self._input_relative_temperature;
+        });
     }
 
     /// HCTs for all hues, with the same chroma/tone as the input.
     /// Sorted from coldest first to warmest last.
-    pub fn get_hcts_by_temp(&mut self) -> Vec<Hct> {
-        if !self._hcts_by_temp.is_empty() {
-            let mut hcts_by_temp: Vec<Hct> = Vec::new();
-            for hct in &self._hcts_by_temp {
-                hcts_by_temp.push(hct.clone());
-            }
-            return hcts_by_temp;
-        }
+    pub fn get_hcts_by_temp(&self) -> Vec<Hct> {
+        return self._hcts_by_temp_ref().clone();
+    }
 
-        let mut hcts: Vec<Hct> = self.get_hcts_by_hue();
-        hcts.push(self.input.clone());
-        let temperatures_by_hct = self.get_temps_by_hct();
-        hcts.sort_by(|a, b| {
-            let temperature_a = temperatures_by_hct[a];
-            let temperature_b = temperatures_by_hct[b];
-            return temperature_a
-                .partial_cmp(&temperature_b)
-                .unwrap_or(std::cmp::Ordering::Equal);
-        });
-        self._hcts_by_temp = hcts;
-        return self._hcts_by_temp.clone();
+    fn _hcts_by_temp_ref(&self) -> &Vec<Hct> {
+        self._hcts_by_temp.get_or_init(|| {
+            let mut hcts: Vec<Hct> = self._hcts_by_hue_ref().clone();
+            hcts.push(self.input.clone());
+            let temperatures_by_hct = self._temps_by_hct_ref();
+            hcts.sort_by(|a, b| {
+                let temperature_a = temperatures_by_hct[a];
+                let temperature_b = temperatures_by_hct[b];
+                return temperature_a
+                    .partial_cmp(&temperature_b)
+                    .unwrap_or(std::cmp::Ordering::Equal);
+            });
+            hcts
+        })
     }
 
     /// A Map with keys of HCTs in [hctsByTemp], values of raw temperature.
-    pub fn get_temps_by_hct(&mut self) -> HashMap<Hct, f64> {
-        if !self._temps_by_hct.is_empty() {
-            return self._temps_by_hct.clone();
-        }
-        let mut all_hcts: Vec<Hct> = self.get_hcts_by_hue();
-        all_hcts.push(self.input.clone());
-        let mut temperatures_by_hct: HashMap<Hct, f64> = HashMap::new();
-        for e in all_hcts {
-            temperatures_by_hct.insert(e.clone(), Self::raw_temperature(&e));
-        }
-        //{for (var e in allHcts) e: rawTemperature(e)};
-        self._temps_by_hct = temperatures_by_hct;
-        return self._temps_by_hct.clone();
+    pub fn get_temps_by_hct(&self) -> HashMap<Hct, f64> {
+        return self._temps_by_hct_ref().clone();
+    }
+
+    fn _temps_by_hct_ref(&self) -> &HashMap<Hct, f64> {
+        self._temps_by_hct.get_or_init(|| {
+            let mut all_hcts: Vec<Hct> = self._hcts_by_hue_ref().clone();
+            all_hcts.push(self.input.clone());
+            let mut temperatures_by_hct: HashMap<Hct, f64> = HashMap::new();
+            for e in all_hcts {
+                temperatures_by_hct.insert(e.clone(), Self::raw_temperature(&e));
+            }
+            //{for (var e in allHcts) e: rawTemperature(e)};
+            temperatures_by_hct
+        })
     }
 
     /// HCTs for all hues, with the same chroma/tone as the input.
     /// Sorted ascending, hue 0 to 360.
-    pub fn get_hcts_by_hue(&mut self) -> Vec<Hct> {
-        if !self._hcts_by_hue.is_empty() {
-            return self._hcts_by_hue.clone();
-        }
-        let mut hcts: Vec<Hct> = Vec::new();
-        for hue in 0..360 {
-            let hue = hue as f64;
-            let color_at_hue = Hct::from(hue, self.input.get_chroma(), self.input.get_tone());
-            hcts.push(color_at_hue);
-        }
-        self._hcts_by_hue = hcts;
+    pub fn get_hcts_by_hue(&self) -> Vec<Hct> {
+        return self._hcts_by_hue_ref().clone();
+    }
 
-        return self._hcts_by_hue.clone();
+    fn _hcts_by_hue_ref(&self) -> &Vec<Hct> {
+        self._hcts_by_hue.get_or_init(|| {
+            let mut hcts: Vec<Hct> = Vec::new();
+            for hue in 0..360 {
+                let hue = hue as f64;
+                let color_at_hue = Hct::from(hue, self.input.get_chroma(), self.input.get_tone());
+                hcts.push(color_at_hue);
+            }
+            hcts
+        })
     }
 
     /// Determines if an angle is between two other angles, rotating clockwise.
@@ -320,7 +327,7 @@ impl TemperatureCache {
     /// - Upper bound: -0.52 + (chroma ^ 1.07 / 20). L*a*b* chroma is infinite.
     ///   Assuming max of 130 chroma, 8.61.
     pub fn raw_temperature(color: &Hct) -> f64 {
-        let lab = ColorUtils::lab_from_argb(color.to_int());
+        let lab = color.to_argb().to_lab();
         let hue = MathUtils::sanitize_degrees_double(
             (lab[2]).atan2(lab[1]) * 180.0 / std::f64::consts::PI,
         );
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use crate::{
+    blend::blend::Blend,
+    dynamiccolor::{
+        dynamic_scheme::DynamicScheme, material_dynamic_colors::MaterialDynamicColors,
+        variant::Variant,
+    },
+    error::error::Error,
+    hct::hct::Hct,
+    quantize::quantizer_wsmeans::QuantizerWsmeans,
+    score::score::Score,
+    utils::{argb::Argb, string_utils::StringUtils},
+};
+
+#[test]
+fn components() {
+    let argb = Argb(0x80123456);
+    assert_eq!(argb.alpha(), 0x80);
+    assert_eq!(argb.red(), 0x12);
+    assert_eq!(argb.green(), 0x34);
+    assert_eq!(argb.blue(), 0x56);
+    assert!(!argb.is_opaque());
+    assert_eq!(Argb::new(0x80, 0x12, 0x34, 0x56), argb);
+    assert_eq!(Argb::from_rgb(0x12, 0x34, 0x56), Argb(0xff123456));
+    assert!(Argb::from_rgb(0x12, 0x34, 0x56).is_opaque());
+}
+
+#[test]
+fn conversions() {
+    let argb = Argb(0xff123456);
+    assert_eq!(u32::from(argb), 0xff123456);
+    assert_eq!(i64::from(argb), 0xff123456);
+    assert_eq!(Argb::from(0xff123456), argb);
+    assert_eq!(<[u8; 4]>::from(argb), [0xff, 0x12, 0x34, 0x56]);
+    assert_eq!(Argb::from([0xff, 0x12, 0x34, 0x56]), argb);
+}
+
+#[test]
+fn display() {
+    assert_eq!(Argb(0xff123abc).to_string(), "#123ABC");
+    assert_eq!(Argb(0x80123abc).to_string(), "#80123ABC");
+}
+
+#[test]
+fn from_str() {
+    assert_eq!("#123abc".parse::<Argb>(), Ok(Argb(0xff123abc)));
+    assert_eq!("123ABC".parse::<Argb>(), Ok(Argb(0xff123abc)));
+    assert_eq!("#80123abc".parse::<Argb>(), Ok(Argb(0x80123abc)));
+    assert!("".parse::<Argb>().is_err());
+    assert!("#12345".parse::<Argb>().is_err());
+    assert!("#+12345".parse::<Argb>().is_err());
+    assert!("#12345g".parse::<Argb>().is_err());
+}
+
+#[test]
+fn display_from_str_round_trip() {
+    for argb in [
+        Argb(0xff000000),
+        Argb(0xffffffff),
+        Argb(0x00000000),
+        Argb(0x7f4285f4),
+    ] {
+        assert_eq!(argb.to_string().parse::<Argb>(), Ok(argb));
+    }
+}
+
+#[test]
+fn from_str_error() {
+    assert_eq!(
+        "#12345".parse::<Argb>(),
+        Err(Error::InvalidHex("#12345".to_string()))
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn argb_from_hex_matches_from_str() {
+    for hex in [
+        "#123abc",
+        "80123ABC",
+        "fff",
+        "#12345",
+        "123456789",
+        "#12345g",
+        "",
+    ] {
+        assert_eq!(
+            StringUtils::argb_from_hex(hex.to_string()),
+            hex.parse::<Argb>().ok().map(i64::from),
+            "{}",
+            hex
+        );
+    }
+    assert_eq!(
+        StringUtils::argb_from_hex("#123abc".to_string()),
+        Some(0xff123abc)
+    );
+    assert_eq!(StringUtils::argb_from_hex("fff".to_string()), None);
+}
+
+#[test]
+fn try_from_i64() {
+    assert_eq!(Argb::try_from(0xff123456i64), Ok(Argb(0xff123456)));
+    assert_eq!(Argb::try_from(-1i64), Err(Error::InvalidArgb(-1)));
+    assert_eq!(
+        Argb::try_from(0x1_0000_0000i64),
+        Err(Error::InvalidArgb(0x1_0000_0000))
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn i64_shims_match_argb_functions() {
+    let (red, blue) = (Argb(0xffff0000), Argb(0xff0000ff));
+    assert_eq!(
+        Blend::harmonize(red.into(), blue.into()),
+        i64::from(Blend::harmonize_argb(red, blue))
+    );
+    assert_eq!(
+        Blend::hct_hue(red.into(), blue.into(), 0.5),
+        i64::from(Blend::hct_hue_argb(red, blue, 0.5))
+    );
+    assert_eq!(
+        Blend::cam16_ucs(red.into(), blue.into(), 0.5),
+        i64::from(Blend::cam16_ucs_argb(red, blue, 0.5))
+    );
+
+    let populations = HashMap::from([(red, 3), (blue, 1)]);
+    let ranked: Vec<i64> = Score::score_argb(&populations, None, None, None)
+        .into_iter()
+        .map(i64::from)
+        .collect();
+    let populations_i64 = HashMap::from([(red.into(), 3), (blue.into(), 1)]);
+    assert_eq!(Score::score(&populations_i64, None, None, None), ranked);
+
+    let pixels = vec![red, red, blue];
+    let pixels_i64: Vec<i64> = pixels.iter().map(|argb| (*argb).into()).collect();
+    assert_eq!(
+        QuantizerWsmeans::quantize(&pixels_i64, 2, None, None, None, None).color_to_count,
+        QuantizerWsmeans::quantize_argb(&pixels, 2, None, None, None, None).color_to_count
+    );
+
+    let scheme = DynamicScheme::from_variant(Variant::TonalSpot, Hct::from_argb(blue), false, 0.0);
+    let primary = MaterialDynamicColors::primary();
+    assert_eq!(
+        primary.get_argb(&scheme),
+        i64::from(primary.get_color(&scheme))
+    );
+    assert_eq!(scheme.get_argb(&primary), i64::from(scheme.primary()));
+}
@@ -1,67 +1,67 @@
-use crate::blend::blend::Blend;
+use crate::{blend::blend::Blend, utils::argb::Argb};
 
-const RED: i64 = 0xffff0000;
-const BLUE: i64 = 0xff0000ff;
-const GREEN: i64 = 0xff00ff00;
-const YELLOW: i64 = 0xffffff00;
+const RED: Argb = Argb(0xffff0000);
+const BLUE: Argb = Argb(0xff0000ff);
+const GREEN: Argb = Argb(0xff00ff00);
+const YELLOW: Argb = Argb(0xffffff00);
 
 #[test]
 fn red_to_blue() {
-    let answer = Blend::harmonize(RED, BLUE);
-    assert_eq!(answer, 0xffFB0057)
+    let answer = Blend::harmonize_argb(RED, BLUE);
+    assert_eq!(answer, Argb(0xffFB0057))
 }
 #[test]
 fn red_to_green() {
-    let answer = Blend::harmonize(RED, GREEN);
-    assert_eq!(answer, (0xffD85600));
+    let answer = Blend::harmonize_argb(RED, GREEN);
+    assert_eq!(answer, (Argb(0xffD85600)));
 }
 #[test]
 fn red_to_yellow() {
-    let answer = Blend::harmonize(RED, YELLOW);
-    assert_eq!(answer, (0xffD85600));
+    let answer = Blend::harmonize_argb(RED, YELLOW);
+    assert_eq!(answer, (Argb(0xffD85600)));
 }
 #[test]
 fn blue_to_green() {
-    let answer = Blend::harmonize(BLUE, GREEN);
-    assert_eq!(answer, (0xff0047A3));
+    let answer = Blend::harmonize_argb(BLUE, GREEN);
+    assert_eq!(answer, (Argb(0xff0047A3)));
 }
 #[test]
 fn blue_to_red() {
-    let answer = Blend::harmonize(BLUE, RED);
-    assert_eq!(answer, (0xff5700DC));
+    let answer = Blend::harmonize_argb(BLUE, RED);
+    assert_eq!(answer, (Argb(0xff5700DC)));
 }
 #[test]
 fn blue_to_yellow() {
-    let answer = Blend::harmonize(BLUE, YELLOW);
-    assert_eq!(answer, (0xff0047A3));
+    let answer = Blend::harmonize_argb(BLUE, YELLOW);
+    assert_eq!(answer, (Argb(0xff0047A3)));
 }
 #[test]
 fn green_to_blue() {
-    let answer = Blend::harmonize(GREEN, BLUE);
-    assert_eq!(answer, (0xff00FC94));
+    let answer = Blend::harmonize_argb(GREEN, BLUE);
+    assert_eq!(answer, (Argb(0xff00FC94)));
 }
 #[test]
 fn green_to_red() {
-    let answer = Blend::harmonize(GREEN, RED);
-    assert_eq!(answer, (0xffB1F000));
+    let answer = Blend::harmonize_argb(GREEN, RED);
+    assert_eq!(answer, (Argb(0xffB1F000)));
 }
 #[test]
 fn green_to_yellow() {
-    let answer = Blend::harmonize(GREEN, YELLOW);
-    assert_eq!(answer, (0xffB1F000));
+    let answer = Blend::harmonize_argb(GREEN, YELLOW);
+    assert_eq!(answer, (Argb(0xffB1F000)));
 }
 #[test]
 fn yellow_to_blue() {
-    let answer = Blend::harmonize(YELLOW, BLUE);
-    assert_eq!(answer, (0xffEBFFBA));
+    let answer = Blend::harmonize_argb(YELLOW, BLUE);
+    assert_eq!(answer, (Argb(0xffEBFFBA)));
 }
 #[test]
 fn yellow_to_green() {
-    let answer = Blend::harmonize(YELLOW, GREEN);
-    assert_eq!(answer, (0xffEBFFBA));
+    let answer = Blend::harmonize_argb(YELLOW, GREEN);
+    assert_eq!(answer, (Argb(0xffEBFFBA)));
 }
 #[test]
 fn yellow_to_red() {
-    let answer = Blend::harmonize(YELLOW, RED);
-    assert_eq!(answer, (0xffFFF6E3));
+    let answer = Blend::harmonize_argb(YELLOW, RED);
+    assert_eq!(answer, (Argb(0xffFFF6E3)));
 }
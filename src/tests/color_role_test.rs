@@ -0,0 +1,56 @@
+use std::collections::HashSet;
+
+use crate::{
+    dynamiccolor::{color_role::ColorRole, material_dynamic_colors::MaterialDynamicColors},
+    error::error::Error,
+    hct::hct::Hct,
+    scheme::scheme_tonal_spot::SchemeTonalSpot,
+    utils::argb::Argb,
+};
+
+#[test]
+fn names_are_unique() {
+    let names: HashSet<&str> = ColorRole::ALL.iter().map(|role| role.name()).collect();
+    assert_eq!(names.len(), ColorRole::ALL.len());
+}
+
+#[test]
+fn names_match_dynamic_colors() {
+    for role in ColorRole::ALL {
+        assert_eq!(MaterialDynamicColors::for_role(role).name, role.name());
+    }
+}
+
+#[test]
+fn from_str_round_trips() {
+    for role in ColorRole::ALL {
+        assert_eq!(role.name().parse::<ColorRole>(), Ok(role));
+        assert_eq!(role.to_string(), role.name());
+    }
+}
+
+#[test]
+fn from_str_unknown_errors() {
+    assert_eq!(
+        "primary_tint".parse::<ColorRole>(),
+        Err(Error::UnknownColorRole("primary_tint".to_string()))
+    );
+}
+
+#[test]
+fn scheme_get_matches_getters() {
+    let scheme = SchemeTonalSpot::new(Hct::from_argb(Argb(0xff0000ff)), false, 0.0).dynamic_scheme;
+    assert_eq!(scheme.get(ColorRole::Primary), scheme.primary());
+    assert_eq!(
+        scheme.get(ColorRole::OnPrimaryContainer),
+        scheme.on_primary_container()
+    );
+    assert_eq!(
+        scheme.get(ColorRole::SurfaceContainerHighest),
+        scheme.surface_container_highest()
+    );
+    assert_eq!(
+        scheme.get(ColorRole::OnTertiaryFixedVariant),
+        scheme.on_tertiary_fixed_variant()
+    );
+}
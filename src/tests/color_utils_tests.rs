@@ -1,5 +1,5 @@
 #[cfg(test)]
-use crate::utils::color_utils::ColorUtils;
+use crate::utils::{argb::Argb, color_utils::ColorUtils};
 
 fn _range(start: f64, stop: f64, case_count: i64) -> Vec<f64> {
     let step_size: f64 = (stop - start) / (case_count - 1) as f64;
@@ -132,24 +132,12 @@ fn rgb_to_xyz_to_rgb() {
     for r in _get_rgb_range() {
         for g in _get_full_rgb_range() {
             for b in _get_rgb_range() {
-                let argb = ColorUtils::argb_from_rgb(r, g, b);
-                let xyz = ColorUtils::xyz_from_argb(argb);
-                let converted = ColorUtils::argb_from_xyz(xyz[0], xyz[1], xyz[2]);
-                assert_approx_eq::assert_approx_eq!(
-                    ColorUtils::red_from_argb(converted) as f64,
-                    r as f64,
-                    1.5
-                );
-                assert_approx_eq::assert_approx_eq!(
-                    ColorUtils::green_from_argb(converted) as f64,
-                    g as f64,
-                    1.5
-                );
-                assert_approx_eq::assert_approx_eq!(
-                    ColorUtils::blue_from_argb(converted) as f64,
-                    b as f64,
-                    1.5
-                );
+                let argb = Argb::from_rgb(r as u8, g as u8, b as u8);
+                let xyz = argb.to_xyz();
+                let converted = Argb::from_xyz(xyz[0], xyz[1], xyz[2]);
+                assert_approx_eq::assert_approx_eq!(converted.red() as f64, r as f64, 1.5);
+                assert_approx_eq::assert_approx_eq!(converted.green() as f64, g as f64, 1.5);
+                assert_approx_eq::assert_approx_eq!(converted.blue() as f64, b as f64, 1.5);
             }
         }
     }
@@ -160,24 +148,12 @@ fn rgb_to_lab_to_rgb() {
     for r in _get_rgb_range() {
         for g in _get_rgb_range() {
             for b in _get_rgb_range() {
-                let argb = ColorUtils::argb_from_rgb(r, g, b);
-                let lab = ColorUtils::lab_from_argb(argb);
-                let converted = ColorUtils::argb_from_lab(lab[0], lab[1], lab[2]);
-                assert_approx_eq::assert_approx_eq!(
-                    ColorUtils::red_from_argb(converted) as f64,
-                    r as f64,
-                    1.5
-                );
-                assert_approx_eq::assert_approx_eq!(
-                    ColorUtils::green_from_argb(converted) as f64,
-                    g as f64,
-                    1.5
-                );
-                assert_approx_eq::assert_approx_eq!(
-                    ColorUtils::blue_from_argb(converted) as f64,
-                    b as f64,
-                    1.5
-                );
+                let argb = Argb::from_rgb(r as u8, g as u8, b as u8);
+                let lab = argb.to_lab();
+                let converted = Argb::from_lab(lab[0], lab[1], lab[2]);
+                assert_approx_eq::assert_approx_eq!(converted.red() as f64, r as f64, 1.5);
+                assert_approx_eq::assert_approx_eq!(converted.green() as f64, g as f64, 1.5);
+                assert_approx_eq::assert_approx_eq!(converted.blue() as f64, b as f64, 1.5);
             }
         }
     }
@@ -186,9 +162,9 @@ fn rgb_to_lab_to_rgb() {
 #[test]
 fn rgb_to_lstar_to_rgb() {
     for component in _get_full_rgb_range() {
-        let argb = ColorUtils::argb_from_rgb(component, component, component);
-        let lstar = ColorUtils::lstar_from_argb(argb);
-        let converted = ColorUtils::argb_from_lstar(lstar);
+        let argb = Argb::from_rgb(component as u8, component as u8, component as u8);
+        let lstar = argb.lstar();
+        let converted = Argb::from_lstar(lstar);
         assert_eq!(converted, argb);
     }
 }
@@ -198,10 +174,10 @@ fn rgb_to_lstar_to_y_commutes() {
     for r in _get_rgb_range() {
         for g in _get_rgb_range() {
             for b in _get_rgb_range() {
-                let argb = ColorUtils::argb_from_rgb(r, g, b);
-                let lstar = ColorUtils::lstar_from_argb(argb);
+                let argb = Argb::from_rgb(r as u8, g as u8, b as u8);
+                let lstar = argb.lstar();
                 let y = ColorUtils::y_from_lstar(lstar);
-                let y2 = ColorUtils::xyz_from_argb(argb)[1];
+                let y2 = argb.to_xyz()[1];
                 assert_approx_eq::assert_approx_eq!(y, y2, 1e-5);
             }
         }
@@ -211,8 +187,8 @@ fn rgb_to_lstar_to_y_commutes() {
 #[test]
 fn lstar_to_rgb_to_y_commutes() {
     for lstar in _range(0.0, 100.0, 1001) {
-        let argb = ColorUtils::argb_from_lstar(lstar);
-        let y = ColorUtils::xyz_from_argb(argb)[1];
+        let argb = Argb::from_lstar(lstar);
+        let y = argb.to_xyz()[1];
         let y2 = ColorUtils::y_from_lstar(lstar);
         assert_approx_eq::assert_approx_eq!(y, y2, 1.0);
     }
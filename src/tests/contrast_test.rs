@@ -1,5 +1,5 @@
 
-use crate::contrast::contrast::Contrast;
+use crate::{contrast::contrast::Contrast, error::error::Error};
 
 #[test]
 fn ratio_of_tones_out_of_bounds_input() {
@@ -8,17 +8,29 @@ fn ratio_of_tones_out_of_bounds_input() {
 
 #[test]
 fn lighter_impossible_ratio_errors() {
-    assert_approx_eq::assert_approx_eq!(-1.0, Contrast::lighter(90.0, 10.0), 0.001);
+    assert_eq!(
+        Contrast::try_lighter(90.0, 10.0),
+        Err(Error::UnreachableContrast {
+            tone: 90.0,
+            ratio: 10.0
+        })
+    );
 }
 
 #[test]
 fn lighter_out_of_bounds_input_above_errors() {
-    assert_approx_eq::assert_approx_eq!(-1.0, Contrast::lighter(110.0, 2.0), 0.001);
+    assert_eq!(
+        Contrast::try_lighter(110.0, 2.0),
+        Err(Error::ToneOutOfRange(110.0))
+    );
 }
 
 #[test]
 fn lighter_out_of_bounds_input_below_errors() {
-    assert_approx_eq::assert_approx_eq!(-1.0, Contrast::lighter(-10.0, 2.0), 0.001);
+    assert_eq!(
+        Contrast::try_lighter(-10.0, 2.0),
+        Err(Error::ToneOutOfRange(-10.0))
+    );
 }
 
 #[test]
@@ -28,17 +40,29 @@ fn lighter_unsafe_returns_max_tone() {
 
 #[test]
 fn darker_impossible_ratio_errors() {
-    assert_approx_eq::assert_approx_eq!(-1.0, Contrast::darker(10.0, 20.0), 0.001);
+    assert_eq!(
+        Contrast::try_darker(10.0, 20.0),
+        Err(Error::UnreachableContrast {
+            tone: 10.0,
+            ratio: 20.0
+        })
+    );
 }
 
 #[test]
 fn darker_out_of_bounds_input_above_errors() {
-    assert_approx_eq::assert_approx_eq!(-1.0, Contrast::darker(110.0, 2.0), 0.001);
+    assert_eq!(
+        Contrast::try_darker(110.0, 2.0),
+        Err(Error::ToneOutOfRange(110.0))
+    );
 }
 
 #[test]
 fn darker_out_of_bounds_input_below_errors() {
-    assert_approx_eq::assert_approx_eq!(-1.0, Contrast::darker(-10.0, 2.0), 0.001);
+    assert_eq!(
+        Contrast::try_darker(-10.0, 2.0),
+        Err(Error::ToneOutOfRange(-10.0))
+    );
 }
 
 #[test]
@@ -12,14 +12,15 @@ pub mod dynamic_color_test {
             scheme_content::SchemeContent, scheme_fidelity::SchemeFidelity,
             scheme_monochrome::SchemeMonochrome, scheme_tonal_spot::SchemeTonalSpot,
         },
+        utils::argb::Argb,
     };
 
     fn _seed_colors() -> [Hct; 4] {
         [
-            Hct::from_int(0xFFFF0000),
-            Hct::from_int(0xFFFFFF00),
-            Hct::from_int(0xFF00FF00),
-            Hct::from_int(0xFF0000FF),
+            Hct::from_argb(Argb(0xFFFF0000)),
+            Hct::from_argb(Argb(0xFFFFFF00)),
+            Hct::from_argb(Argb(0xFF00FF00)),
+            Hct::from_argb(Argb(0xFF0000FF)),
         ]
     }
 
@@ -200,39 +201,40 @@ pub mod dynamic_color_test {
     #[test]
     fn values_are_correct() {
         assert_eq!(
-            MaterialDynamicColors::on_primary_container().get_argb(
-                &SchemeFidelity::new(Hct::from_int(0xFFFF0000), false, 0.5,).dynamic_scheme
+            MaterialDynamicColors::on_primary_container().get_color(
+                &SchemeFidelity::new(Hct::from_argb(Argb(0xFFFF0000)), false, 0.5,).dynamic_scheme
             ),
-            (0xFFFFFFFF),
+            (Argb(0xFFFFFFFF)),
         );
         assert_eq!(
-            MaterialDynamicColors::on_secondary_container().get_argb(
-                &SchemeContent::new(Hct::from_int(0xFF0000FF), false, 0.5,).dynamic_scheme
+            MaterialDynamicColors::on_secondary_container().get_color(
+                &SchemeContent::new(Hct::from_argb(Argb(0xFF0000FF)), false, 0.5,).dynamic_scheme
             ),
-            (0xFFFFFFFF),
+            (Argb(0xFFFFFFFF)),
         );
         assert_eq!(
-            MaterialDynamicColors::on_tertiary_container().get_argb(
-                &SchemeContent::new(Hct::from_int(0xFFFFFF00), true, -0.5,).dynamic_scheme
+            MaterialDynamicColors::on_tertiary_container().get_color(
+                &SchemeContent::new(Hct::from_argb(Argb(0xFFFFFF00)), true, -0.5,).dynamic_scheme
             ),
-            (0xffbac040),
+            (Argb(0xffbac040)),
         );
         assert_eq!(
-            MaterialDynamicColors::inverse_surface().get_argb(
-                &SchemeContent::new(Hct::from_int(0xFF0000FF), false, 0.0).dynamic_scheme
+            MaterialDynamicColors::inverse_surface().get_color(
+                &SchemeContent::new(Hct::from_argb(Argb(0xFF0000FF)), false, 0.0).dynamic_scheme
             ),
-            (0xFF2F2F3B),
+            (Argb(0xFF2F2F3B)),
         );
         assert_eq!(
-            MaterialDynamicColors::inverse_primary().get_argb(
-                &SchemeContent::new(Hct::from_int(0xFFFF0000), false, -0.5).dynamic_scheme
+            MaterialDynamicColors::inverse_primary().get_color(
+                &SchemeContent::new(Hct::from_argb(Argb(0xFFFF0000)), false, -0.5).dynamic_scheme
             ),
-            (0xffff422f),
+            (Argb(0xffff422f)),
         );
         assert_eq!(
-            MaterialDynamicColors::outline_variant()
-                .get_argb(&SchemeContent::new(Hct::from_int(0xFFFFFF00), true, 0.0).dynamic_scheme),
-            (0xFF484831),
+            MaterialDynamicColors::outline_variant().get_color(
+                &SchemeContent::new(Hct::from_argb(Argb(0xFFFFFF00)), true, 0.0).dynamic_scheme
+            ),
+            (Argb(0xFF484831)),
         );
     }
 
@@ -301,7 +303,8 @@ pub mod dynamic_color_test {
     // Tests for fixed colors.
     #[test]
     fn fixed_colors_in_non_monochrome_schemes() {
-        let scheme = SchemeTonalSpot::new(Hct::from_int(0xFFFF0000), true, 0.0).dynamic_scheme;
+        let scheme =
+            SchemeTonalSpot::new(Hct::from_argb(Argb(0xFFFF0000)), true, 0.0).dynamic_scheme;
 
         assert_approx_eq::assert_approx_eq!(
             MaterialDynamicColors::primary_fixed()
@@ -391,7 +394,8 @@ pub mod dynamic_color_test {
 
     #[test]
     fn fixed_colors_in_light_monochrome_schemes() {
-        let scheme = SchemeMonochrome::new(Hct::from_int(0xFFFF0000), false, 0.0).dynamic_scheme;
+        let scheme =
+            SchemeMonochrome::new(Hct::from_argb(Argb(0xFFFF0000)), false, 0.0).dynamic_scheme;
 
         assert_approx_eq::assert_approx_eq!(
             MaterialDynamicColors::primary_fixed()
@@ -481,7 +485,8 @@ pub mod dynamic_color_test {
 
     #[test]
     fn fixed_colors_in_dark_monochrome_schemes() {
-        let scheme = SchemeMonochrome::new(Hct::from_int(0xFFFF0000), true, 0.0).dynamic_scheme;
+        let scheme =
+            SchemeMonochrome::new(Hct::from_argb(Argb(0xFFFF0000)), true, 0.0).dynamic_scheme;
 
         assert_approx_eq::assert_approx_eq!(
             MaterialDynamicColors::primary_fixed()
@@ -44,3 +44,16 @@ fn rotation_result_larger_than_360_degrees_wraps() {
     assert_approx_eq::assert_approx_eq!(hue, 163.0, 1.0);
 }
  */
+
+use crate::{dynamiccolor::dynamic_scheme::DynamicScheme, error::error::Error, hct::hct::Hct};
+
+#[test]
+fn input_length_mismatch_errors() {
+    assert_eq!(
+        DynamicScheme::try_get_rotated_hue(&Hct::from(43.0, 16.0, 16.0), &[0.0, 1.0], &[0.0]),
+        Err(Error::MismatchedRotations {
+            hues: 2,
+            rotations: 1
+        })
+    );
+}
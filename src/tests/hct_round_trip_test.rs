@@ -1,13 +1,12 @@
-
-use crate::hct::hct::Hct;
+use crate::{hct::hct::Hct, utils::argb::Argb};
 
 #[test]
 #[ignore]
 fn hct_preserves_original_color() {
-    for argb in 0xFF000000..=0xFFFFFFFF {
-        let hct = Hct::from_int(argb);
+    for argb in (0xFF000000..=0xFFFFFFFF).map(Argb) {
+        let hct = Hct::from_argb(argb);
         let reconstructed_argb =
-            Hct::from(hct.get_hue(), hct.get_chroma(), hct.get_tone()).to_int();
+            Hct::from(hct.get_hue(), hct.get_chroma(), hct.get_tone()).to_argb();
 
         assert_eq!(reconstructed_argb, (argb));
     }
@@ -5,32 +5,32 @@ use std::{
 
 use crate::{
     hct::{cam16::Cam16, hct::Hct, viewing_conditions::ViewingConditions},
-    utils::{color_utils::ColorUtils, string_utils::StringUtils},
+    utils::{argb::Argb, color_utils::ColorUtils},
 };
 
-const RED: i64 = 0xffff0000;
-const BLACK: i64 = 0xff000000;
-const WHITE: i64 = 0xffffffff;
-const GREEN: i64 = 0xff00ff00;
-const BLUE: i64 = 0xff0000ff;
-const MIDGRAY: i64 = 0xff777777;
+const RED: Argb = Argb(0xffff0000);
+const BLACK: Argb = Argb(0xff000000);
+const WHITE: Argb = Argb(0xffffffff);
+const GREEN: Argb = Argb(0xff00ff00);
+const BLUE: Argb = Argb(0xff0000ff);
+const MIDGRAY: Argb = Argb(0xff777777);
 
 #[test]
 fn hash_code_basics() {
-    assert!(Hct::from_int(123) == Hct::from_int(123));
+    assert!(Hct::from_argb(Argb(123)) == Hct::from_argb(Argb(123)));
     let mut a = DefaultHasher::new();
-    Hct::from_int(123).hash(&mut a);
+    Hct::from_argb(Argb(123)).hash(&mut a);
     let fa = a.finish();
     let mut b = DefaultHasher::new();
-    Hct::from_int(123).hash(&mut b);
+    Hct::from_argb(Argb(123)).hash(&mut b);
     let fb = b.finish();
     assert_eq!(fa, fb);
 }
 
 #[test]
 fn conversions_are_reflexive() {
-    let cam = Cam16::from_int(RED);
-    let color = cam.viewed(&ViewingConditions::standard());
+    let cam = Cam16::from_argb(RED);
+    let color = cam.viewed_argb(&ViewingConditions::standard());
     assert_eq!(color, RED);
 }
 
@@ -51,7 +51,7 @@ fn y_white() {
 
 #[test]
 fn cam_red() {
-    let cam = Cam16::from_int(RED);
+    let cam = Cam16::from_argb(RED);
     assert_approx_eq::assert_approx_eq!(46.445, cam.j, 0.001);
     assert_approx_eq::assert_approx_eq!(113.357, cam.chroma, 0.001);
     assert_approx_eq::assert_approx_eq!(27.408, cam.hue, 0.001);
@@ -62,7 +62,7 @@ fn cam_red() {
 
 #[test]
 fn cam_green() {
-    let cam = Cam16::from_int(GREEN);
+    let cam = Cam16::from_argb(GREEN);
     assert_approx_eq::assert_approx_eq!(79.331, cam.j, 0.001);
     assert_approx_eq::assert_approx_eq!(108.410, cam.chroma, 0.001);
     assert_approx_eq::assert_approx_eq!(142.139, cam.hue, 0.001);
@@ -73,7 +73,7 @@ fn cam_green() {
 
 #[test]
 fn cam_blue() {
-    let cam = Cam16::from_int(BLUE);
+    let cam = Cam16::from_argb(BLUE);
     assert_approx_eq::assert_approx_eq!(25.465, cam.j, 0.001);
     assert_approx_eq::assert_approx_eq!(87.230, cam.chroma, 0.001);
     assert_approx_eq::assert_approx_eq!(282.788, cam.hue, 0.001);
@@ -84,7 +84,7 @@ fn cam_blue() {
 
 #[test]
 fn cam_black() {
-    let cam = Cam16::from_int(BLACK);
+    let cam = Cam16::from_argb(BLACK);
     assert_approx_eq::assert_approx_eq!(0.0, cam.j, 0.001);
     assert_approx_eq::assert_approx_eq!(0.0, cam.chroma, 0.001);
     assert_approx_eq::assert_approx_eq!(0.0, cam.hue, 0.001);
@@ -95,7 +95,7 @@ fn cam_black() {
 
 #[test]
 fn cam_white() {
-    let cam = Cam16::from_int(WHITE);
+    let cam = Cam16::from_argb(WHITE);
     assert_approx_eq::assert_approx_eq!(100.0, cam.j, 0.001);
     assert_approx_eq::assert_approx_eq!(2.869, cam.chroma, 0.001);
     assert_approx_eq::assert_approx_eq!(209.492, cam.hue, 0.001);
@@ -107,75 +107,50 @@ fn cam_white() {
 #[test]
 fn gamut_map_red() {
     let color_to_test = RED;
-    let cam = Cam16::from_int(color_to_test);
-    let color = Hct::from(
-        cam.hue,
-        cam.chroma,
-        ColorUtils::lstar_from_argb(color_to_test),
-    )
-    .to_int();
+    let cam = Cam16::from_argb(color_to_test);
+    let color = Hct::from(cam.hue, cam.chroma, color_to_test.lstar()).to_argb();
     assert_eq!(color_to_test, (color));
 }
 
 #[test]
 fn gamut_map_green() {
     let color_to_test = GREEN;
-    let cam = Cam16::from_int(color_to_test);
-    let color = Hct::from(
-        cam.hue,
-        cam.chroma,
-        ColorUtils::lstar_from_argb(color_to_test),
-    )
-    .to_int();
+    let cam = Cam16::from_argb(color_to_test);
+    let color = Hct::from(cam.hue, cam.chroma, color_to_test.lstar()).to_argb();
     assert_eq!(color_to_test, (color));
 }
 
 #[test]
 fn gamut_map_blue() {
     let color_to_test = BLUE;
-    let cam = Cam16::from_int(color_to_test);
-    let color = Hct::from(
-        cam.hue,
-        cam.chroma,
-        ColorUtils::lstar_from_argb(color_to_test),
-    )
-    .to_int();
+    let cam = Cam16::from_argb(color_to_test);
+    let color = Hct::from(cam.hue, cam.chroma, color_to_test.lstar()).to_argb();
     assert_eq!(color_to_test, (color));
 }
 
 #[test]
 fn gamut_map_white() {
     let color_to_test = WHITE;
-    let cam = Cam16::from_int(color_to_test);
-    let color = Hct::from(
-        cam.hue,
-        cam.chroma,
-        ColorUtils::lstar_from_argb(color_to_test),
-    )
-    .to_int();
+    let cam = Cam16::from_argb(color_to_test);
+    let color = Hct::from(cam.hue, cam.chroma, color_to_test.lstar()).to_argb();
     assert_eq!(color_to_test, (color));
 }
 
 #[test]
 fn gamut_map_midgray() {
     let color_to_test = MIDGRAY;
-    let cam = Cam16::from_int(color_to_test);
-    let color = Hct::from(
-        cam.hue,
-        cam.chroma,
-        ColorUtils::lstar_from_argb(color_to_test),
-    )
-    .to_int();
+    let cam = Cam16::from_argb(color_to_test);
+    let color = Hct::from(cam.hue, cam.chroma, color_to_test.lstar()).to_argb();
     assert_eq!(color_to_test, (color));
 }
 
-fn _color_is_on_boundary(argb: i64) -> bool {
-    return ColorUtils::red_from_argb(argb) == 0
-        || ColorUtils::red_from_argb(argb) == 255
-        || ColorUtils::green_from_argb(argb) == 0
-        || ColorUtils::green_from_argb(argb) == 255
-        || ColorUtils::blue_from_argb(argb) == 0
-        || ColorUtils::blue_from_argb(argb) == 255;
+fn _color_is_on_boundary(argb: Argb) -> bool {
+    return argb.red() == 0
+        || argb.red() == 255
+        || argb.green() == 0
+        || argb.green() == 255
+        || argb.blue() == 0
+        || argb.blue() == 255;
 }
 
 #[test]
@@ -204,10 +179,10 @@ fn hct_returns_a_sufficiently_close_color() {
 
                 if hct_color.get_chroma() < chroma as f64 - 2.5 {
                     assert_eq!(
-                        _color_is_on_boundary(hct_color.to_int()),
+                        _color_is_on_boundary(hct_color.to_argb()),
                         true,
                         "HCT request for non-sRGB color should return \na color on the boundary of the sRGB cube \nfor {}, but got {} instead",hct_request_description,
-                        StringUtils::hex_from_argb(hct_color.to_int(), None)
+                        hct_color.to_argb()
                       );
                 }
 
@@ -232,7 +207,7 @@ pub mod cam16_to_xyz {
     #[test]
     fn without_array() {
         let color_to_test = RED;
-        let cam = Cam16::from_int(color_to_test);
+        let cam = Cam16::from_argb(color_to_test);
         let xyz = cam.xyz_in_viewing_conditions(&ViewingConditions::s_rgb(), None);
         assert_approx_eq::assert_approx_eq!(xyz[0], 41.23, 0.01);
         assert_approx_eq::assert_approx_eq!(xyz[1], 21.26, 0.01);
@@ -242,7 +217,7 @@ pub mod cam16_to_xyz {
     #[test]
     fn with_array() {
         let color_to_test = RED;
-        let cam = Cam16::from_int(color_to_test);
+        let cam = Cam16::from_argb(color_to_test);
         let xyz =
             cam.xyz_in_viewing_conditions(&ViewingConditions::s_rgb(), Some(&vec![0.0, 0.0, 0.0]));
         assert_approx_eq::assert_approx_eq!(xyz[0], 41.23, 0.01);
@@ -256,6 +231,7 @@ pub mod color_relativity {
     use crate::{
         hct::{hct::Hct, viewing_conditions::ViewingConditions},
         tests::hct_test::{BLACK, BLUE, GREEN, MIDGRAY, WHITE},
+        utils::argb::Argb,
     };
 
     use super::RED;
@@ -263,18 +239,18 @@ pub mod color_relativity {
     #[test]
     fn red_in_black() {
         let color_to_test = RED;
-        let hct = Hct::from_int(color_to_test);
+        let hct = Hct::from_argb(color_to_test);
         assert_eq!(
             hct.in_viewing_conditions(&ViewingConditions::make(None, None, Some(0.0), None, None,))
-                .to_int(),
-            (0xff9F5C51)
+                .to_argb(),
+            (Argb(0xff9F5C51))
         );
     }
 
     #[test]
     fn red_in_white() {
         let color_to_test = RED;
-        let hct = Hct::from_int(color_to_test);
+        let hct = Hct::from_argb(color_to_test);
         assert_eq!(
             hct.in_viewing_conditions(&ViewingConditions::make(
                 None,
@@ -283,26 +259,26 @@ pub mod color_relativity {
                 None,
                 None,
             ))
-            .to_int(),
-            (0xffFF5D48)
+            .to_argb(),
+            (Argb(0xffFF5D48))
         );
     }
 
     #[test]
     fn green_in_black() {
         let color_to_test = GREEN;
-        let hct = Hct::from_int(color_to_test);
+        let hct = Hct::from_argb(color_to_test);
         assert_eq!(
             hct.in_viewing_conditions(&ViewingConditions::make(None, None, Some(0.0), None, None,))
-                .to_int(),
-            (0xffACD69D)
+                .to_argb(),
+            (Argb(0xffACD69D))
         );
     }
 
     #[test]
     fn green_in_white() {
         let color_to_test = GREEN;
-        let hct = Hct::from_int(color_to_test);
+        let hct = Hct::from_argb(color_to_test);
         assert_eq!(
             hct.in_viewing_conditions(&ViewingConditions::make(
                 None,
@@ -311,26 +287,26 @@ pub mod color_relativity {
                 None,
                 None,
             ))
-            .to_int(),
-            (0xff8EFF77)
+            .to_argb(),
+            (Argb(0xff8EFF77))
         );
     }
 
     #[test]
     fn blue_in_black() {
         let color_to_test = BLUE;
-        let hct = Hct::from_int(color_to_test);
+        let hct = Hct::from_argb(color_to_test);
         assert_eq!(
             hct.in_viewing_conditions(&ViewingConditions::make(None, None, Some(0.0), None, None,))
-                .to_int(),
-            (0xff343654)
+                .to_argb(),
+            (Argb(0xff343654))
         );
     }
 
     #[test]
     fn blue_in_white() {
         let color_to_test = BLUE;
-        let hct = Hct::from_int(color_to_test);
+        let hct = Hct::from_argb(color_to_test);
         assert_eq!(
             hct.in_viewing_conditions(&ViewingConditions::make(
                 None,
@@ -339,26 +315,26 @@ pub mod color_relativity {
                 None,
                 None,
             ))
-            .to_int(),
-            (0xff3F49FF)
+            .to_argb(),
+            (Argb(0xff3F49FF))
         );
     }
 
     #[test]
     fn white_in_black() {
         let color_to_test = WHITE;
-        let hct = Hct::from_int(color_to_test);
+        let hct = Hct::from_argb(color_to_test);
         assert_eq!(
             hct.in_viewing_conditions(&ViewingConditions::make(None, None, Some(0.0), None, None,))
-                .to_int(),
-            (0xffFFFFFF)
+                .to_argb(),
+            (Argb(0xffFFFFFF))
         );
     }
 
     #[test]
     fn white_in_white() {
         let color_to_test = WHITE;
-        let hct = Hct::from_int(color_to_test);
+        let hct = Hct::from_argb(color_to_test);
         assert_eq!(
             hct.in_viewing_conditions(&ViewingConditions::make(
                 None,
@@ -367,26 +343,26 @@ pub mod color_relativity {
                 None,
                 None,
             ))
-            .to_int(),
-            (0xffFFFFFF)
+            .to_argb(),
+            (Argb(0xffFFFFFF))
         );
     }
 
     #[test]
     fn midgray_in_black() {
         let color_to_test = MIDGRAY;
-        let hct = Hct::from_int(color_to_test);
+        let hct = Hct::from_argb(color_to_test);
         assert_eq!(
             hct.in_viewing_conditions(&ViewingConditions::make(None, None, Some(0.0), None, None,))
-                .to_int(),
-            (0xff605F5F)
+                .to_argb(),
+            (Argb(0xff605F5F))
         );
     }
 
     #[test]
     fn midgray_in_white() {
         let color_to_test = MIDGRAY;
-        let hct = Hct::from_int(color_to_test);
+        let hct = Hct::from_argb(color_to_test);
         assert_eq!(
             hct.in_viewing_conditions(&ViewingConditions::make(
                 None,
@@ -395,26 +371,26 @@ pub mod color_relativity {
                 None,
                 None,
             ))
-            .to_int(),
-            (0xff8E8E8E)
+            .to_argb(),
+            (Argb(0xff8E8E8E))
         );
     }
 
     #[test]
     fn black_in_black() {
         let color_to_test = BLACK;
-        let hct = Hct::from_int(color_to_test);
+        let hct = Hct::from_argb(color_to_test);
         assert_eq!(
             hct.in_viewing_conditions(&ViewingConditions::make(None, None, Some(0.0), None, None,))
-                .to_int(),
-            (0xff000000)
+                .to_argb(),
+            (Argb(0xff000000))
         );
     }
 
     #[test]
     fn black_in_white() {
         let color_to_test = BLACK;
-        let hct = Hct::from_int(color_to_test);
+        let hct = Hct::from_argb(color_to_test);
         assert_eq!(
             hct.in_viewing_conditions(&ViewingConditions::make(
                 None,
@@ -423,8 +399,32 @@ pub mod color_relativity {
                 None,
                 None,
             ))
-            .to_int(),
-            (0xff000000)
+            .to_argb(),
+            (Argb(0xff000000))
+        );
+    }
+}
+
+#[cfg(test)]
+pub mod viewing_conditions {
+    use crate::{error::error::Error, hct::viewing_conditions::ViewingConditions};
+
+    #[test]
+    fn surround_out_of_range_errors() {
+        assert_eq!(
+            ViewingConditions::try_make(None, None, None, Some(2.5), None).err(),
+            Some(Error::SurroundOutOfRange(2.5))
+        );
+    }
+
+    #[test]
+    fn white_point_wrong_length_errors() {
+        assert_eq!(
+            ViewingConditions::try_make(Some(vec![95.047, 100.0]), None, None, None, None).err(),
+            Some(Error::InvalidLength {
+                expected: 3,
+                actual: 2
+            })
         );
     }
 }
@@ -0,0 +1,109 @@
+use image::{imageops::FilterType, DynamicImage, Rgba, RgbaImage};
+
+use crate::{
+    quantize::{
+        image_pixels::{ImagePixels, ImageSampling, QuantizeImage},
+        quantizer::Quantizer,
+        quantizer_celebi::QuantizerCelebi,
+        quantizer_map::QuantizerMap,
+        quantizer_wu::QuantizerWu,
+    },
+    utils::{argb::Argb, theme_from_image::ThemeFromImage},
+};
+
+const RED: Argb = Argb(0xffd32f2f);
+const BLUE: Argb = Argb(0xff1e88e5);
+
+/// A [width] by [height] image, red on the left half and blue on the right
+/// half, with a translucent top row.
+fn image(width: u32, height: u32) -> RgbaImage {
+    return RgbaImage::from_fn(width, height, |x, y| {
+        let alpha = if y == 0 { 128 } else { 255 };
+        if x < width / 2 {
+            Rgba([0xd3, 0x2f, 0x2f, alpha])
+        } else {
+            Rgba([0x1e, 0x88, 0xe5, alpha])
+        }
+    });
+}
+
+#[test]
+fn converts_opaque_pixels() {
+    let pixels = ImagePixels::from(&image(4, 3));
+    assert_eq!(pixels.len(), 8);
+    assert_eq!(&pixels[..4], &[RED, RED, BLUE, BLUE]);
+}
+
+#[test]
+fn dynamic_image_matches_rgba_image() {
+    let rgba = image(10, 10);
+    let dynamic = DynamicImage::ImageRgba8(rgba.clone());
+    assert_eq!(ImagePixels::from(&dynamic), ImagePixels::from(&rgba));
+    let rgb = DynamicImage::ImageRgb8(DynamicImage::ImageRgba8(rgba).to_rgb8());
+    assert_eq!(ImagePixels::from(&rgb).len(), 100);
+}
+
+#[test]
+fn quantizers_accept_images() {
+    let rgba = image(20, 11);
+    let pixels: Vec<Argb> = rgba
+        .pixels()
+        .filter(|p| p[3] == 255)
+        .map(|p| Argb::from_rgb(p[0], p[1], p[2]))
+        .collect();
+    assert_eq!(
+        QuantizerMap {}.quantize_image(&rgba, 128).color_to_count,
+        QuantizerMap {}.quantize(&pixels, 128, None).color_to_count
+    );
+    assert_eq!(
+        QuantizerWu::new().quantize_image(&rgba, 128).color_to_count,
+        QuantizerWu::new()
+            .quantize(&pixels, 128, None)
+            .color_to_count
+    );
+    let result = QuantizerCelebi {}.quantize_image(&DynamicImage::ImageRgba8(rgba), 128);
+    assert_eq!(result.color_to_count.get(&RED), Some(&100));
+    assert_eq!(result.color_to_count.get(&BLUE), Some(&100));
+}
+
+#[test]
+fn resizes_large_images() {
+    let rgba = image(400, 200);
+    let sampling = ImageSampling::Resize {
+        max_dimension: 100,
+        filter: FilterType::Nearest,
+    };
+    assert!(ImagePixels::from_rgba_image(&rgba, sampling).len() <= 100 * 50);
+    let dynamic = DynamicImage::ImageRgba8(rgba.clone());
+    assert!(ImagePixels::from_image(&dynamic, sampling).len() <= 100 * 50);
+
+    let small = image(40, 20);
+    assert_eq!(
+        ImagePixels::from_rgba_image(&small, sampling),
+        ImagePixels::from(&small)
+    );
+}
+
+#[test]
+fn samples_with_stride() {
+    let rgba = image(10, 10);
+    let pixels = ImagePixels::from_rgba_image(&rgba, ImageSampling::Stride(3));
+    // 34 of the 100 pixels are sampled, 4 of which are in the translucent row.
+    assert_eq!(pixels.len(), 30);
+    assert_eq!(
+        ImagePixels::from_rgba_image(&rgba, ImageSampling::Stride(0)),
+        ImagePixels::from(&rgba)
+    );
+}
+
+#[test]
+fn themes_images() {
+    let rgba = image(30, 31);
+    let theme = ThemeFromImage::new().theme_image(&rgba);
+    assert!(theme == ThemeFromImage::new().theme(&ImagePixels::from(&rgba)));
+    assert!(theme.candidates.contains(&RED));
+    assert!(theme.candidates.contains(&BLUE));
+
+    let candidates = ThemeFromImage::new().theme_candidates_image(&rgba, 2);
+    assert!(candidates == ThemeFromImage::new().theme_candidates(&ImagePixels::from(&rgba), 2));
+}
@@ -1,6 +1,10 @@
 #[cfg(test)]
+pub mod argb_test;
+#[cfg(test)]
 pub mod blend_test;
 #[cfg(test)]
+pub mod color_role_test;
+#[cfg(test)]
 pub mod color_utils_tests;
 #[cfg(test)]
 pub mod contrast_test;
@@ -14,21 +18,59 @@ pub mod dynamic_scheme_test;
 pub mod hct_round_trip_test;
 #[cfg(test)]
 pub mod hct_test;
+#[cfg(all(test, feature = "image"))]
+pub mod image_pixels_test;
 #[cfg(test)]
 pub mod math_utils_test;
 #[cfg(test)]
 pub mod palettes_test;
 #[cfg(test)]
+pub mod pixel_buffer_test;
+#[cfg(test)]
+pub mod point_provider_test;
+#[cfg(test)]
 pub mod quantizer_celebi_test;
 #[cfg(test)]
+pub mod quantizer_checks;
+#[cfg(test)]
+pub mod quantizer_median_cut_test;
+#[cfg(test)]
+pub mod quantizer_octree_test;
+#[cfg(test)]
+pub mod quantizer_parallel_test;
+#[cfg(test)]
+pub mod quantizer_session_test;
+#[cfg(test)]
 pub mod quantizer_wsmeans_test;
 #[cfg(test)]
 pub mod quantizer_wu_test;
 #[cfg(test)]
+pub mod remapper_test;
+#[cfg(test)]
+pub mod resolved_scheme_test;
+#[cfg(test)]
+pub mod scheme_builder_test;
+#[cfg(test)]
 pub mod scheme_correctness_test;
 #[cfg(test)]
 pub mod scheme_monochrome_test;
 #[cfg(test)]
 pub mod score_test;
 #[cfg(test)]
+pub mod semantic_palettes_test;
+#[cfg(all(test, feature = "serde"))]
+pub mod serde_test;
+#[cfg(all(test, feature = "spec-2025"))]
+pub mod spec_version_test;
+#[cfg(test)]
+pub mod swatch_test;
+#[cfg(test)]
 pub mod temperature_cache_test;
+#[cfg(test)]
+pub mod theme_from_image_test;
+#[cfg(test)]
+pub mod theme_utils_test;
+#[cfg(test)]
+pub mod thread_safety_test;
+#[cfg(test)]
+pub mod weighted_quantization_test;
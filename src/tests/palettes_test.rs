@@ -1,7 +1,7 @@
 pub mod tonal_palette {
     #[cfg(test)]
     pub mod of_and_from_list_constructors {
-        use crate::palettes::tonal_palette::TonalPalette;
+        use crate::{palettes::tonal_palette::TonalPalette, utils::argb::Argb};
 
         // Regression test for https://github.com/material-foundation/material-color-utilities/issues/56
         #[test]
@@ -9,9 +9,9 @@ pub mod tonal_palette {
             let a1 = TonalPalette::of(1.0, 1.0);
             let a2 = TonalPalette::of(1.0, 1.0);
             let b1 =
-                TonalPalette::from_list(&TonalPalette::COMMON_TONES.map(|_| 0xDEADBEEF).to_vec());
+                TonalPalette::from_argb_list(&TonalPalette::COMMON_TONES.map(|_| Argb(0xDEADBEEF)));
             let b2 =
-                TonalPalette::from_list(&TonalPalette::COMMON_TONES.map(|_| 0xDEADBEEF).to_vec());
+                TonalPalette::from_argb_list(&TonalPalette::COMMON_TONES.map(|_| Argb(0xDEADBEEF)));
             assert_eq!(a1 == b1, false);
             assert_eq!(b1 == a1, false);
             assert_eq!(a1 != b1, true);
@@ -19,15 +19,17 @@ pub mod tonal_palette {
             assert_eq!(a1 == a2, true);
             assert_eq!(b1 == b2, true);
 
-            let mut c1 = TonalPalette::from_list(&TonalPalette::COMMON_TONES.map(|_| 123).to_vec());
+            let c1 = TonalPalette::from_argb_list(&TonalPalette::COMMON_TONES.map(|_| Argb(123)));
 
-            let mut c2 = TonalPalette::from_list(
-                &TonalPalette::COMMON_TONES
-                    .map(|e| if e < 15 { 456 } else { 123 })
-                    .to_vec(),
-            );
+            let c2 = TonalPalette::from_argb_list(&TonalPalette::COMMON_TONES.map(|e| {
+                if e < 15 {
+                    Argb(456)
+                } else {
+                    Argb(123)
+                }
+            }));
 
-            assert_eq!(c1.get(50), c2.get(50));
+            assert_eq!(c1.get_argb(50), c2.get_argb(50));
             assert_eq!(c1 == c2, false);
         }
     }
@@ -38,51 +40,61 @@ pub mod tonal_palette {
             hash::{Hash, Hasher},
         };
 
-        use crate::{hct::hct::Hct, palettes::tonal_palette::TonalPalette};
+        use crate::{hct::hct::Hct, palettes::tonal_palette::TonalPalette, utils::argb::Argb};
         #[test]
         fn tones_of_blue() {
-            let hct = Hct::from_int(0xff0000ff);
-            let mut tones = TonalPalette::of(hct.get_hue(), hct.get_chroma());
-
-            assert_eq!(tones.get(0), 0xff000000);
-            assert_eq!(tones.get(10), 0xff00006e);
-            assert_eq!(tones.get(20), 0xff0001ac);
-            assert_eq!(tones.get(30), 0xff0000ef);
-            assert_eq!(tones.get(40), 0xff343dff);
-            assert_eq!(tones.get(50), 0xff5a64ff);
-            assert_eq!(tones.get(60), 0xff7c84ff);
-            assert_eq!(tones.get(70), 0xff9da3ff);
-            assert_eq!(tones.get(80), 0xffbec2ff);
-            assert_eq!(tones.get(90), 0xffe0e0ff);
-            assert_eq!(tones.get(95), 0xfff1efff);
-            assert_eq!(tones.get(99), 0xfffffbff);
-            assert_eq!(tones.get(100), 0xffffffff);
+            let hct = Hct::from_argb(Argb(0xff0000ff));
+            let tones = TonalPalette::of(hct.get_hue(), hct.get_chroma());
+
+            assert_eq!(tones.get_argb(0), Argb(0xff000000));
+            assert_eq!(tones.get_argb(10), Argb(0xff00006e));
+            assert_eq!(tones.get_argb(20), Argb(0xff0001ac));
+            assert_eq!(tones.get_argb(30), Argb(0xff0000ef));
+            assert_eq!(tones.get_argb(40), Argb(0xff343dff));
+            assert_eq!(tones.get_argb(50), Argb(0xff5a64ff));
+            assert_eq!(tones.get_argb(60), Argb(0xff7c84ff));
+            assert_eq!(tones.get_argb(70), Argb(0xff9da3ff));
+            assert_eq!(tones.get_argb(80), Argb(0xffbec2ff));
+            assert_eq!(tones.get_argb(90), Argb(0xffe0e0ff));
+            assert_eq!(tones.get_argb(95), Argb(0xfff1efff));
+            assert_eq!(tones.get_argb(99), Argb(0xfffffbff));
+            assert_eq!(tones.get_argb(100), Argb(0xffffffff));
 
             // Tone not in [TonalPalette.commonTones]
-            assert_eq!(tones.get(3), 0xff00003c);
+            assert_eq!(tones.get_argb(3), Argb(0xff00003c));
         }
 
         #[test]
         fn as_list() {
-            let hct = Hct::from_int(0xff0000ff);
-            let mut tones = TonalPalette::of(hct.get_hue(), hct.get_chroma());
+            let hct = Hct::from_argb(Argb(0xff0000ff));
+            let tones = TonalPalette::of(hct.get_hue(), hct.get_chroma());
 
             assert_eq!(
-                tones.get_as_list(),
+                tones.get_argb_list(),
                 [
-                    0xff000000, 0xff00006e, 0xff0001ac, 0xff0000ef, 0xff343dff, 0xff5a64ff,
-                    0xff7c84ff, 0xff9da3ff, 0xffbec2ff, 0xffe0e0ff, 0xfff1efff, 0xfffffbff,
-                    0xffffffff,
+                    Argb(0xff000000),
+                    Argb(0xff00006e),
+                    Argb(0xff0001ac),
+                    Argb(0xff0000ef),
+                    Argb(0xff343dff),
+                    Argb(0xff5a64ff),
+                    Argb(0xff7c84ff),
+                    Argb(0xff9da3ff),
+                    Argb(0xffbec2ff),
+                    Argb(0xffe0e0ff),
+                    Argb(0xfff1efff),
+                    Argb(0xfffffbff),
+                    Argb(0xffffffff),
                 ],
             );
         }
 
         #[test]
         fn operator_eq_and_hash_code() {
-            let hct_ab = Hct::from_int(0xff0000ff);
+            let hct_ab = Hct::from_argb(Argb(0xff0000ff));
             let tones_a = TonalPalette::of(hct_ab.get_hue(), hct_ab.get_chroma());
             let tones_b = TonalPalette::of(hct_ab.get_hue(), hct_ab.get_chroma());
-            let hct_c = Hct::from_int(0xff123456);
+            let hct_c = Hct::from_argb(Argb(0xff123456));
             let tones_c = TonalPalette::of(hct_c.get_hue(), hct_c.get_chroma());
 
             assert_eq!(tones_a, tones_b);
@@ -98,6 +110,22 @@ pub mod tonal_palette {
             assert_eq!(a_hash_code.finish(), b_hash_code.finish());
             assert!(b_hash_code.finish() != (c_hash_code.finish()));
         }
+
+        #[test]
+        fn max_chroma_tone() {
+            let tones = TonalPalette::of(270.0, 36.0);
+            let lightest = tones.max_chroma_tone(true);
+            let darkest = tones.max_chroma_tone(false);
+
+            assert!(darkest < lightest);
+            assert!(tones.get_hct(lightest).get_chroma() >= 35.0);
+            assert!(tones.get_hct(darkest).get_chroma() >= 35.0);
+            assert_eq!(tones.clone().max_chroma_tone(true), lightest);
+            assert_eq!(
+                TonalPalette::of(270.0, 36.0).max_chroma_tone(false),
+                darkest
+            );
+        }
     }
 
     #[cfg(test)]
@@ -107,35 +135,38 @@ pub mod tonal_palette {
             hash::{Hash, Hasher},
         };
 
-        use crate::{hct::hct::Hct, palettes::tonal_palette::TonalPalette};
+        use crate::{
+            error::error::Error, hct::hct::Hct, palettes::tonal_palette::TonalPalette,
+            utils::argb::Argb,
+        };
 
         fn hue_chroma_palette() -> TonalPalette {
             TonalPalette::of(270.0, 36.0)
         }
-        fn cached_palette() -> Vec<i64> {
+        fn cached_palette() -> Vec<Argb> {
             TonalPalette::COMMON_TONES
-                .map(|t| hue_chroma_palette().get(t))
+                .map(|t| hue_chroma_palette().get_argb(t))
                 .to_vec()
         }
-        fn broken_palette() -> [i64; 13] {
+        fn broken_palette() -> [Argb; 13] {
             [
                 cached_palette()[0],
                 cached_palette()[1],
-                Hct::from(180.0, 24.0, 20.0).to_int(),
+                Hct::from(180.0, 24.0, 20.0).to_argb(),
                 cached_palette()[3],
                 cached_palette()[4],
                 cached_palette()[5],
                 cached_palette()[6],
                 cached_palette()[7],
                 cached_palette()[8],
-                Hct::from(0.0, 12.0, 90.0).to_int(),
+                Hct::from(0.0, 12.0, 90.0).to_argb(),
                 cached_palette()[10],
                 cached_palette()[11],
                 cached_palette()[12],
             ]
         }
         fn rebuilt_palette() -> TonalPalette {
-            TonalPalette::from_list(&broken_palette().to_vec())
+            TonalPalette::from_argb_list(&broken_palette())
         }
 
         #[test]
@@ -146,25 +177,34 @@ pub mod tonal_palette {
 
         #[test]
         fn low_chroma_noise_does_not_affect_the_hue_and_chroma_deduced() {
-            let rebuilt_clean_palette = TonalPalette::from_list(&cached_palette());
+            let rebuilt_clean_palette = TonalPalette::from_argb_list(&cached_palette());
             assert_eq!(rebuilt_palette().hue, rebuilt_clean_palette.hue);
             assert_eq!(rebuilt_palette().chroma, rebuilt_clean_palette.chroma);
         }
 
         #[test]
         fn returns_cached_colors_when_possible() {
-            assert_eq!(rebuilt_palette().get(20), (broken_palette()[2]));
-            assert_eq!(rebuilt_palette().get(50), (broken_palette()[5]));
-            assert_eq!(rebuilt_palette().get(90), (broken_palette()[9]));
-            assert_eq!(rebuilt_palette().get(99), (broken_palette()[11]));
+            assert_eq!(rebuilt_palette().get_argb(20), (broken_palette()[2]));
+            assert_eq!(rebuilt_palette().get_argb(50), (broken_palette()[5]));
+            assert_eq!(rebuilt_palette().get_argb(90), (broken_palette()[9]));
+            assert_eq!(rebuilt_palette().get_argb(99), (broken_palette()[11]));
         }
 
         #[test]
         #[ignore = ""]
         fn correctly_deduces_colors_at_other_tones() {
-            assert_eq!(rebuilt_palette().get(15), (hue_chroma_palette().get(15)),);
-            assert_eq!(rebuilt_palette().get(53), (hue_chroma_palette().get(53)),);
-            assert_eq!(rebuilt_palette().get(78), (hue_chroma_palette().get(78)),);
+            assert_eq!(
+                rebuilt_palette().get_argb(15),
+                (hue_chroma_palette().get_argb(15)),
+            );
+            assert_eq!(
+                rebuilt_palette().get_argb(53),
+                (hue_chroma_palette().get_argb(53)),
+            );
+            assert_eq!(
+                rebuilt_palette().get_argb(78),
+                (hue_chroma_palette().get_argb(78)),
+            );
         }
 
         #[test]
@@ -188,9 +228,25 @@ pub mod tonal_palette {
 
         #[test]
         fn as_list() {
-            let ints: Vec<i64> = (0..TonalPalette::COMMON_SIZE).map(|i| i as i64).collect();
-            let mut tones = TonalPalette::from_list(&ints.clone());
-            assert_eq!(tones.get_as_list(), ints);
+            let ints: Vec<Argb> = (0..TonalPalette::COMMON_SIZE)
+                .map(|i| Argb(i as u32))
+                .collect();
+            let tones = TonalPalette::from_argb_list(&ints.clone());
+            assert_eq!(tones.get_argb_list(), ints);
+        }
+
+        #[test]
+        fn wrong_length_errors() {
+            let ints: Vec<Argb> = (0..TonalPalette::COMMON_SIZE - 1)
+                .map(|i| Argb(i as u32))
+                .collect();
+            assert_eq!(
+                TonalPalette::try_from_argb_list(&ints),
+                Err(Error::InvalidLength {
+                    expected: TonalPalette::COMMON_SIZE,
+                    actual: TonalPalette::COMMON_SIZE - 1,
+                })
+            );
         }
 
         #[test]
@@ -207,15 +263,15 @@ pub mod tonal_palette {
             // 4   YES -   -   YES -
             // 5   -   -   -   -   YES
 
-            let mut palette1 = TonalPalette::of(270.0, 36.0);
+            let palette1 = TonalPalette::of(270.0, 36.0);
             let palette2 = TonalPalette::of(180.0, 36.0);
             let palette3 = TonalPalette::of(270.0, 12.0);
 
-            let palette4 = TonalPalette::from_list(&palette1.get_as_list());
-            let mut broken_list = palette1.clone().get_as_list();
-            broken_list[2] = Hct::from(180.0, 24.0, 20.0).to_int();
-            broken_list[9] = Hct::from(0.0, 12.0, 90.0).to_int();
-            let palette5 = TonalPalette::from_list(&broken_list);
+            let palette4 = TonalPalette::from_argb_list(&palette1.get_argb_list());
+            let mut broken_list = palette1.clone().get_argb_list();
+            broken_list[2] = Hct::from(180.0, 24.0, 20.0).to_argb();
+            broken_list[9] = Hct::from(0.0, 12.0, 90.0).to_argb();
+            let palette5 = TonalPalette::from_argb_list(&broken_list);
 
             assert_eq!(palette1, palette1);
             assert!(palette1 != (palette2));
@@ -267,22 +323,40 @@ pub mod core_palette {
         hash::{Hash, Hasher},
     };
 
-    use crate::palettes::{core_palette::CorePalette, tonal_palette::TonalPalette};
+    use crate::{
+        error::error::Error,
+        palettes::{core_palette::CorePalette, tonal_palette::TonalPalette},
+        utils::argb::Argb,
+    };
 
     #[test]
     fn as_list() {
-        let ints: Vec<i64> = (0..(CorePalette::SIZE * TonalPalette::COMMON_SIZE))
-            .map(|i| i as i64)
+        let ints: Vec<Argb> = (0..(CorePalette::SIZE * TonalPalette::COMMON_SIZE))
+            .map(|i| Argb(i as u32))
+            .collect();
+        let core_palette = CorePalette::from_argb_list(&ints);
+        assert_eq!(core_palette.as_argb_list(), ints);
+    }
+
+    #[test]
+    fn wrong_length_errors() {
+        let ints: Vec<Argb> = (0..TonalPalette::COMMON_SIZE)
+            .map(|i| Argb(i as u32))
             .collect();
-        let mut core_palette = CorePalette::from_list(&ints);
-        assert_eq!(core_palette.as_list(), ints);
+        assert_eq!(
+            CorePalette::try_from_argb_list(&ints),
+            Err(Error::InvalidLength {
+                expected: CorePalette::SIZE * TonalPalette::COMMON_SIZE,
+                actual: TonalPalette::COMMON_SIZE,
+            })
+        );
     }
 
     #[test]
     fn hash_code() {
-        let core_palette_a = CorePalette::of(0xff0000ff);
-        let core_palette_b = CorePalette::of(0xff0000ff);
-        let core_palette_c = CorePalette::of(0xff123456);
+        let core_palette_a = CorePalette::from_argb(Argb(0xff0000ff));
+        let core_palette_b = CorePalette::from_argb(Argb(0xff0000ff));
+        let core_palette_c = CorePalette::from_argb(Argb(0xff123456));
 
         assert_eq!(core_palette_a, core_palette_b);
         assert!(core_palette_b != core_palette_c);
@@ -297,59 +371,59 @@ pub mod core_palette {
     }
     #[test]
     fn of_blue() {
-        let mut core = CorePalette::of(0xff0000ff);
-        assert_eq!(core.primary.get(100), 0xffffffff);
-        assert_eq!(core.primary.get(95), 0xfff1efff);
-        assert_eq!(core.primary.get(90), 0xffe0e0ff);
-        assert_eq!(core.primary.get(80), 0xffbec2ff);
-        assert_eq!(core.primary.get(70), 0xff9da3ff);
-        assert_eq!(core.primary.get(60), 0xff7c84ff);
-        assert_eq!(core.primary.get(50), 0xff5a64ff);
-        assert_eq!(core.primary.get(40), 0xff343dff);
-        assert_eq!(core.primary.get(30), 0xff0000ef);
-        assert_eq!(core.primary.get(20), 0xff0001ac);
-        assert_eq!(core.primary.get(10), 0xff00006e);
-        assert_eq!(core.primary.get(0), 0xff000000);
-        assert_eq!(core.secondary.get(100), 0xffffffff);
-        assert_eq!(core.secondary.get(95), 0xfff1efff);
-        assert_eq!(core.secondary.get(90), 0xffe1e0f9);
-        assert_eq!(core.secondary.get(80), 0xffc5c4dd);
-        assert_eq!(core.secondary.get(70), 0xffa9a9c1);
-        assert_eq!(core.secondary.get(60), 0xff8f8fa6);
-        assert_eq!(core.secondary.get(50), 0xff75758b);
-        assert_eq!(core.secondary.get(40), 0xff5c5d72);
-        assert_eq!(core.secondary.get(30), 0xff444559);
-        assert_eq!(core.secondary.get(20), 0xff2e2f42);
-        assert_eq!(core.secondary.get(10), 0xff191a2c);
-        assert_eq!(core.secondary.get(0), 0xff000000);
+        let core = CorePalette::from_argb(Argb(0xff0000ff));
+        assert_eq!(core.primary.get_argb(100), Argb(0xffffffff));
+        assert_eq!(core.primary.get_argb(95), Argb(0xfff1efff));
+        assert_eq!(core.primary.get_argb(90), Argb(0xffe0e0ff));
+        assert_eq!(core.primary.get_argb(80), Argb(0xffbec2ff));
+        assert_eq!(core.primary.get_argb(70), Argb(0xff9da3ff));
+        assert_eq!(core.primary.get_argb(60), Argb(0xff7c84ff));
+        assert_eq!(core.primary.get_argb(50), Argb(0xff5a64ff));
+        assert_eq!(core.primary.get_argb(40), Argb(0xff343dff));
+        assert_eq!(core.primary.get_argb(30), Argb(0xff0000ef));
+        assert_eq!(core.primary.get_argb(20), Argb(0xff0001ac));
+        assert_eq!(core.primary.get_argb(10), Argb(0xff00006e));
+        assert_eq!(core.primary.get_argb(0), Argb(0xff000000));
+        assert_eq!(core.secondary.get_argb(100), Argb(0xffffffff));
+        assert_eq!(core.secondary.get_argb(95), Argb(0xfff1efff));
+        assert_eq!(core.secondary.get_argb(90), Argb(0xffe1e0f9));
+        assert_eq!(core.secondary.get_argb(80), Argb(0xffc5c4dd));
+        assert_eq!(core.secondary.get_argb(70), Argb(0xffa9a9c1));
+        assert_eq!(core.secondary.get_argb(60), Argb(0xff8f8fa6));
+        assert_eq!(core.secondary.get_argb(50), Argb(0xff75758b));
+        assert_eq!(core.secondary.get_argb(40), Argb(0xff5c5d72));
+        assert_eq!(core.secondary.get_argb(30), Argb(0xff444559));
+        assert_eq!(core.secondary.get_argb(20), Argb(0xff2e2f42));
+        assert_eq!(core.secondary.get_argb(10), Argb(0xff191a2c));
+        assert_eq!(core.secondary.get_argb(0), Argb(0xff000000));
     }
 
     #[test]
     fn content_of_blue() {
-        let mut core = CorePalette::content_of(0xff0000ff);
-        assert_eq!(core.primary.get(100), 0xffffffff);
-        assert_eq!(core.primary.get(95), 0xfff1efff);
-        assert_eq!(core.primary.get(90), 0xffe0e0ff);
-        assert_eq!(core.primary.get(80), 0xffbec2ff);
-        assert_eq!(core.primary.get(70), 0xff9da3ff);
-        assert_eq!(core.primary.get(60), 0xff7c84ff);
-        assert_eq!(core.primary.get(50), 0xff5a64ff);
-        assert_eq!(core.primary.get(40), 0xff343dff);
-        assert_eq!(core.primary.get(30), 0xff0000ef);
-        assert_eq!(core.primary.get(20), 0xff0001ac);
-        assert_eq!(core.primary.get(10), 0xff00006e);
-        assert_eq!(core.primary.get(0), 0xff000000);
-        assert_eq!(core.secondary.get(100), 0xffffffff);
-        assert_eq!(core.secondary.get(95), 0xfff1efff);
-        assert_eq!(core.secondary.get(90), 0xffe0e0ff);
-        assert_eq!(core.secondary.get(80), 0xffc1c3f4);
-        assert_eq!(core.secondary.get(70), 0xffa5a7d7);
-        assert_eq!(core.secondary.get(60), 0xff8b8dbb);
-        assert_eq!(core.secondary.get(50), 0xff7173a0);
-        assert_eq!(core.secondary.get(40), 0xff585b86);
-        assert_eq!(core.secondary.get(30), 0xff40436d);
-        assert_eq!(core.secondary.get(20), 0xff2a2d55);
-        assert_eq!(core.secondary.get(10), 0xff14173f);
-        assert_eq!(core.secondary.get(0), 0xff000000);
+        let core = CorePalette::content_from_argb(Argb(0xff0000ff));
+        assert_eq!(core.primary.get_argb(100), Argb(0xffffffff));
+        assert_eq!(core.primary.get_argb(95), Argb(0xfff1efff));
+        assert_eq!(core.primary.get_argb(90), Argb(0xffe0e0ff));
+        assert_eq!(core.primary.get_argb(80), Argb(0xffbec2ff));
+        assert_eq!(core.primary.get_argb(70), Argb(0xff9da3ff));
+        assert_eq!(core.primary.get_argb(60), Argb(0xff7c84ff));
+        assert_eq!(core.primary.get_argb(50), Argb(0xff5a64ff));
+        assert_eq!(core.primary.get_argb(40), Argb(0xff343dff));
+        assert_eq!(core.primary.get_argb(30), Argb(0xff0000ef));
+        assert_eq!(core.primary.get_argb(20), Argb(0xff0001ac));
+        assert_eq!(core.primary.get_argb(10), Argb(0xff00006e));
+        assert_eq!(core.primary.get_argb(0), Argb(0xff000000));
+        assert_eq!(core.secondary.get_argb(100), Argb(0xffffffff));
+        assert_eq!(core.secondary.get_argb(95), Argb(0xfff1efff));
+        assert_eq!(core.secondary.get_argb(90), Argb(0xffe0e0ff));
+        assert_eq!(core.secondary.get_argb(80), Argb(0xffc1c3f4));
+        assert_eq!(core.secondary.get_argb(70), Argb(0xffa5a7d7));
+        assert_eq!(core.secondary.get_argb(60), Argb(0xff8b8dbb));
+        assert_eq!(core.secondary.get_argb(50), Argb(0xff7173a0));
+        assert_eq!(core.secondary.get_argb(40), Argb(0xff585b86));
+        assert_eq!(core.secondary.get_argb(30), Argb(0xff40436d));
+        assert_eq!(core.secondary.get_argb(20), Argb(0xff2a2d55));
+        assert_eq!(core.secondary.get_argb(10), Argb(0xff14173f));
+        assert_eq!(core.secondary.get_argb(0), Argb(0xff000000));
     }
 }
@@ -0,0 +1,163 @@
+use crate::{
+    error::error::Error,
+    quantize::{
+        pixel_buffer::{PixelBuffer, PixelFormat},
+        pixel_histogram::PixelHistogram,
+        quantizer::Quantizer,
+        quantizer_celebi::QuantizerCelebi,
+        quantizer_map::QuantizerMap,
+        quantizer_wsmeans::{QuantizerWsmeans, WsmeansOptions},
+        quantizer_wu::QuantizerWu,
+    },
+    utils::argb::Argb,
+};
+
+/// A gradient of [width] by [height] pixels, with translucent pixels on the
+/// diagonal.
+fn pixels(width: usize, height: usize) -> Vec<Argb> {
+    return (0..width * height)
+        .map(|i| {
+            let (x, y) = (i % width, i / width);
+            let alpha = if x == y { 128 } else { 255 };
+            Argb::new(alpha, (x * 17) as u8, (y * 29) as u8, ((x + y) * 7) as u8)
+        })
+        .collect();
+}
+
+/// [pixels] in [format], with [padding] bytes after each row.
+fn encode(pixels: &[Argb], width: usize, format: PixelFormat, padding: usize) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for row in pixels.chunks(width) {
+        for p in row {
+            match format {
+                PixelFormat::Rgba8 => bytes.extend([p.red(), p.green(), p.blue(), p.alpha()]),
+                PixelFormat::Bgra8 => bytes.extend([p.blue(), p.green(), p.red(), p.alpha()]),
+                PixelFormat::Rgb8 => bytes.extend([p.red(), p.green(), p.blue()]),
+            }
+        }
+        bytes.extend(vec![0xAB; padding]);
+    }
+    return bytes;
+}
+
+#[test]
+fn reads_formats() {
+    let expected = pixels(7, 5);
+    for format in [PixelFormat::Rgba8, PixelFormat::Bgra8] {
+        let bytes = encode(&expected, 7, format, 0);
+        let buffer = PixelBuffer::new(&bytes, format, 7, None).unwrap();
+        assert_eq!(buffer.height(), 5);
+        assert_eq!(buffer.len(), 35);
+        assert_eq!(buffer.pixels().collect::<Vec<Argb>>(), expected);
+    }
+    let bytes = encode(&expected, 7, PixelFormat::Rgb8, 0);
+    let buffer = PixelBuffer::new(&bytes, PixelFormat::Rgb8, 7, None).unwrap();
+    let opaque: Vec<Argb> = expected.iter().map(|p| Argb(p.0 | 0xff000000)).collect();
+    assert_eq!(buffer.pixels().collect::<Vec<Argb>>(), opaque);
+}
+
+#[test]
+fn skips_row_padding() {
+    let expected = pixels(5, 4);
+    let mut bytes = encode(&expected, 5, PixelFormat::Bgra8, 12);
+    let buffer = PixelBuffer::new(&bytes, PixelFormat::Bgra8, 5, Some(32)).unwrap();
+    assert_eq!(buffer.pixels().collect::<Vec<Argb>>(), expected);
+
+    // The last row doesn't need its padding.
+    bytes.truncate(bytes.len() - 12);
+    let buffer = PixelBuffer::new(&bytes, PixelFormat::Bgra8, 5, Some(32)).unwrap();
+    assert_eq!(buffer.height(), 4);
+    assert_eq!(buffer.pixels().collect::<Vec<Argb>>(), expected);
+}
+
+#[test]
+fn rejects_invalid_layouts() {
+    let bytes = [0u8; 40];
+    assert_eq!(
+        PixelBuffer::new(&bytes, PixelFormat::Rgba8, 5, Some(16)),
+        Err(Error::InvalidStride {
+            stride: 16,
+            row_bytes: 20
+        })
+    );
+    assert_eq!(
+        PixelBuffer::new(&bytes[..30], PixelFormat::Rgba8, 5, None),
+        Err(Error::InvalidLength {
+            expected: 40,
+            actual: 30
+        })
+    );
+    assert!(PixelBuffer::new(&bytes, PixelFormat::Rgb8, 0, None).is_err());
+    assert!(PixelBuffer::new(&[], PixelFormat::Rgb8, 4, None)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn histogram_keeps_first_appearance_order() {
+    let histogram: PixelHistogram = [Argb(3), Argb(1), Argb(3), Argb(2), Argb(1), Argb(3)]
+        .into_iter()
+        .collect();
+    assert_eq!(histogram.colors(), &[Argb(3), Argb(1), Argb(2)]);
+    assert_eq!(histogram.counts(), &[3, 2, 1]);
+    assert_eq!(histogram.count_of(Argb(1)), 2);
+    assert_eq!(histogram.count_of(Argb(4)), 0);
+    assert_eq!(histogram.pixel_count(), 6);
+}
+
+#[test]
+fn quantizes_bytes_like_pixels() {
+    let expected = pixels(40, 30);
+    let bytes = encode(&expected, 40, PixelFormat::Rgba8, 8);
+    let buffer = PixelBuffer::new(&bytes, PixelFormat::Rgba8, 40, Some(168)).unwrap();
+
+    assert_eq!(
+        QuantizerMap {}
+            .quantize_bytes(&buffer, 128, None)
+            .color_to_count,
+        QuantizerMap {}
+            .quantize(&expected, 128, None)
+            .color_to_count
+    );
+    assert_eq!(
+        QuantizerWu::new()
+            .quantize_bytes(&buffer, 128, None)
+            .color_to_count,
+        QuantizerWu::new()
+            .quantize(&expected, 128, None)
+            .color_to_count
+    );
+    let from_bytes = QuantizerCelebi {}.quantize_bytes(&buffer, 128, Some(true));
+    let from_pixels = QuantizerCelebi {}.quantize(&expected, 128, Some(true));
+    assert_eq!(from_bytes.color_to_count, from_pixels.color_to_count);
+    assert_eq!(
+        from_bytes.input_pixel_to_cluster_pixel,
+        from_pixels.input_pixel_to_cluster_pixel
+    );
+}
+
+#[test]
+fn quantizes_iterators_like_pixels() {
+    let expected = pixels(30, 30);
+    assert_eq!(
+        QuantizerCelebi {}
+            .quantize_iter(expected.iter().copied(), 16, None)
+            .color_to_count,
+        QuantizerCelebi {}
+            .quantize(&expected, 16, None)
+            .color_to_count
+    );
+    let histogram: PixelHistogram = expected.iter().copied().collect();
+    assert_eq!(
+        QuantizerWsmeans::quantize_histogram(
+            &histogram,
+            16,
+            None,
+            None,
+            &WsmeansOptions::default(),
+            None
+        )
+        .color_to_count,
+        QuantizerWsmeans::quantize_argb(&expected, 16, None, None, None, None).color_to_count
+    );
+}
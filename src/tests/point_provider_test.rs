@@ -0,0 +1,93 @@
+use assert_approx_eq::assert_approx_eq;
+
+use crate::{
+    quantize::{
+        quantizer_wsmeans::QuantizerWsmeans,
+        src::{
+            point_provider::PointProvider, point_provider_cam16_ucs::PointProviderCam16Ucs,
+            point_provider_lab::PointProviderLab, point_provider_oklab::PointProviderOklab,
+        },
+    },
+    utils::argb::Argb,
+};
+
+const COLORS: [Argb; 6] = [
+    Argb(0xffffffff),
+    Argb(0xff000000),
+    Argb(0xffff0000),
+    Argb(0xff00ff00),
+    Argb(0xff0000ff),
+    Argb(0xff4285f4),
+];
+
+fn providers() -> Vec<Box<dyn PointProvider>> {
+    return vec![
+        Box::new(PointProviderLab::new()),
+        Box::new(PointProviderCam16Ucs::new()),
+        Box::new(PointProviderOklab::new()),
+    ];
+}
+
+#[test]
+fn round_trips() {
+    for provider in providers() {
+        for color in COLORS {
+            assert_eq!(provider.to_argb(&provider.from_argb(color)), color);
+        }
+    }
+}
+
+#[test]
+fn oklab_reference_values() {
+    let provider = PointProviderOklab::new();
+    let white = provider.from_argb(Argb(0xffffffff));
+    assert_approx_eq!(white[0], 1.0, 1e-4);
+    assert_approx_eq!(white[1], 0.0, 1e-4);
+    assert_approx_eq!(white[2], 0.0, 1e-4);
+    let red = provider.from_argb(Argb(0xffff0000));
+    assert_approx_eq!(red[0], 0.62796, 1e-4);
+    assert_approx_eq!(red[1], 0.22486, 1e-4);
+    assert_approx_eq!(red[2], 0.12585, 1e-4);
+}
+
+#[test]
+fn cam16_ucs_matches_cam16() {
+    let provider = PointProviderCam16Ucs::new();
+    let point = provider.from_argb(Argb(0xff4285f4));
+    let cam = crate::hct::cam16::Cam16::from_argb(Argb(0xff4285f4));
+    assert_eq!(point, vec![cam.jstar, cam.astar, cam.bstar]);
+}
+
+#[test]
+fn distance_orders_colors() {
+    for provider in providers() {
+        let red = provider.from_argb(Argb(0xffff0000));
+        let dark_red = provider.from_argb(Argb(0xffcc0000));
+        let blue = provider.from_argb(Argb(0xff0000ff));
+        assert_eq!(provider.distance(&red, &red), 0.0);
+        assert!(provider.distance(&red, &dark_red) < provider.distance(&red, &blue));
+        assert_eq!(
+            provider.distance(&red, &blue),
+            provider.distance(&blue, &red)
+        );
+    }
+}
+
+#[test]
+fn wsmeans_clusters_with_any_provider() {
+    let mut pixels = Vec::new();
+    for i in 0..10u8 {
+        pixels.push(Argb::from_rgb(250 - i, i, i));
+        pixels.push(Argb::from_rgb(i, i, 250 - i));
+    }
+    for provider in providers() {
+        let result =
+            QuantizerWsmeans::quantize_argb(&pixels, 2, None, Some(provider.as_ref()), None, None);
+        let mut counts: Vec<i64> = result.color_to_count.values().copied().collect();
+        counts.sort();
+        assert_eq!(counts, vec![10, 10]);
+        let mut colors: Vec<Argb> = result.color_to_count.keys().copied().collect();
+        colors.sort_by_key(|color| color.red());
+        assert!(colors[0].blue() > 200 && colors[1].red() > 200);
+    }
+}
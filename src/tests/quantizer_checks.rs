@@ -0,0 +1,107 @@
+use crate::{
+    quantize::{pixel_histogram::PixelHistogram, quantizer::Quantizer},
+    score::score::Score,
+    utils::argb::Argb,
+};
+
+const RED: Argb = Argb(0xffff0000);
+const GREEN: Argb = Argb(0xff00ff00);
+const BLUE: Argb = Argb(0xff0000ff);
+const MAX_COLORS: i64 = 256;
+
+/// Checks that a quantizer from [new] keeps the colors of the fixtures in
+/// `quantizer_wu_test.rs`, which have fewer colors than requested.
+pub fn check_fixtures<Q: Quantizer>(new: impl Fn() -> Q) {
+    for color in [Argb(0xff141216), RED, GREEN, BLUE] {
+        let result = new().quantize(&[color], MAX_COLORS, None);
+        let colors: Vec<Argb> = result.color_to_count.keys().cloned().collect();
+        assert_eq!(colors, vec![color]);
+    }
+
+    let result = new().quantize(&[BLUE, BLUE, BLUE, BLUE, BLUE], MAX_COLORS, None);
+    let colors: Vec<Argb> = result.color_to_count.keys().cloned().collect();
+    assert_eq!(colors, vec![BLUE]);
+
+    let result = new().quantize(&[RED, RED, GREEN, GREEN, GREEN], MAX_COLORS, None);
+    assert_eq!(result.color_to_count.len(), 2);
+    assert!(result.color_to_count.contains_key(&RED));
+    assert!(result.color_to_count.contains_key(&GREEN));
+
+    let result = new().quantize(&[RED, GREEN, BLUE], MAX_COLORS, None);
+    assert_eq!(result.color_to_count.len(), 3);
+    assert!(result.color_to_count.contains_key(&RED));
+    assert!(result.color_to_count.contains_key(&GREEN));
+    assert!(result.color_to_count.contains_key(&BLUE));
+}
+
+/// Checks the populations and pixel-to-cluster maps every [Quantizer] returns,
+/// with a fresh quantizer from [new] for each case.
+pub fn check_quantizer<Q: Quantizer>(new: impl Fn() -> Q) {
+    // No colors are requested.
+    let result = new().quantize(&[RED, GREEN, BLUE], 0, None);
+    assert!(result.color_to_count.is_empty());
+
+    // Populations are pixel counts.
+    let result = new().quantize(&[RED, RED, GREEN, GREEN, GREEN], MAX_COLORS, None);
+    assert_eq!(result.color_to_count.get(&RED), Some(&2));
+    assert_eq!(result.color_to_count.get(&GREEN), Some(&3));
+    assert_eq!(result.color_to_count.get(&BLUE), None);
+
+    // Populations add up to the opaque pixels.
+    let mut pixels = Vec::new();
+    for i in 0..1000u32 {
+        pixels.push(Argb::from_rgb(
+            (i * 7 % 256) as u8,
+            (i * 13 % 256) as u8,
+            (i * 29 % 256) as u8,
+        ));
+    }
+    pixels.push(Argb(0x80ff0000));
+    let result = new().quantize(&pixels, 8, None);
+    assert!(!result.color_to_count.is_empty());
+    assert!(result.color_to_count.len() <= 8);
+    assert_eq!(result.color_to_count.values().sum::<i64>(), 1000);
+
+    // Every opaque input color maps to a cluster.
+    let pixels = [RED, Argb(0xfffe0101), GREEN, BLUE, Argb(0x00ff0000)];
+    let result = new().quantize(&pixels, 3, Some(true));
+    let mapping = result.input_pixel_to_cluster_pixel;
+    assert_eq!(mapping.len(), 4);
+    for cluster in mapping.values() {
+        assert!(result.color_to_count.contains_key(cluster));
+    }
+    assert_eq!(mapping.get(&Argb(0xfffe0101)), mapping.get(&RED));
+    assert_eq!(mapping.get(&GREEN), Some(&GREEN));
+    assert_eq!(mapping.get(&BLUE), Some(&BLUE));
+    assert!(!mapping.contains_key(&Argb(0x00ff0000)));
+
+    // The map is only built when asked for.
+    let result = new().quantize(&[RED, GREEN], MAX_COLORS, None);
+    assert!(result.input_pixel_to_cluster_pixel.is_empty());
+
+    // Populations can be scored.
+    let mut pixels = vec![BLUE; 90];
+    pixels.extend(vec![RED; 10]);
+    let result = new().quantize(&pixels, MAX_COLORS, None);
+    let ranked = Score::score_argb(&result.color_to_count, Some(2), None, None);
+    assert_eq!(ranked[0], BLUE);
+
+    // A histogram quantizes the same as its pixels.
+    let pixels: Vec<Argb> = (0..500u32)
+        .map(|i| {
+            Argb::from_rgb(
+                (i * 3 % 256) as u8,
+                (i * 5 % 256) as u8,
+                (i * 11 % 256) as u8,
+            )
+        })
+        .collect();
+    let histogram: PixelHistogram = pixels.iter().copied().collect();
+    let from_histogram = new().quantize_histogram(&histogram, 16, Some(true));
+    let from_pixels = new().quantize(&pixels, 16, Some(true));
+    assert_eq!(from_histogram.color_to_count, from_pixels.color_to_count);
+    assert_eq!(
+        from_histogram.input_pixel_to_cluster_pixel,
+        from_pixels.input_pixel_to_cluster_pixel
+    );
+}
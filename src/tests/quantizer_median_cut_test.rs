@@ -0,0 +1,29 @@
+use crate::{
+    quantize::{quantizer::Quantizer, quantizer_median_cut::QuantizerMedianCut},
+    tests::quantizer_checks::{check_fixtures, check_quantizer},
+    utils::argb::Argb,
+};
+
+#[test]
+fn fixtures() {
+    check_fixtures(|| QuantizerMedianCut {});
+}
+
+#[test]
+fn quantizer() {
+    check_quantizer(|| QuantizerMedianCut {});
+}
+
+#[test]
+fn splits_on_widest_channel() {
+    // Green spans 255, red and blue only 2.
+    let low = [Argb::from_rgb(10, 0, 10), Argb::from_rgb(12, 100, 10)];
+    let high = [Argb::from_rgb(10, 200, 12), Argb::from_rgb(11, 255, 10)];
+    let pixels = [low, high].concat();
+    let result = QuantizerMedianCut {}.quantize(&pixels, 2, Some(true));
+    let mapping = result.input_pixel_to_cluster_pixel;
+    assert_eq!(result.color_to_count.len(), 2);
+    assert_eq!(mapping.get(&low[0]), mapping.get(&low[1]));
+    assert_eq!(mapping.get(&high[0]), mapping.get(&high[1]));
+    assert_ne!(mapping.get(&low[0]), mapping.get(&high[0]));
+}
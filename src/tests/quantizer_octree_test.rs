@@ -0,0 +1,38 @@
+use crate::{
+    quantize::{quantizer::Quantizer, quantizer_octree::QuantizerOctree},
+    tests::quantizer_checks::{check_fixtures, check_quantizer},
+    utils::argb::Argb,
+};
+
+#[test]
+fn fixtures() {
+    check_fixtures(|| QuantizerOctree {});
+}
+
+#[test]
+fn quantizer() {
+    check_quantizer(|| QuantizerOctree {});
+}
+
+#[test]
+fn reduction_gives_fewer_colors_than_requested() {
+    // Eight colors differing only in their last bits share a parent, so
+    // reducing it to fit 8 colors leaves 2.
+    let mut pixels: Vec<Argb> = (0..8u8)
+        .map(|bits| {
+            Argb::from_rgb(
+                128 + (bits >> 2 & 1),
+                128 + (bits >> 1 & 1),
+                128 + (bits & 1),
+            )
+        })
+        .collect();
+    pixels.push(Argb::from_rgb(0, 0, 0));
+    let result = QuantizerOctree {}.quantize(&pixels, 8, None);
+    assert_eq!(result.color_to_count.len(), 2);
+    assert_eq!(
+        result.color_to_count.get(&Argb::from_rgb(0, 0, 0)),
+        Some(&1)
+    );
+    assert_eq!(result.color_to_count.values().sum::<i64>(), 9);
+}
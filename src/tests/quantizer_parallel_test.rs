@@ -0,0 +1,130 @@
+//! Golden results of the quantizers. The sequential path and the parallel
+//! path of the `rayon` feature must both reproduce them exactly.
+
+use std::collections::HashMap;
+
+use crate::{
+    quantize::{
+        quantizer::Quantizer,
+        quantizer_celebi::QuantizerCelebi,
+        quantizer_wsmeans::QuantizerWsmeans,
+        quantizer_wu::QuantizerWu,
+        src::{
+            point_provider_cam16_ucs::PointProviderCam16Ucs,
+            point_provider_oklab::PointProviderOklab,
+        },
+    },
+    utils::argb::Argb,
+};
+
+/// A noisy 160x120 gradient, generated with a linear congruential generator
+/// so that it doesn't depend on the `rand` crate.
+fn image() -> Vec<Argb> {
+    let mut state: u32 = 0x42688;
+    let mut pixels = Vec::new();
+    for y in 0..120u32 {
+        for x in 0..160u32 {
+            state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+            let noise = (state >> 24) % 48;
+            let red = (x * 255 / 159 + noise) % 256;
+            let green = (y * 255 / 119 + noise / 2) % 256;
+            let blue = ((x + y) * 255 / 278 + (state >> 16) % 32) % 256;
+            pixels.push(Argb::from_rgb(red as u8, green as u8, blue as u8));
+        }
+    }
+    return pixels;
+}
+
+fn sorted(color_to_count: &HashMap<Argb, i64>) -> Vec<(u32, i64)> {
+    let mut entries: Vec<(u32, i64)> = color_to_count
+        .iter()
+        .map(|(color, count)| (color.0, *count))
+        .collect();
+    entries.sort();
+    return entries;
+}
+
+#[test]
+fn wu() {
+    let result = QuantizerWu::new().quantize(&image(), 16, None);
+    let expected = vec![
+        (0xff13bfe0, 770),
+        (0xff2536ad, 1054),
+        (0xff29d45f, 682),
+        (0xff2b3f38, 1867),
+        (0xff3d9860, 1228),
+        (0xff54e594, 1446),
+        (0xff66a98a, 952),
+        (0xff674356, 2216),
+        (0xffa31e7d, 1094),
+        (0xffa35f84, 1030),
+        (0xffa7d5a6, 891),
+        (0xffb599a3, 1392),
+        (0xffd0e5da, 1312),
+        (0xffdf1e9b, 1193),
+        (0xffdf5fa7, 1190),
+        (0xffe2aad0, 883),
+    ];
+    assert_eq!(sorted(&result.color_to_count), expected);
+    assert_eq!(result.color_to_count.values().sum::<i64>(), 160 * 120);
+}
+
+#[test]
+fn celebi() {
+    let result = QuantizerCelebi {}.quantize(&image(), 16, None);
+    let expected = vec![
+        (0xff1caddb, 838),
+        (0xff3d4d41, 1654),
+        (0xff49e369, 721),
+        (0xff4a2dac, 1142),
+        (0xff4aa96e, 2039),
+        (0xff58e08f, 1158),
+        (0xff593046, 1442),
+        (0xff78a38a, 1118),
+        (0xffa4306f, 1905),
+        (0xffa86287, 641),
+        (0xfface4c1, 988),
+        (0xffafa1a6, 1779),
+        (0xffcdded3, 570),
+        (0xffe0a2c6, 1095),
+        (0xffe164a9, 987),
+        (0xffe62aa9, 1123),
+    ];
+    assert_eq!(sorted(&result.color_to_count), expected);
+}
+
+#[test]
+fn wsmeans_cam16_ucs() {
+    let provider = PointProviderCam16Ucs::new();
+    let result =
+        QuantizerWsmeans::quantize_argb(&image(), 8, None, Some(&provider), Some(10), None);
+    let expected = vec![
+        (0xff3b3a91, 1281),
+        (0xff476d53, 2335),
+        (0xff593446, 2197),
+        (0xff63d299, 2299),
+        (0xff78a0a5, 1507),
+        (0xff87db9d, 2467),
+        (0xffc34d92, 4658),
+        (0xffd1b4c3, 2456),
+    ];
+    assert_eq!(sorted(&result.color_to_count), expected);
+}
+
+#[test]
+fn wsmeans_oklab() {
+    let provider = PointProviderOklab::new();
+    let result =
+        QuantizerWsmeans::quantize_argb(&image(), 8, None, Some(&provider), Some(10), None);
+    let expected = vec![
+        (0xff37325c, 2548),
+        (0xff4d7a6c, 2359),
+        (0xff5bd899, 1506),
+        (0xff66e694, 1753),
+        (0xff6dab9a, 2200),
+        (0xff7b3e5c, 1808),
+        (0xffcb539c, 4362),
+        (0xffd7c7ce, 2664),
+    ];
+    assert_eq!(sorted(&result.color_to_count), expected);
+}
@@ -0,0 +1,161 @@
+use crate::{
+    hct::hct::Hct,
+    quantize::{
+        quantizer::Quantizer,
+        quantizer_celebi::QuantizerCelebi,
+        quantizer_session::{QuantizerSession, QuantizerSessionOptions},
+    },
+    score::score::Score,
+    utils::{argb::Argb, math_utils::MathUtils},
+};
+
+/// A 40x30 frame of [red], [green] and [blue] with some noise.
+fn frame(red: u8, green: u8, blue: u8, seed: u32) -> Vec<Argb> {
+    let mut state = seed;
+    let mut noise = || {
+        state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+        return ((state >> 24) % 16) as i32 - 8;
+    };
+    let channel = |value: u8, noise: i32| (value as i32 + noise).clamp(0, 255) as u8;
+    return (0..40 * 30)
+        .map(|_| {
+            Argb::from_rgb(
+                channel(red, noise()),
+                channel(green, noise()),
+                channel(blue, noise()),
+            )
+        })
+        .collect();
+}
+
+fn blue_frame(seed: u32) -> Vec<Argb> {
+    return frame(40, 80, 200, seed);
+}
+
+fn red_frame(seed: u32) -> Vec<Argb> {
+    return frame(200, 40, 40, seed);
+}
+
+fn options() -> QuantizerSessionOptions {
+    return QuantizerSessionOptions {
+        max_colors: 16,
+        ..QuantizerSessionOptions::default()
+    };
+}
+
+#[test]
+fn first_frame_matches_celebi() {
+    let pixels = blue_frame(1);
+    let mut session = QuantizerSession::new(options());
+    assert_eq!(session.source_color(), None);
+    let source_color = session.add_frame(&pixels);
+
+    let result = QuantizerCelebi {}.quantize(&pixels, 16, None);
+    let expected = Score::score_argb(&result.color_to_count, None, None, None);
+    assert_eq!(session.populations(), &result.color_to_count);
+    assert_eq!(session.candidates(), expected.as_slice());
+    assert_eq!(source_color, expected[0]);
+    assert_eq!(session.source_color(), Some(expected[0]));
+}
+
+#[test]
+fn jitter_keeps_source_color() {
+    let mut session = QuantizerSession::new(options());
+    let source_color = session.add_frame(&blue_frame(1));
+    for seed in 2..10 {
+        let pixels = frame(
+            40 + (seed % 3) as u8 * 6,
+            80,
+            200 - (seed % 2) as u8 * 8,
+            seed,
+        );
+        assert_eq!(session.add_frame(&pixels), source_color);
+    }
+}
+
+#[test]
+fn scene_change_replaces_source_color_after_hold() {
+    let mut session = QuantizerSession::new(options());
+    let blue = session.add_frame(&blue_frame(1));
+    session.add_frame(&blue_frame(2));
+
+    assert_eq!(session.add_frame(&red_frame(3)), blue);
+    assert_eq!(session.add_frame(&red_frame(4)), blue);
+    let red = session.add_frame(&red_frame(5));
+    assert_ne!(red, blue);
+    assert!(red.red() > red.blue());
+    assert_eq!(session.add_frame(&red_frame(6)), red);
+}
+
+#[test]
+fn flash_keeps_source_color() {
+    let mut session = QuantizerSession::new(options());
+    let blue = session.add_frame(&blue_frame(1));
+    assert_eq!(session.add_frame(&red_frame(2)), blue);
+    assert_eq!(session.add_frame(&blue_frame(3)), blue);
+    assert_eq!(session.add_frame(&red_frame(4)), blue);
+    assert_eq!(session.add_frame(&blue_frame(5)), blue);
+}
+
+#[test]
+fn populations_decay() {
+    let pixel_count = 40 * 30;
+    let mut forgetful = QuantizerSession::new(QuantizerSessionOptions {
+        decay: 0.0,
+        ..options()
+    });
+    forgetful.add_frame(&blue_frame(1));
+    forgetful.add_frame(&blue_frame(2));
+    let total: i64 = forgetful.populations().values().sum();
+    assert_eq!(total, pixel_count);
+
+    let mut session = QuantizerSession::new(options());
+    session.add_frame(&blue_frame(1));
+    session.add_frame(&blue_frame(2));
+    let total: i64 = session.populations().values().sum();
+    assert!((total - pixel_count * 3 / 2).abs() <= 16, "{}", total);
+    assert!(session.populations().len() <= 16);
+}
+
+#[test]
+fn transparent_frame_uses_fallback() {
+    let mut session = QuantizerSession::new(options());
+    let source_color = session.add_frame(&[Argb(0x00000000); 16]);
+    assert_eq!(source_color, Argb(0xff4285f4));
+}
+
+#[test]
+fn real_color_replaces_fallback_at_once() {
+    let mut session = QuantizerSession::new(options());
+    let fallback = session.add_frame(&[Argb(0x00000000); 16]);
+    assert_eq!(fallback, Argb(0xff4285f4));
+    let blue = session.add_frame(&blue_frame(1));
+    assert_ne!(blue, fallback);
+    assert!(session.populations().contains_key(&blue));
+    assert_eq!(session.add_frame(&blue_frame(2)), blue);
+}
+
+#[test]
+fn chroma_change_replaces_source_color_after_hold() {
+    let mut session = QuantizerSession::new(options());
+    let muted_blue = session.add_frame(&frame(60, 70, 120, 1));
+
+    assert_eq!(session.add_frame(&blue_frame(2)), muted_blue);
+    assert_eq!(session.add_frame(&blue_frame(3)), muted_blue);
+    let blue = session.add_frame(&blue_frame(4));
+    assert_ne!(blue, muted_blue);
+    let (blue, muted_blue) = (Hct::from_argb(blue), Hct::from_argb(muted_blue));
+    assert!(MathUtils::difference_degrees(blue.get_hue(), muted_blue.get_hue()) < 15.0);
+    assert!(blue.get_chroma() > muted_blue.get_chroma() + 20.0);
+}
+
+#[test]
+fn reset_forgets_frames() {
+    let mut session = QuantizerSession::new(options());
+    let blue = session.add_frame(&blue_frame(1));
+    session.reset();
+    assert_eq!(session.source_color(), None);
+    assert!(session.populations().is_empty());
+    let red = session.add_frame(&red_frame(2));
+    assert_ne!(red, blue);
+}
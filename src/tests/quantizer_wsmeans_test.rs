@@ -1,24 +1,33 @@
-use crate::quantize::quantizer_wsmeans::QuantizerWsmeans;
+use std::collections::HashMap;
 
-const RED: i64 = 0xffff0000;
-const GREEN: i64 = 0xff00ff00;
-const BLUE: i64 = 0xff0000ff;
-// const WHITE: i64 = 0xffffffff;
-// const RANDOM: i64 = 0xff426088;
+use crate::{
+    quantize::{
+        quantizer::Quantizer,
+        quantizer_celebi::QuantizerCelebi,
+        quantizer_wsmeans::{QuantizerWsmeans, WsmeansOptions},
+    },
+    utils::argb::Argb,
+};
+
+const RED: Argb = Argb(0xffff0000);
+const GREEN: Argb = Argb(0xff00ff00);
+const BLUE: Argb = Argb(0xff0000ff);
+// const WHITE: Argb = Argb(0xffffffff);
+// const RANDOM: Argb = Argb(0xff426088);
 const MAX_COLORS: i64 = 256;
 
 #[test]
 fn _1_rando() {
     let result =
-        QuantizerWsmeans::quantize(&[0xff141216].to_vec(), MAX_COLORS, None, None, None, None);
+        QuantizerWsmeans::quantize_argb(&[Argb(0xff141216)], MAX_COLORS, None, None, None, None);
     let colors = result.color_to_count;
     assert_eq!(colors.len(), 1);
-    assert_eq!(true, colors.get(&0xff141216).is_some());
+    assert_eq!(true, colors.get(&Argb(0xff141216)).is_some());
 }
 
 #[test]
 fn _1_r() {
-    let result = QuantizerWsmeans::quantize(&[RED].to_vec(), MAX_COLORS, None, None, None, None);
+    let result = QuantizerWsmeans::quantize_argb(&[RED], MAX_COLORS, None, None, None, None);
     let colors = result.color_to_count;
     assert_eq!(colors.len(), 1);
     assert_eq!(true, colors.get(&RED).is_some());
@@ -26,7 +35,7 @@ fn _1_r() {
 
 #[test]
 fn _1_g() {
-    let result = QuantizerWsmeans::quantize(&[GREEN].to_vec(), MAX_COLORS, None, None, None, None);
+    let result = QuantizerWsmeans::quantize_argb(&[GREEN], MAX_COLORS, None, None, None, None);
     let colors = result.color_to_count;
     assert_eq!(colors.len(), 1);
     assert_eq!(true, colors.get(&GREEN).is_some());
@@ -34,7 +43,7 @@ fn _1_g() {
 
 #[test]
 fn _1_b() {
-    let result = QuantizerWsmeans::quantize(&[BLUE].to_vec(), MAX_COLORS, None, None, None, None);
+    let result = QuantizerWsmeans::quantize_argb(&[BLUE], MAX_COLORS, None, None, None, None);
     let colors = result.color_to_count;
     assert_eq!(colors.len(), 1);
     assert_eq!(true, colors.get(&BLUE).is_some());
@@ -42,8 +51,8 @@ fn _1_b() {
 
 #[test]
 fn _5_b() {
-    let result = QuantizerWsmeans::quantize(
-        &[BLUE, BLUE, BLUE, BLUE, BLUE].to_vec(),
+    let result = QuantizerWsmeans::quantize_argb(
+        &[BLUE, BLUE, BLUE, BLUE, BLUE],
         MAX_COLORS,
         None,
         None,
@@ -54,3 +63,113 @@ fn _5_b() {
     assert_eq!(colors.len(), 1);
     assert_eq!(true, colors.get(&BLUE).is_some());
 }
+
+#[test]
+fn zero_max_colors() {
+    let result =
+        QuantizerWsmeans::quantize_argb(&[RED, GREEN, BLUE], 0, None, None, None, Some(true));
+    assert!(result.color_to_count.is_empty());
+    assert!(result.input_pixel_to_cluster_pixel.is_empty());
+}
+
+/// Three groups of similar colors, of 60, 30 and 10 pixels.
+fn groups() -> Vec<Argb> {
+    let mut pixels = Vec::new();
+    for i in 0..60u8 {
+        pixels.push(Argb::from_rgb(200 + i % 20, i % 7, i % 5));
+    }
+    for i in 0..30u8 {
+        pixels.push(Argb::from_rgb(i % 3, 200 + i % 11, i % 5));
+    }
+    for i in 0..10u8 {
+        pixels.push(Argb::from_rgb(i % 3, i % 4, 200 + i));
+    }
+    return pixels;
+}
+
+fn quantize(options: &WsmeansOptions) -> HashMap<Argb, i64> {
+    return QuantizerWsmeans::quantize_with_options(&groups(), 3, None, None, options, None)
+        .color_to_count;
+}
+
+fn sorted_counts(color_to_count: &HashMap<Argb, i64>) -> Vec<i64> {
+    let mut counts: Vec<i64> = color_to_count.values().copied().collect();
+    counts.sort();
+    return counts;
+}
+
+#[test]
+fn default_options_match_quantize() {
+    let pixels = groups();
+    assert_eq!(
+        quantize(&WsmeansOptions::default()),
+        QuantizerWsmeans::quantize_argb(&pixels, 3, None, None, None, None).color_to_count
+    );
+    assert_eq!(
+        QuantizerCelebi {}
+            .quantize_with_options(&pixels, 8, &WsmeansOptions::default(), None)
+            .color_to_count,
+        QuantizerCelebi {}.quantize(&pixels, 8, None).color_to_count
+    );
+}
+
+#[test]
+fn seed_is_reproducible() {
+    let options = WsmeansOptions {
+        seed: 7,
+        max_iterations: 1,
+        ..WsmeansOptions::default()
+    };
+    assert_eq!(quantize(&options), quantize(&options));
+    let other_seeds: Vec<HashMap<Argb, i64>> = (8..16)
+        .map(|seed| quantize(&WsmeansOptions { seed, ..options }))
+        .collect();
+    assert!(other_seeds
+        .iter()
+        .any(|result| *result != quantize(&options)));
+}
+
+#[test]
+fn kmeans_plus_plus_finds_every_group() {
+    for seed in 0..8 {
+        let options = WsmeansOptions {
+            seed,
+            kmeans_plus_plus: true,
+            max_iterations: 10,
+            ..WsmeansOptions::default()
+        };
+        assert_eq!(sorted_counts(&quantize(&options)), vec![10, 30, 60]);
+    }
+}
+
+#[test]
+fn kmeans_plus_plus_keeps_starting_clusters() {
+    let options = WsmeansOptions {
+        kmeans_plus_plus: true,
+        max_iterations: 10,
+        ..WsmeansOptions::default()
+    };
+    let result = QuantizerWsmeans::quantize_with_options(
+        &groups(),
+        3,
+        Some(&[Argb::from_rgb(0, 0, 255)]),
+        None,
+        &options,
+        None,
+    );
+    assert_eq!(sorted_counts(&result.color_to_count), vec![10, 30, 60]);
+}
+
+#[test]
+fn convergence_threshold_stops_early() {
+    let one_iteration = WsmeansOptions {
+        max_iterations: 1,
+        ..WsmeansOptions::default()
+    };
+    let converged = WsmeansOptions {
+        max_iterations: 100,
+        convergence_threshold: f64::MAX,
+        ..WsmeansOptions::default()
+    };
+    assert_eq!(quantize(&converged), quantize(&one_iteration));
+}
@@ -1,34 +1,38 @@
-use crate::quantize::{quantizer::Quantizer, quantizer_wu::QuantizerWu};
+use crate::{
+    quantize::{quantizer::Quantizer, quantizer_wu::QuantizerWu},
+    tests::quantizer_checks::check_quantizer,
+    utils::argb::Argb,
+};
 
-const RED: i64 = 0xffff0000;
-const GREEN: i64 = 0xff00ff00;
-const BLUE: i64 = 0xff0000ff;
-// const WHITE: i64 = 0xffffffff;
-// const RANDOM: i64 = 0xff426088;
+const RED: Argb = Argb(0xffff0000);
+const GREEN: Argb = Argb(0xff00ff00);
+const BLUE: Argb = Argb(0xff0000ff);
+// const WHITE: Argb = Argb(0xffffffff);
+// const RANDOM: Argb = Argb(0xff426088);
 const MAX_COLORS: i64 = 256;
 
 #[test]
 fn _1_rando() {
     let mut wu = QuantizerWu::new();
-    let result = wu.quantize(&[0xff141216].to_vec(), MAX_COLORS, None);
-    let colors: Vec<i64> = result.color_to_count.keys().cloned().collect();
+    let result = wu.quantize(&[Argb(0xff141216)], MAX_COLORS, None);
+    let colors: Vec<Argb> = result.color_to_count.keys().cloned().collect();
     assert_eq!(colors.len(), (1));
-    assert_eq!(colors[0], (0xff141216));
+    assert_eq!(colors[0], (Argb(0xff141216)));
 }
 
 #[test]
 fn _1r() {
     let mut wu = QuantizerWu::new();
-    let result = wu.quantize(&[RED].to_vec(), MAX_COLORS, None);
-    let colors: Vec<i64> = result.color_to_count.keys().cloned().collect();
+    let result = wu.quantize(&[RED], MAX_COLORS, None);
+    let colors: Vec<Argb> = result.color_to_count.keys().cloned().collect();
     assert_eq!(colors.len(), (1));
     assert_eq!(colors[0], (RED));
 }
 #[test]
 fn _1g() {
     let mut wu = QuantizerWu::new();
-    let result = wu.quantize(&[GREEN].to_vec(), MAX_COLORS, None);
-    let colors: Vec<i64> = result.color_to_count.keys().cloned().collect();
+    let result = wu.quantize(&[GREEN], MAX_COLORS, None);
+    let colors: Vec<Argb> = result.color_to_count.keys().cloned().collect();
     assert_eq!(colors.len(), (1));
     assert_eq!(colors[0], (GREEN));
 }
@@ -36,8 +40,8 @@ fn _1g() {
 #[test]
 fn _1b() {
     let mut wu = QuantizerWu::new();
-    let result = wu.quantize(&[BLUE].to_vec(), MAX_COLORS, None);
-    let colors: Vec<i64> = result.color_to_count.keys().cloned().collect();
+    let result = wu.quantize(&[BLUE], MAX_COLORS, None);
+    let colors: Vec<Argb> = result.color_to_count.keys().cloned().collect();
     assert_eq!(colors.len(), (1));
     assert_eq!(colors[0], (BLUE));
 }
@@ -45,8 +49,8 @@ fn _1b() {
 #[test]
 fn _5b() {
     let mut wu = QuantizerWu::new();
-    let result = wu.quantize(&[BLUE, BLUE, BLUE, BLUE, BLUE].to_vec(), MAX_COLORS, None);
-    let colors: Vec<i64> = result.color_to_count.keys().cloned().collect();
+    let result = wu.quantize(&[BLUE, BLUE, BLUE, BLUE, BLUE], MAX_COLORS, None);
+    let colors: Vec<Argb> = result.color_to_count.keys().cloned().collect();
     assert_eq!(colors.len(), (1));
     assert_eq!(colors[0], (BLUE));
 }
@@ -54,7 +58,7 @@ fn _5b() {
 #[test]
 fn _2r_3g() {
     let mut wu = QuantizerWu::new();
-    let result = wu.quantize(&[RED, RED, GREEN, GREEN, GREEN].to_vec(), MAX_COLORS, None);
+    let result = wu.quantize(&[RED, RED, GREEN, GREEN, GREEN], MAX_COLORS, None);
     let colors = result.color_to_count;
     assert_eq!(colors.len(), (2));
     assert_eq!(true, colors.get(&GREEN).is_some());
@@ -64,10 +68,15 @@ fn _2r_3g() {
 #[test]
 fn _1r_1g_1b() {
     let mut wu = QuantizerWu::new();
-    let result = wu.quantize(&[RED, GREEN, BLUE].to_vec(), MAX_COLORS, None);
+    let result = wu.quantize(&[RED, GREEN, BLUE], MAX_COLORS, None);
     let colors = result.color_to_count;
     assert_eq!(colors.len(), (3));
     assert_eq!(true, colors.get(&RED).is_some());
     assert_eq!(true, colors.get(&GREEN).is_some());
     assert_eq!(true, colors.get(&BLUE).is_some());
 }
+
+#[test]
+fn quantizer() {
+    check_quantizer(QuantizerWu::new);
+}
@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use crate::{
+    error::error::Error,
+    quantize::{
+        quantizer::{Quantizer, QuantizerResult},
+        quantizer_celebi::QuantizerCelebi,
+        remapper::{Dithering, Remapper},
+        src::point_provider_cam16_ucs::PointProviderCam16Ucs,
+    },
+    utils::argb::Argb,
+};
+
+const BLACK: Argb = Argb(0xff000000);
+const WHITE: Argb = Argb(0xffffffff);
+const GRAY: Argb = Argb(0xff777777);
+const RED: Argb = Argb(0xffff0000);
+const BLUE: Argb = Argb(0xff0000ff);
+
+fn white_count(pixels: &[Argb]) -> usize {
+    return pixels.iter().filter(|pixel| **pixel == WHITE).count();
+}
+
+#[test]
+fn empty_palette() {
+    assert_eq!(Remapper::new(vec![]).err(), Some(Error::EmptyPalette));
+}
+
+#[test]
+fn palette_from_result() {
+    let result = QuantizerResult::new(HashMap::from([(RED, 2), (BLUE, 5), (WHITE, 2)]), None);
+    let remapper = Remapper::from_result(&result).unwrap();
+    assert_eq!(remapper.palette(), &[BLUE, RED, WHITE]);
+}
+
+#[test]
+fn nearest() {
+    let remapper = Remapper::new(vec![BLACK, WHITE, RED]).unwrap();
+    assert_eq!(remapper.nearest(Argb(0xff202020)), BLACK);
+    assert_eq!(remapper.nearest(Argb(0xffe0e0e0)), WHITE);
+    assert_eq!(remapper.nearest_index(Argb(0xffd01010)), 2);
+}
+
+#[test]
+fn nearest_cam16_ucs() {
+    let provider = PointProviderCam16Ucs::new();
+    let remapper = Remapper::new(vec![BLACK, WHITE, RED])
+        .unwrap()
+        .point_provider(&provider);
+    assert_eq!(remapper.nearest(Argb(0xff202020)), BLACK);
+    assert_eq!(remapper.nearest(Argb(0xffd01010)), RED);
+}
+
+#[test]
+fn indexed() {
+    let remapper = Remapper::new(vec![BLACK, WHITE]).unwrap();
+    let pixels = [Argb(0xff101010), Argb(0xfff0f0f0), WHITE, BLACK];
+    assert_eq!(remapper.indexed(&pixels, 2), vec![0, 1, 1, 0]);
+}
+
+#[test]
+fn remap_keeps_alpha() {
+    let remapper = Remapper::new(vec![BLACK, WHITE]).unwrap();
+    let pixels = [Argb(0x80f0f0f0), Argb(0x00101010)];
+    assert_eq!(
+        remapper.remap(&pixels, 2),
+        vec![Argb(0x80ffffff), Argb(0x00000000)]
+    );
+}
+
+#[test]
+fn remaps_quantized_image() {
+    let pixels: Vec<Argb> = (0..256u32)
+        .map(|i| Argb::from_rgb(i as u8, (255 - i) as u8, 128))
+        .collect();
+    let result = QuantizerCelebi {}.quantize(&pixels, 4, None);
+    let remapper = Remapper::from_result(&result).unwrap();
+    let remapped = remapper.remap(&pixels, 16);
+    assert_eq!(remapped.len(), pixels.len());
+    for pixel in remapped {
+        assert!(result.color_to_count.contains_key(&pixel));
+    }
+}
+
+#[test]
+fn without_dithering_gray_is_solid() {
+    let pixels = vec![GRAY; 16 * 16];
+    let remapper = Remapper::new(vec![BLACK, WHITE]).unwrap();
+    let remapped = remapper.remap(&pixels, 16);
+    assert!(remapped.iter().all(|pixel| *pixel == remapped[0]));
+}
+
+#[test]
+fn dithering_mixes_gray() {
+    let pixels = vec![GRAY; 16 * 16];
+    for dithering in [
+        Dithering::FloydSteinberg,
+        Dithering::Atkinson,
+        Dithering::Ordered,
+    ] {
+        let remapper = Remapper::new(vec![BLACK, WHITE])
+            .unwrap()
+            .dithering(dithering);
+        let remapped = remapper.remap(&pixels, 16);
+        let whites = white_count(&remapped);
+        assert!(whites > 64 && whites < 192, "{:?}: {}", dithering, whites);
+    }
+}
+
+#[test]
+fn floyd_steinberg_keeps_average() {
+    let pixels = vec![GRAY; 32 * 32];
+    let remapper = Remapper::new(vec![BLACK, WHITE])
+        .unwrap()
+        .dithering(Dithering::FloydSteinberg);
+    let whites = white_count(&remapper.remap(&pixels, 32)) as f64;
+    let expected = 0x77 as f64 / 255.0 * 1024.0;
+    assert!((whites - expected).abs() < 16.0, "{}", whites);
+}
+
+#[test]
+fn dithering_keeps_palette_colors() {
+    let pixels: Vec<Argb> = (0..64)
+        .map(|i| if i % 3 == 0 { RED } else { BLUE })
+        .collect();
+    for dithering in [
+        Dithering::FloydSteinberg,
+        Dithering::Atkinson,
+        Dithering::Ordered,
+    ] {
+        let remapper = Remapper::new(vec![RED, BLUE]).unwrap().dithering(dithering);
+        assert_eq!(remapper.remap(&pixels, 8), pixels, "{:?}", dithering);
+    }
+}
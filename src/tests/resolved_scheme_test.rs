@@ -0,0 +1,61 @@
+use crate::{
+    dynamiccolor::{color_role::ColorRole, dynamic_scheme::DynamicScheme},
+    hct::hct::Hct,
+    scheme::{
+        scheme_content::SchemeContent, scheme_expressive::SchemeExpressive,
+        scheme_fidelity::SchemeFidelity, scheme_fruit_salad::SchemeFruitSalad,
+        scheme_monochrome::SchemeMonochrome, scheme_neutral::SchemeNeutral,
+        scheme_rainbow::SchemeRainbow, scheme_tonal_spot::SchemeTonalSpot,
+        scheme_vibrant::SchemeVibrant,
+    },
+    utils::argb::Argb,
+};
+
+fn schemes(hct: Hct, is_dark: bool, contrast_level: f64) -> Vec<DynamicScheme> {
+    return [
+        SchemeContent::new(hct.clone(), is_dark, contrast_level).dynamic_scheme,
+        SchemeExpressive::new(hct.clone(), is_dark, contrast_level).dynamic_scheme,
+        SchemeFidelity::new(hct.clone(), is_dark, contrast_level).dynamic_scheme,
+        SchemeFruitSalad::new(hct.clone(), is_dark, contrast_level).dynamic_scheme,
+        SchemeMonochrome::new(hct.clone(), is_dark, contrast_level).dynamic_scheme,
+        SchemeNeutral::new(hct.clone(), is_dark, contrast_level).dynamic_scheme,
+        SchemeRainbow::new(hct.clone(), is_dark, contrast_level).dynamic_scheme,
+        SchemeTonalSpot::new(hct.clone(), is_dark, contrast_level).dynamic_scheme,
+        SchemeVibrant::new(hct, is_dark, contrast_level).dynamic_scheme,
+    ]
+    .into();
+}
+
+#[test]
+fn matches_resolving_each_role() {
+    for argb in [0xff0000ff, 0xffff0000, 0xff00ff00, 0xffbca6ab, 0xff000000] {
+        for is_dark in [false, true] {
+            for contrast_level in [-1.0, -0.5, 0.0, 0.5, 1.0] {
+                for scheme in schemes(Hct::from_argb(Argb(argb)), is_dark, contrast_level) {
+                    let resolved = scheme.resolve();
+                    for role in ColorRole::ALL {
+                        assert_eq!(
+                            resolved.get(role),
+                            scheme.get(role),
+                            "{} differs for {:?}",
+                            role,
+                            scheme.variant
+                        );
+                        assert_eq!(resolved.get_hct(role).to_argb(), resolved.get(role));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn iter_covers_every_role_in_order() {
+    let scheme = SchemeTonalSpot::new(Hct::from_argb(Argb(0xff4285f4)), true, 0.0).dynamic_scheme;
+    let resolved = scheme.resolve();
+    let roles: Vec<ColorRole> = resolved.iter().map(|(role, _)| role).collect();
+    assert_eq!(roles, ColorRole::ALL);
+    for (role, argb) in resolved.iter() {
+        assert_eq!(argb, scheme.get(role));
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{
+    dynamiccolor::{color_role::ColorRole, dynamic_scheme::DynamicScheme, variant::Variant},
+    hct::hct::Hct,
+    palettes::tonal_palette::TonalPalette,
+    scheme::{
+        scheme_builder::SchemeBuilder, scheme_content::SchemeContent,
+        scheme_expressive::SchemeExpressive, scheme_fidelity::SchemeFidelity,
+        scheme_fruit_salad::SchemeFruitSalad, scheme_monochrome::SchemeMonochrome,
+        scheme_neutral::SchemeNeutral, scheme_rainbow::SchemeRainbow,
+        scheme_tonal_spot::SchemeTonalSpot, scheme_vibrant::SchemeVibrant,
+    },
+    utils::argb::Argb,
+};
+
+fn source() -> Hct {
+    return Hct::from_argb(Argb(0xff4285f4));
+}
+
+#[test]
+fn from_variant_matches_scheme_structs() {
+    let expected = [
+        SchemeMonochrome::new(source(), true, 0.5).dynamic_scheme,
+        SchemeNeutral::new(source(), true, 0.5).dynamic_scheme,
+        SchemeTonalSpot::new(source(), true, 0.5).dynamic_scheme,
+        SchemeVibrant::new(source(), true, 0.5).dynamic_scheme,
+        SchemeExpressive::new(source(), true, 0.5).dynamic_scheme,
+        SchemeContent::new(source(), true, 0.5).dynamic_scheme,
+        SchemeFidelity::new(source(), true, 0.5).dynamic_scheme,
+        SchemeRainbow::new(source(), true, 0.5).dynamic_scheme,
+        SchemeFruitSalad::new(source(), true, 0.5).dynamic_scheme,
+    ];
+    for scheme in expected {
+        let variant = scheme.variant.clone();
+        assert!(DynamicScheme::from_variant(variant.clone(), source(), true, 0.5) == scheme);
+        assert!(variant.build(source(), true, 0.5) == scheme);
+    }
+}
+
+#[test]
+fn builder_defaults_to_light_tonal_spot() {
+    let scheme = SchemeBuilder::new(source()).build();
+    assert!(scheme == SchemeTonalSpot::new(source(), false, 0.0).dynamic_scheme);
+}
+
+#[test]
+fn builder_sets_variant_mode_and_contrast() {
+    let scheme = SchemeBuilder::new(source())
+        .variant(Variant::Vibrant)
+        .is_dark(true)
+        .contrast_level(1.0)
+        .build();
+    assert!(scheme == SchemeVibrant::new(source(), true, 1.0).dynamic_scheme);
+}
+
+#[test]
+fn builder_overrides_palettes() {
+    let scheme = SchemeBuilder::new(source())
+        .primary_palette(TonalPalette::of(120.0, 40.0))
+        .tertiary_palette(TonalPalette::of(300.0, 20.0))
+        .error_palette(TonalPalette::of(60.0, 50.0))
+        .success_palette(TonalPalette::of(150.0, 30.0))
+        .build();
+    let default = SchemeTonalSpot::new(source(), false, 0.0).dynamic_scheme;
+
+    assert_eq!(scheme.primary_palette, TonalPalette::of(120.0, 40.0));
+    assert_eq!(scheme.secondary_palette, default.secondary_palette);
+    assert_eq!(scheme.tertiary_palette, TonalPalette::of(300.0, 20.0));
+    assert_eq!(scheme.error_palette, TonalPalette::of(60.0, 50.0));
+    assert_eq!(scheme.success_palette, TonalPalette::of(150.0, 30.0));
+    assert_eq!(scheme.warning_palette, default.warning_palette);
+    assert_eq!(scheme.primary(), TonalPalette::of(120.0, 40.0).get_argb(40));
+    assert_eq!(scheme.error(), TonalPalette::of(60.0, 50.0).get_argb(40));
+    assert_eq!(
+        scheme.get(ColorRole::Secondary),
+        default.get(ColorRole::Secondary)
+    );
+}
@@ -15,6 +15,7 @@ use crate::{
         scheme_rainbow::SchemeRainbow, scheme_tonal_spot::SchemeTonalSpot,
         scheme_vibrant::SchemeVibrant,
     },
+    utils::argb::Argb,
 };
 
 trait _Constraint {
@@ -637,11 +638,16 @@ fn scheme_correctness_test() {
             // For each variant-contrast combination, tests across four
             // seed colors as well as two brightnesses.
 
-            for source_color_argb in [0xFF0000FF, 0xFF00FF00, 0xFFFFFF00, 0xFFFF0000] {
+            for source_color_argb in [
+                Argb(0xFF0000FF),
+                Argb(0xFF00FF00),
+                Argb(0xFFFFFF00),
+                Argb(0xFFFF0000),
+            ] {
                 for is_dark in [false, true] {
                     let s: DynamicScheme = _scheme_from_variant(
                         &variant,
-                        &Hct::from_int(source_color_argb),
+                        &Hct::from_argb(source_color_argb),
                         is_dark,
                         contrast_level,
                     );
@@ -1,11 +1,11 @@
 use crate::{
     dynamiccolor::material_dynamic_colors::MaterialDynamicColors, hct::hct::Hct,
-    scheme::scheme_monochrome::SchemeMonochrome,
+    scheme::scheme_monochrome::SchemeMonochrome, utils::argb::Argb,
 };
 
 #[test]
 fn dark_theme_monochrome_spec() {
-    let scheme = SchemeMonochrome::new(Hct::from_int(0xff0000ff), true, 0.0).dynamic_scheme;
+    let scheme = SchemeMonochrome::new(Hct::from_argb(Argb(0xff0000ff)), true, 0.0).dynamic_scheme;
     assert_approx_eq::assert_approx_eq!(
         MaterialDynamicColors::primary().get_hct(&scheme).get_tone(),
         100.0,
@@ -92,7 +92,7 @@ fn dark_theme_monochrome_spec() {
 
 #[test]
 fn light_theme_monochrome_spec() {
-    let scheme = SchemeMonochrome::new(Hct::from_int(0xff0000ff), false, 0.0).dynamic_scheme;
+    let scheme = SchemeMonochrome::new(Hct::from_argb(Argb(0xff0000ff)), false, 0.0).dynamic_scheme;
     assert_approx_eq::assert_approx_eq!(
         MaterialDynamicColors::primary().get_hct(&scheme).get_tone(),
         0.0,
@@ -1,245 +1,514 @@
 use std::collections::HashMap;
 
-use crate::score::score::Score;
+use crate::{
+    score::score::{
+        HueFilter, HueRange, Score, ScoreCutoff, ScoreDetails, ScoreOptions, ScoreOutcome,
+    },
+    utils::argb::Argb,
+};
 
 #[test]
 fn prioritizes_chroma() {
-    let mut colors_to_population: HashMap<i64, i64> = HashMap::new();
-    colors_to_population.insert(0xff000000, 1);
-    colors_to_population.insert(0xffffffff, 1);
-    colors_to_population.insert(0xff0000ff, 1);
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xff000000), 1);
+    colors_to_population.insert(Argb(0xffffffff), 1);
+    colors_to_population.insert(Argb(0xff0000ff), 1);
 
-    let ranked = Score::score(&colors_to_population, Some(4), None, None);
+    let ranked = Score::score_argb(&colors_to_population, Some(4), None, None);
 
     assert_eq!(ranked.len(), (1));
-    assert_eq!(ranked[0], (0xff0000ff));
+    assert_eq!(ranked[0], (Argb(0xff0000ff)));
 }
 
 #[test]
 fn prioritizes_chroma_when_proportions_equal() {
-    let mut colors_to_population: HashMap<i64, i64> = HashMap::new();
-    colors_to_population.insert(0xffff0000, 1);
-    colors_to_population.insert(0xff00ff00, 1);
-    colors_to_population.insert(0xff0000ff, 1);
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xffff0000), 1);
+    colors_to_population.insert(Argb(0xff00ff00), 1);
+    colors_to_population.insert(Argb(0xff0000ff), 1);
 
-    let ranked = Score::score(&colors_to_population, Some(4), None, None);
+    let ranked = Score::score_argb(&colors_to_population, Some(4), None, None);
 
     assert_eq!(ranked.len(), (3));
-    assert_eq!(ranked[0], (0xffff0000));
-    assert_eq!(ranked[1], (0xff00ff00));
-    assert_eq!(ranked[2], (0xff0000ff));
+    assert_eq!(ranked[0], (Argb(0xffff0000)));
+    assert_eq!(ranked[1], (Argb(0xff00ff00)));
+    assert_eq!(ranked[2], (Argb(0xff0000ff)));
 }
 
 #[test]
 fn generates_g_blue_when_no_colors_available() {
-    let mut colors_to_population: HashMap<i64, i64> = HashMap::new();
-    colors_to_population.insert(0xff000000, 1);
-    let ranked = Score::score(&colors_to_population, Some(4), None, None);
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xff000000), 1);
+    let ranked = Score::score_argb(&colors_to_population, Some(4), None, None);
     assert_eq!(ranked.len(), (1));
-    assert_eq!(ranked[0], (0xff4285f4));
+    assert_eq!(ranked[0], (Argb(0xff4285f4)));
 }
 
 #[test]
 fn dedupes_nearby_hues() {
-    let mut colors_to_population: HashMap<i64, i64> = HashMap::new();
-    colors_to_population.insert(0xff008772, 1); // H 180 C 42 T 50
-    colors_to_population.insert(0xff318477, 1); // H 184 C 35 T 50
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xff008772), 1); // H 180 C 42 T 50
+    colors_to_population.insert(Argb(0xff318477), 1); // H 184 C 35 T 50
 
-    let ranked = Score::score(&colors_to_population, Some(4), None, None);
+    let ranked = Score::score_argb(&colors_to_population, Some(4), None, None);
     assert_eq!(ranked.len(), (1));
-    assert_eq!(ranked[0], (0xff008772));
+    assert_eq!(ranked[0], (Argb(0xff008772)));
 }
 
 #[test]
 fn maximizes_hue_distance() {
-    let mut colors_to_population: HashMap<i64, i64> = HashMap::new();
-    colors_to_population.insert(0xff008772, 1); // H 180 C 42 T 50
-    colors_to_population.insert(0xff008587, 1); // H 198 C 50 T 50
-    colors_to_population.insert(0xff007ebc, 1); // H 245 C 50 T 50
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xff008772), 1); // H 180 C 42 T 50
+    colors_to_population.insert(Argb(0xff008587), 1); // H 198 C 50 T 50
+    colors_to_population.insert(Argb(0xff007ebc), 1); // H 245 C 50 T 50
 
-    let ranked = Score::score(&colors_to_population, Some(2), None, None);
+    let ranked = Score::score_argb(&colors_to_population, Some(2), None, None);
     assert_eq!(ranked.len(), (2));
-    assert_eq!(ranked[0], (0xff007ebc));
-    assert_eq!(ranked[1], (0xff008772));
+    assert_eq!(ranked[0], (Argb(0xff007ebc)));
+    assert_eq!(ranked[1], (Argb(0xff008772)));
 }
 
 #[test]
 fn passes_generated_scenario_one() {
-    let mut colors_to_population: HashMap<i64, i64> = HashMap::new();
-    colors_to_population.insert(0xff7ea16d, 67);
-    colors_to_population.insert(0xffd8ccae, 67);
-    colors_to_population.insert(0xff835c0d, 49);
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xff7ea16d), 67);
+    colors_to_population.insert(Argb(0xffd8ccae), 67);
+    colors_to_population.insert(Argb(0xff835c0d), 49);
 
-    let ranked = Score::score(
+    let ranked = Score::score_argb(
         &colors_to_population,
         Some(3),
-        Some(0xff8d3819),
+        Some(Argb(0xff8d3819)),
         Some(false),
     );
     assert_eq!(ranked.len(), (3));
-    assert_eq!(ranked[0], (0xff7ea16d));
-    assert_eq!(ranked[1], (0xffd8ccae));
-    assert_eq!(ranked[2], (0xff835c0d));
+    assert_eq!(ranked[0], (Argb(0xff7ea16d)));
+    assert_eq!(ranked[1], (Argb(0xffd8ccae)));
+    assert_eq!(ranked[2], (Argb(0xff835c0d)));
 }
 
 #[test]
 fn passes_generated_scenario_two() {
-    let mut colors_to_population: HashMap<i64, i64> = HashMap::new();
-    colors_to_population.insert(0xffd33881, 14);
-    colors_to_population.insert(0xff3205cc, 77);
-    colors_to_population.insert(0xff0b48cf, 36);
-    colors_to_population.insert(0xffa08f5d, 81);
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xffd33881), 14);
+    colors_to_population.insert(Argb(0xff3205cc), 77);
+    colors_to_population.insert(Argb(0xff0b48cf), 36);
+    colors_to_population.insert(Argb(0xffa08f5d), 81);
 
-    let ranked = Score::score(&colors_to_population, Some(4), Some(0xff7d772b), Some(true));
+    let ranked = Score::score_argb(
+        &colors_to_population,
+        Some(4),
+        Some(Argb(0xff7d772b)),
+        Some(true),
+    );
     assert_eq!(ranked.len(), (3));
-    assert_eq!(ranked[0], (0xff3205cc));
-    assert_eq!(ranked[1], (0xffa08f5d));
-    assert_eq!(ranked[2], (0xffd33881));
+    assert_eq!(ranked[0], (Argb(0xff3205cc)));
+    assert_eq!(ranked[1], (Argb(0xffa08f5d)));
+    assert_eq!(ranked[2], (Argb(0xffd33881)));
 }
 
 #[test]
 fn passes_generated_scenario_three() {
-    let mut colors_to_population: HashMap<i64, i64> = HashMap::new();
-    colors_to_population.insert(0xffbe94a6, 23);
-    colors_to_population.insert(0xffc33fd7, 42);
-    colors_to_population.insert(0xff899f36, 90);
-    colors_to_population.insert(0xff94c574, 82);
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xffbe94a6), 23);
+    colors_to_population.insert(Argb(0xffc33fd7), 42);
+    colors_to_population.insert(Argb(0xff899f36), 90);
+    colors_to_population.insert(Argb(0xff94c574), 82);
 
-    let ranked = Score::score(&colors_to_population, Some(3), Some(0xffaa79a4), Some(true));
+    let ranked = Score::score_argb(
+        &colors_to_population,
+        Some(3),
+        Some(Argb(0xffaa79a4)),
+        Some(true),
+    );
     assert_eq!(ranked.len(), (3));
-    assert_eq!(ranked[0], (0xff94c574));
-    assert_eq!(ranked[1], (0xffc33fd7));
-    assert_eq!(ranked[2], (0xffbe94a6));
+    assert_eq!(ranked[0], (Argb(0xff94c574)));
+    assert_eq!(ranked[1], (Argb(0xffc33fd7)));
+    assert_eq!(ranked[2], (Argb(0xffbe94a6)));
 }
 
 #[test]
 fn passes_generated_scenario_four() {
-    let mut colors_to_population: HashMap<i64, i64> = HashMap::new();
-    colors_to_population.insert(0xffdf241c, 85);
-    colors_to_population.insert(0xff685859, 44);
-    colors_to_population.insert(0xffd06d5f, 34);
-    colors_to_population.insert(0xff561c54, 27);
-    colors_to_population.insert(0xff713090, 88);
-
-    let ranked = Score::score(
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xffdf241c), 85);
+    colors_to_population.insert(Argb(0xff685859), 44);
+    colors_to_population.insert(Argb(0xffd06d5f), 34);
+    colors_to_population.insert(Argb(0xff561c54), 27);
+    colors_to_population.insert(Argb(0xff713090), 88);
+
+    let ranked = Score::score_argb(
         &colors_to_population,
         Some(5),
-        Some(0xff58c19c),
+        Some(Argb(0xff58c19c)),
         Some(false),
     );
 
     assert_eq!(ranked.len(), (2));
-    assert_eq!(ranked[0], (0xffdf241c));
-    assert_eq!(ranked[1], (0xff561c54));
+    assert_eq!(ranked[0], (Argb(0xffdf241c)));
+    assert_eq!(ranked[1], (Argb(0xff561c54)));
 }
 
 #[test]
 fn passes_generated_scenario_five() {
-    let mut colors_to_population: HashMap<i64, i64> = HashMap::new();
-    colors_to_population.insert(0xffbe66f8, 41);
-    colors_to_population.insert(0xff4bbda9, 88);
-    colors_to_population.insert(0xff80f6f9, 44);
-    colors_to_population.insert(0xffab8017, 43);
-    colors_to_population.insert(0xffe89307, 65);
-
-    let ranked = Score::score(
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xffbe66f8), 41);
+    colors_to_population.insert(Argb(0xff4bbda9), 88);
+    colors_to_population.insert(Argb(0xff80f6f9), 44);
+    colors_to_population.insert(Argb(0xffab8017), 43);
+    colors_to_population.insert(Argb(0xffe89307), 65);
+
+    let ranked = Score::score_argb(
         &colors_to_population,
         Some(3),
-        Some(0xff916691),
+        Some(Argb(0xff916691)),
         Some(false),
     );
 
     assert_eq!(ranked.len(), (3));
-    assert_eq!(ranked[0], (0xffab8017));
-    assert_eq!(ranked[1], (0xff4bbda9));
-    assert_eq!(ranked[2], (0xffbe66f8));
+    assert_eq!(ranked[0], (Argb(0xffab8017)));
+    assert_eq!(ranked[1], (Argb(0xff4bbda9)));
+    assert_eq!(ranked[2], (Argb(0xffbe66f8)));
 }
 
 #[test]
 fn passes_generated_scenario_six() {
-    let mut colors_to_population: HashMap<i64, i64> = HashMap::new();
-    colors_to_population.insert(0xff18ea8f, 93);
-    colors_to_population.insert(0xff327593, 18);
-    colors_to_population.insert(0xff066a18, 53);
-    colors_to_population.insert(0xfffa8a23, 74);
-    colors_to_population.insert(0xff04ca1f, 62);
-
-    let ranked = Score::score(
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xff18ea8f), 93);
+    colors_to_population.insert(Argb(0xff327593), 18);
+    colors_to_population.insert(Argb(0xff066a18), 53);
+    colors_to_population.insert(Argb(0xfffa8a23), 74);
+    colors_to_population.insert(Argb(0xff04ca1f), 62);
+
+    let ranked = Score::score_argb(
         &colors_to_population,
         Some(2),
-        Some(0xff4c377a),
+        Some(Argb(0xff4c377a)),
         Some(false),
     );
 
     assert_eq!(ranked.len(), (2));
-    assert_eq!(ranked[0], (0xff18ea8f));
-    assert_eq!(ranked[1], (0xfffa8a23));
+    assert_eq!(ranked[0], (Argb(0xff18ea8f)));
+    assert_eq!(ranked[1], (Argb(0xfffa8a23)));
 }
 
 #[test]
 fn passes_generated_scenario_seven() {
-    let mut colors_to_population: HashMap<i64, i64> = HashMap::new();
-    colors_to_population.insert(0xff2e05ed, 23);
-    colors_to_population.insert(0xff153e55, 90);
-    colors_to_population.insert(0xff9ab220, 23);
-    colors_to_population.insert(0xff153379, 66);
-    colors_to_population.insert(0xff68bcc3, 81);
-
-    let ranked = Score::score(&colors_to_population, Some(2), Some(0xfff588dc), Some(true));
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xff2e05ed), 23);
+    colors_to_population.insert(Argb(0xff153e55), 90);
+    colors_to_population.insert(Argb(0xff9ab220), 23);
+    colors_to_population.insert(Argb(0xff153379), 66);
+    colors_to_population.insert(Argb(0xff68bcc3), 81);
+
+    let ranked = Score::score_argb(
+        &colors_to_population,
+        Some(2),
+        Some(Argb(0xfff588dc)),
+        Some(true),
+    );
 
     assert_eq!(ranked.len(), (2));
-    assert_eq!(ranked[0], (0xff2e05ed));
-    assert_eq!(ranked[1], (0xff9ab220));
+    assert_eq!(ranked[0], (Argb(0xff2e05ed)));
+    assert_eq!(ranked[1], (Argb(0xff9ab220)));
 }
 
 #[test]
 fn passes_generated_scenario_eight() {
-    let mut colors_to_population: HashMap<i64, i64> = HashMap::new();
-    colors_to_population.insert(0xff816ec5, 24);
-    colors_to_population.insert(0xff6dcb94, 19);
-    colors_to_population.insert(0xff3cae91, 98);
-    colors_to_population.insert(0xff5b542f, 25);
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xff816ec5), 24);
+    colors_to_population.insert(Argb(0xff6dcb94), 19);
+    colors_to_population.insert(Argb(0xff3cae91), 98);
+    colors_to_population.insert(Argb(0xff5b542f), 25);
 
-    let ranked = Score::score(
+    let ranked = Score::score_argb(
         &colors_to_population,
         Some(1),
-        Some(0xff84b0fd),
+        Some(Argb(0xff84b0fd)),
         Some(false),
     );
 
     assert_eq!(ranked.len(), (1));
-    assert_eq!(ranked[0], (0xff3cae91));
+    assert_eq!(ranked[0], (Argb(0xff3cae91)));
 }
 
 #[test]
 fn passes_generated_scenario_nine() {
-    let mut colors_to_population: HashMap<i64, i64> = HashMap::new();
-    colors_to_population.insert(0xff206f86, 52);
-    colors_to_population.insert(0xff4a620d, 96);
-    colors_to_population.insert(0xfff51401, 85);
-    colors_to_population.insert(0xff2b8ebf, 3);
-    colors_to_population.insert(0xff277766, 59);
-
-    let ranked = Score::score(&colors_to_population, Some(3), Some(0xff02b415), Some(true));
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xff206f86), 52);
+    colors_to_population.insert(Argb(0xff4a620d), 96);
+    colors_to_population.insert(Argb(0xfff51401), 85);
+    colors_to_population.insert(Argb(0xff2b8ebf), 3);
+    colors_to_population.insert(Argb(0xff277766), 59);
+
+    let ranked = Score::score_argb(
+        &colors_to_population,
+        Some(3),
+        Some(Argb(0xff02b415)),
+        Some(true),
+    );
 
     assert_eq!(ranked.len(), (3));
-    assert_eq!(ranked[0], (0xfff51401));
-    assert_eq!(ranked[1], (0xff4a620d));
-    assert_eq!(ranked[2], (0xff2b8ebf));
+    assert_eq!(ranked[0], (Argb(0xfff51401)));
+    assert_eq!(ranked[1], (Argb(0xff4a620d)));
+    assert_eq!(ranked[2], (Argb(0xff2b8ebf)));
 }
 
 #[test]
 fn passes_generated_scenario_ten() {
-    let mut colors_to_population: HashMap<i64, i64> = HashMap::new();
-    colors_to_population.insert(0xff8b1d99, 54);
-    colors_to_population.insert(0xff27effe, 43);
-    colors_to_population.insert(0xff6f558d, 2);
-    colors_to_population.insert(0xff77fdf2, 78);
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xff8b1d99), 54);
+    colors_to_population.insert(Argb(0xff27effe), 43);
+    colors_to_population.insert(Argb(0xff6f558d), 2);
+    colors_to_population.insert(Argb(0xff77fdf2), 78);
 
-    let ranked = Score::score(&colors_to_population, Some(4), Some(0xff5e7a10), Some(true));
+    let ranked = Score::score_argb(
+        &colors_to_population,
+        Some(4),
+        Some(Argb(0xff5e7a10)),
+        Some(true),
+    );
 
     assert_eq!(ranked.len(), (3));
-    assert_eq!(ranked[0], (0xff27effe));
-    assert_eq!(ranked[1], (0xff8b1d99));
-    assert_eq!(ranked[2], (0xff6f558d));
+    assert_eq!(ranked[0], (Argb(0xff27effe)));
+    assert_eq!(ranked[1], (Argb(0xff8b1d99)));
+    assert_eq!(ranked[2], (Argb(0xff6f558d)));
+}
+
+#[test]
+fn score_with_defaults_matches_score() {
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xff8b1d99), 54);
+    colors_to_population.insert(Argb(0xff27effe), 43);
+    colors_to_population.insert(Argb(0xff6f558d), 2);
+    colors_to_population.insert(Argb(0xff77fdf2), 78);
+
+    assert_eq!(
+        Score::score_with(&colors_to_population, &ScoreOptions::default()),
+        Score::score_argb(&colors_to_population, None, None, None)
+    );
+}
+
+#[test]
+fn lower_target_chroma_prefers_muted_colors() {
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xff0000ff), 1); // H 282 C 87 T 32
+    colors_to_population.insert(Argb(0xff8a9a5b), 1); // H 124 C 32 T 61
+
+    let vivid = Score::score_argb(&colors_to_population, Some(1), None, None);
+    assert_eq!(vivid, vec![Argb(0xff0000ff)]);
+
+    let options = ScoreOptions {
+        desired: 1,
+        target_chroma: 24.0,
+        weight_chroma_above: -0.3,
+        ..ScoreOptions::default()
+    };
+    let muted = Score::score_with(&colors_to_population, &options);
+    assert_eq!(muted, vec![Argb(0xff8a9a5b)]);
+}
+
+#[test]
+fn cutoff_chroma_keeps_grays() {
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xff7a7a7c), 1); // C < 5
+
+    let ranked = Score::score_argb(&colors_to_population, Some(4), None, None);
+    assert_eq!(ranked, vec![Argb(0xff4285f4)]);
+
+    let options = ScoreOptions {
+        cutoff_chroma: 0.0,
+        ..ScoreOptions::default()
+    };
+    let ranked = Score::score_with(&colors_to_population, &options);
+    assert_eq!(ranked, vec![Argb(0xff7a7a7c)]);
+}
+
+#[test]
+fn min_hue_separation_dedupes_more_hues() {
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xff008772), 1); // H 180 C 42 T 50
+    colors_to_population.insert(Argb(0xff008587), 1); // H 198 C 50 T 50
+    colors_to_population.insert(Argb(0xff007ebc), 1); // H 245 C 50 T 50
+
+    let ranked = Score::score_argb(&colors_to_population, Some(4), None, None);
+    assert_eq!(ranked.len(), 3);
+
+    let options = ScoreOptions {
+        min_hue_separation: 30,
+        ..ScoreOptions::default()
+    };
+    let ranked = Score::score_with(&colors_to_population, &options);
+    assert_eq!(ranked, vec![Argb(0xff007ebc), Argb(0xff008772)]);
+}
+
+#[test]
+fn min_hue_separation_is_clamped_to_max() {
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xff008772), 1); // H 180 C 42 T 50
+    colors_to_population.insert(Argb(0xff008587), 1); // H 198 C 50 T 50
+    colors_to_population.insert(Argb(0xff007ebc), 1); // H 245 C 50 T 50
+
+    let options = ScoreOptions {
+        max_hue_separation: 90,
+        min_hue_separation: 120,
+        ..ScoreOptions::default()
+    };
+    let ranked = Score::score_with(&colors_to_population, &options);
+    assert_eq!(ranked, vec![Argb(0xff007ebc)]);
+}
+
+#[test]
+fn hue_filter_denies_and_allows_ranges() {
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xffff0000), 1); // H 27
+    colors_to_population.insert(Argb(0xff00ff00), 1); // H 142
+    colors_to_population.insert(Argb(0xff0000ff), 1); // H 282
+
+    let options = ScoreOptions {
+        hue_filter: Some(HueFilter::Deny(vec![HueRange::new(330.0, 60.0)])),
+        ..ScoreOptions::default()
+    };
+    let ranked = Score::score_with(&colors_to_population, &options);
+    assert_eq!(ranked, vec![Argb(0xff00ff00), Argb(0xff0000ff)]);
+
+    let options = ScoreOptions {
+        hue_filter: Some(HueFilter::Allow(vec![HueRange::new(250.0, 300.0)])),
+        ..ScoreOptions::default()
+    };
+    let ranked = Score::score_with(&colors_to_population, &options);
+    assert_eq!(ranked, vec![Argb(0xff0000ff)]);
+}
+
+#[test]
+fn hue_range_wraps_around() {
+    let range = HueRange::new(330.0, 30.0);
+    assert!(range.contains(0.0));
+    assert!(range.contains(345.0));
+    assert!(range.contains(-10.0));
+    assert!(!range.contains(180.0));
+    assert!(HueRange::new(10.0, 20.0).contains(15.0));
+    assert!(!HueRange::new(10.0, 20.0).contains(25.0));
+}
+
+fn outcome_of(details: &ScoreDetails, argb: Argb) -> ScoreOutcome {
+    return details
+        .colors
+        .iter()
+        .find(|color| color.argb == argb)
+        .unwrap()
+        .outcome;
+}
+
+#[test]
+fn score_detailed_matches_score_with() {
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xff8b1d99), 54);
+    colors_to_population.insert(Argb(0xff27effe), 43);
+    colors_to_population.insert(Argb(0xff6f558d), 2);
+    colors_to_population.insert(Argb(0xff77fdf2), 78);
+    colors_to_population.insert(Argb(0xff000000), 10);
+
+    let options = ScoreOptions::default();
+    let details = Score::score_detailed(&colors_to_population, &options);
+    assert_eq!(
+        details.ranked,
+        Score::score_with(&colors_to_population, &options)
+    );
+    assert!(!details.used_fallback);
+    assert_eq!(details.colors.len(), 5);
+    for pair in details.colors.windows(2) {
+        assert!(pair[0].score >= pair[1].score);
+    }
+    let proportions: f64 = details.colors.iter().map(|color| color.proportion).sum();
+    assert!((proportions - 1.0).abs() < 1e-9);
+    for (rank, argb) in details.ranked.iter().enumerate() {
+        assert_eq!(outcome_of(&details, *argb), ScoreOutcome::Chosen(rank));
+    }
+    for color in &details.colors {
+        assert_eq!(color.score, color.proportion_score + color.chroma_score);
+    }
+}
+
+#[test]
+fn score_detailed_explains_cutoffs() {
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xff000000), 100);
+    colors_to_population.insert(Argb(0xff0000ff), 1000);
+    colors_to_population.insert(Argb(0xffff0000), 1);
+    colors_to_population.insert(Argb(0xff00ff00), 200);
+
+    let options = ScoreOptions {
+        hue_filter: Some(HueFilter::Deny(vec![HueRange::new(120.0, 160.0)])),
+        ..ScoreOptions::default()
+    };
+    let details = Score::score_detailed(&colors_to_population, &options);
+    assert_eq!(details.ranked, vec![Argb(0xff0000ff)]);
+    assert_eq!(
+        outcome_of(&details, Argb(0xff000000)),
+        ScoreOutcome::Filtered(ScoreCutoff::Chroma)
+    );
+    assert_eq!(
+        outcome_of(&details, Argb(0xffff0000)),
+        ScoreOutcome::Filtered(ScoreCutoff::ExcitedProportion)
+    );
+    assert_eq!(
+        outcome_of(&details, Argb(0xff00ff00)),
+        ScoreOutcome::Filtered(ScoreCutoff::Hue)
+    );
+}
+
+#[test]
+fn score_detailed_explains_duplicate_hues() {
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xff008772), 1); // H 180 C 42 T 50
+    colors_to_population.insert(Argb(0xff318477), 1); // H 184 C 35 T 50
+
+    let details = Score::score_detailed(&colors_to_population, &ScoreOptions::default());
+    assert_eq!(
+        outcome_of(&details, Argb(0xff008772)),
+        ScoreOutcome::Chosen(0)
+    );
+    assert_eq!(
+        outcome_of(&details, Argb(0xff318477)),
+        ScoreOutcome::DuplicateHue
+    );
+    assert_eq!(details.hue_separation, 15);
+}
+
+#[test]
+fn score_detailed_explains_unneeded_colors() {
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xffff0000), 1);
+    colors_to_population.insert(Argb(0xff00ff00), 1);
+    colors_to_population.insert(Argb(0xff0000ff), 1);
+
+    let options = ScoreOptions {
+        desired: 1,
+        ..ScoreOptions::default()
+    };
+    let details = Score::score_detailed(&colors_to_population, &options);
+    assert_eq!(details.ranked, vec![Argb(0xffff0000)]);
+    assert_eq!(details.hue_separation, 90);
+    assert_eq!(
+        outcome_of(&details, Argb(0xff00ff00)),
+        ScoreOutcome::NotNeeded
+    );
+    assert_eq!(
+        outcome_of(&details, Argb(0xff0000ff)),
+        ScoreOutcome::NotNeeded
+    );
+}
+
+#[test]
+fn score_detailed_reports_fallback() {
+    let mut colors_to_population: HashMap<Argb, i64> = HashMap::new();
+    colors_to_population.insert(Argb(0xff000000), 1);
+
+    let details = Score::score_detailed(&colors_to_population, &ScoreOptions::default());
+    assert!(details.used_fallback);
+    assert_eq!(details.ranked, vec![Argb(0xff4285f4)]);
+    assert_eq!(
+        details.colors[0].outcome,
+        ScoreOutcome::Filtered(ScoreCutoff::Chroma)
+    );
 }
@@ -0,0 +1,136 @@
+use crate::{
+    dynamiccolor::{
+        dynamic_scheme::DynamicScheme,
+        spec_version::{Platform, SpecVersion},
+        variant::Variant,
+    },
+    hct::hct::Hct,
+    palettes::tonal_palette::TonalPalette,
+    utils::argb::Argb,
+};
+
+const VARIANTS: [Variant; 9] = [
+    Variant::Monochrome,
+    Variant::Neutral,
+    Variant::TonalSpot,
+    Variant::Vibrant,
+    Variant::Expressive,
+    Variant::Content,
+    Variant::Fidelity,
+    Variant::Rainbow,
+    Variant::FruitSalad,
+];
+
+#[cfg(feature = "spec-2025")]
+const SPEC_VERSIONS: [SpecVersion; 2] = [SpecVersion::Spec2021, SpecVersion::Spec2025];
+#[cfg(not(feature = "spec-2025"))]
+const SPEC_VERSIONS: [SpecVersion; 1] = [SpecVersion::Spec2021];
+
+#[test]
+fn defaults() {
+    let scheme = DynamicScheme::from_variant(
+        Variant::TonalSpot,
+        Hct::from_argb(Argb(0xff4285f4)),
+        false,
+        0.0,
+    );
+    assert_eq!(scheme.error_palette, DynamicScheme::default_error_palette());
+    assert_eq!(
+        scheme.success_palette,
+        DynamicScheme::default_success_palette()
+    );
+    assert_eq!(
+        scheme.warning_palette,
+        DynamicScheme::default_warning_palette()
+    );
+    assert_eq!(scheme.info_palette, DynamicScheme::default_info_palette());
+}
+
+#[test]
+fn custom_error_palette() {
+    let source = Hct::from_argb(Argb(0xff4285f4));
+    let scheme = DynamicScheme::from_variant(Variant::TonalSpot, source, false, 0.0)
+        .with_error_palette(TonalPalette::of(350.0, 60.0));
+    assert_eq!(scheme.error(), TonalPalette::of(350.0, 60.0).get_argb(40));
+    assert_eq!(
+        scheme.on_error_container(),
+        TonalPalette::of(350.0, 60.0).get_argb(10)
+    );
+}
+
+#[test]
+fn semantic_roles_follow_error_rules() {
+    for (variant, spec_version) in VARIANTS
+        .into_iter()
+        .flat_map(|variant| SPEC_VERSIONS.map(|spec_version| (variant.clone(), spec_version)))
+    {
+        for is_dark in [false, true] {
+            for contrast_level in [-1.0, 0.0, 0.5, 1.0] {
+                let scheme = DynamicScheme::from_spec(
+                    variant.clone(),
+                    Hct::from_argb(Argb(0xff4285f4)),
+                    is_dark,
+                    contrast_level,
+                    spec_version.clone(),
+                    Platform::Phone,
+                );
+                // Spec2025 schemes have their own error palettes.
+                let error_palette = scheme.error_palette.clone();
+                let scheme = scheme
+                    .with_success_palette(error_palette.clone())
+                    .with_warning_palette(error_palette.clone())
+                    .with_info_palette(error_palette);
+
+                let error = [
+                    scheme.error(),
+                    scheme.on_error(),
+                    scheme.error_container(),
+                    scheme.on_error_container(),
+                ];
+                let success = [
+                    scheme.success(),
+                    scheme.on_success(),
+                    scheme.success_container(),
+                    scheme.on_success_container(),
+                ];
+                let warning = [
+                    scheme.warning(),
+                    scheme.on_warning(),
+                    scheme.warning_container(),
+                    scheme.on_warning_container(),
+                ];
+                let info = [
+                    scheme.info(),
+                    scheme.on_info(),
+                    scheme.info_container(),
+                    scheme.on_info_container(),
+                ];
+                assert_eq!(success, error);
+                assert_eq!(warning, error);
+                assert_eq!(info, error);
+            }
+        }
+    }
+}
+
+#[test]
+fn semantic_roles_use_their_palettes() {
+    let scheme = DynamicScheme::from_variant(
+        Variant::TonalSpot,
+        Hct::from_argb(Argb(0xff4285f4)),
+        false,
+        0.0,
+    );
+    assert_eq!(
+        scheme.success_container(),
+        DynamicScheme::default_success_palette().get_argb(90)
+    );
+    assert_eq!(
+        scheme.warning_container(),
+        DynamicScheme::default_warning_palette().get_argb(90)
+    );
+    assert_eq!(
+        scheme.info_container(),
+        DynamicScheme::default_info_palette().get_argb(90)
+    );
+}
@@ -0,0 +1,230 @@
+#[cfg(feature = "spec-2025")]
+use crate::dynamiccolor::spec_version::{Platform, SpecVersion};
+use crate::{
+    dynamiccolor::{
+        color_role::ColorRole, dynamic_scheme::DynamicScheme, src::contrast_curve::ContrastCurve,
+        variant::Variant,
+    },
+    error::error::Error,
+    hct::hct::Hct,
+    palettes::{core_palette::CorePalette, tonal_palette::TonalPalette},
+    scheme::{
+        scheme_content::SchemeContent, scheme_expressive::SchemeExpressive,
+        scheme_fidelity::SchemeFidelity, scheme_fruit_salad::SchemeFruitSalad,
+        scheme_monochrome::SchemeMonochrome, scheme_neutral::SchemeNeutral,
+        scheme_rainbow::SchemeRainbow, scheme_tonal_spot::SchemeTonalSpot,
+        scheme_vibrant::SchemeVibrant,
+    },
+    utils::argb::Argb,
+};
+
+fn round_trip<T: serde::Serialize + serde::de::DeserializeOwned>(value: &T) -> T {
+    let json = serde_json::to_string(value).unwrap();
+    return serde_json::from_str(&json).unwrap();
+}
+
+fn schemes() -> Vec<DynamicScheme> {
+    let mut schemes = vec![];
+    for argb in [0xff0000ff, 0xffff0000, 0xff00ff00, 0xffbca6ab, 0xff000000] {
+        let hct = Hct::from_argb(Argb(argb));
+        for is_dark in [false, true] {
+            for contrast_level in [-1.0, 0.0, 0.5, 1.0] {
+                let (h, d, c) = (hct.clone(), is_dark, contrast_level);
+                schemes.push(SchemeContent::new(h.clone(), d, c).dynamic_scheme);
+                schemes.push(SchemeExpressive::new(h.clone(), d, c).dynamic_scheme);
+                schemes.push(SchemeFidelity::new(h.clone(), d, c).dynamic_scheme);
+                schemes.push(SchemeFruitSalad::new(h.clone(), d, c).dynamic_scheme);
+                schemes.push(SchemeMonochrome::new(h.clone(), d, c).dynamic_scheme);
+                schemes.push(SchemeNeutral::new(h.clone(), d, c).dynamic_scheme);
+                schemes.push(SchemeRainbow::new(h.clone(), d, c).dynamic_scheme);
+                schemes.push(SchemeTonalSpot::new(h.clone(), d, c).dynamic_scheme);
+                schemes.push(SchemeVibrant::new(h, d, c).dynamic_scheme);
+            }
+        }
+    }
+    return schemes;
+}
+
+#[test]
+fn argb_is_a_number() {
+    assert_eq!(
+        serde_json::to_string(&Argb(0xff0000ff)).unwrap(),
+        "4278190335"
+    );
+    assert_eq!(round_trip(&Argb(0x80123456)), Argb(0x80123456));
+}
+
+#[test]
+fn hct_round_trips() {
+    let hct = Hct::from(120.0, 40.0, 60.0);
+    let deserialized = round_trip(&hct);
+    assert_eq!(deserialized, hct);
+    assert_eq!(deserialized.get_hue(), hct.get_hue());
+    assert_eq!(deserialized.get_chroma(), hct.get_chroma());
+    assert_eq!(deserialized.get_tone(), hct.get_tone());
+}
+
+#[test]
+fn variant_is_snake_case() {
+    assert_eq!(
+        serde_json::to_string(&Variant::TonalSpot).unwrap(),
+        "\"tonal_spot\""
+    );
+    assert_eq!(round_trip(&Variant::FruitSalad), Variant::FruitSalad);
+}
+
+#[test]
+fn contrast_curve_round_trips() {
+    let curve = round_trip(&ContrastCurve::new(1.0, 4.5, 7.0, 11.0));
+    assert_eq!(curve.get(-1.0), 1.0);
+    assert_eq!(curve.get(0.0), 4.5);
+    assert_eq!(curve.get(0.5), 7.0);
+    assert_eq!(curve.get(1.0), 11.0);
+}
+
+#[test]
+fn tonal_palette_round_trips() {
+    let palette = TonalPalette::from_hct(&Hct::from_argb(Argb(0xff0000ff)));
+    let deserialized = round_trip(&palette);
+    assert_eq!(deserialized, palette);
+    assert_eq!(deserialized.key_color, palette.key_color);
+    assert_eq!(deserialized.get_argb_list(), palette.get_argb_list());
+}
+
+#[test]
+fn tonal_palette_from_list_keeps_colors() {
+    let palette =
+        TonalPalette::from_argb_list(&TonalPalette::COMMON_TONES.map(|tone| Argb(tone as u32)));
+    let json = serde_json::to_string(&palette).unwrap();
+    assert!(json.contains("\"colors\""));
+    let deserialized: TonalPalette = serde_json::from_str(&json).unwrap();
+    assert!(deserialized._is_from_cache);
+    assert_eq!(deserialized, palette);
+    assert_eq!(deserialized.get_argb_list(), palette.get_argb_list());
+}
+
+#[test]
+fn tonal_palette_keeps_serialized_fields() {
+    // Neither the key color nor the hue and chroma match what the palette
+    // would be created with.
+    let json = r#"{"hue":270.0,"chroma":36.0,"key_color":4278190080}"#;
+    let palette: TonalPalette = serde_json::from_str(json).unwrap();
+    assert_eq!(palette.hue, 270.0);
+    assert_eq!(palette.chroma, 36.0);
+    assert_eq!(palette.key_color.to_argb(), Argb(0xff000000));
+    assert!(!palette._is_from_cache);
+    assert_eq!(
+        palette.get_argb(50),
+        TonalPalette::of(270.0, 36.0).get_argb(50)
+    );
+
+    let json = format!(
+        r#"{{"hue":10.0,"chroma":20.0,"key_color":4278190080,"colors":[{}]}}"#,
+        TonalPalette::COMMON_TONES
+            .map(|tone| tone.to_string())
+            .join(",")
+    );
+    let palette: TonalPalette = serde_json::from_str(&json).unwrap();
+    assert_eq!((palette.hue, palette.chroma), (10.0, 20.0));
+    assert!(palette._is_from_cache);
+    assert_eq!(palette.get_argb(100), Argb(100));
+}
+
+#[test]
+fn tonal_palette_with_wrong_number_of_colors_errors() {
+    let json = r#"{"hue":0.0,"chroma":0.0,"key_color":4278190080,"colors":[4278190080]}"#;
+    let error = serde_json::from_str::<TonalPalette>(json).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        Error::InvalidLength {
+            expected: TonalPalette::COMMON_SIZE,
+            actual: 1
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn core_palette_round_trips() {
+    let palette = CorePalette::from_argb(Argb(0xff4285f4));
+    assert_eq!(round_trip(&palette), palette);
+}
+
+#[test]
+fn dynamic_scheme_resolves_every_role_identically() {
+    for scheme in schemes() {
+        let deserialized = round_trip(&scheme);
+        assert!(deserialized == scheme);
+        assert_eq!(deserialized.source_color_hct, scheme.source_color_hct);
+        for role in ColorRole::ALL {
+            assert_eq!(
+                deserialized.get(role),
+                scheme.get(role),
+                "{} differs for {:?}",
+                role,
+                scheme.variant
+            );
+        }
+    }
+}
+
+#[test]
+fn dynamic_scheme_from_list_palettes_resolves_every_role_identically() {
+    let core = CorePalette::from_argb(Argb(0xff6750a4));
+    let primary = TonalPalette::of(core.primary.hue, core.primary.chroma);
+    let tertiary = TonalPalette::of(core.tertiary.hue, core.tertiary.chroma);
+    let scheme = DynamicScheme::from_palettes(
+        Argb(0xff6750a4),
+        Variant::Content,
+        Some(0.5),
+        true,
+        TonalPalette::from_argb_list(&primary.get_argb_list()),
+        core.secondary,
+        TonalPalette::from_argb_list(&tertiary.get_argb_list()),
+        core.neutral,
+        core.neutral_variant,
+    );
+    let deserialized = round_trip(&scheme);
+    assert!(deserialized.primary_palette._is_from_cache);
+    for role in ColorRole::ALL {
+        assert_eq!(deserialized.get(role), scheme.get(role));
+    }
+}
+
+#[test]
+fn dynamic_scheme_without_semantic_palettes_uses_defaults() {
+    let scheme = SchemeTonalSpot::new(Hct::from_argb(Argb(0xff4285f4)), false, 0.0).dynamic_scheme;
+    let mut json = serde_json::to_value(&scheme).unwrap();
+    let object = json.as_object_mut().unwrap();
+    object.remove("success_palette");
+    object.remove("warning_palette");
+    object.remove("info_palette");
+    let deserialized: DynamicScheme = serde_json::from_value(json).unwrap();
+    assert!(deserialized == scheme);
+}
+
+#[test]
+#[cfg(feature = "spec-2025")]
+fn spec_version_and_platform_round_trip() {
+    assert_eq!(
+        serde_json::to_string(&SpecVersion::Spec2025).unwrap(),
+        "\"2025\""
+    );
+    assert_eq!(
+        serde_json::to_string(&Platform::Watch).unwrap(),
+        "\"watch\""
+    );
+    let scheme = DynamicScheme::from_spec(
+        Variant::Expressive,
+        Hct::from_argb(Argb(0xff4285f4)),
+        true,
+        0.5,
+        SpecVersion::Spec2025,
+        Platform::Watch,
+    );
+    let deserialized = round_trip(&scheme);
+    assert!(deserialized == scheme);
+    for role in ColorRole::ALL {
+        assert_eq!(deserialized.get(role), scheme.get(role));
+    }
+}
@@ -0,0 +1,326 @@
+use crate::{
+    contrast::contrast::Contrast,
+    dynamiccolor::{
+        color_role::ColorRole,
+        dynamic_scheme::DynamicScheme,
+        spec_version::{Platform, SpecVersion},
+        variant::Variant,
+    },
+    hct::hct::Hct,
+    palettes::tonal_palette::TonalPalette,
+    scheme::{scheme_builder::SchemeBuilder, scheme_tonal_spot::SchemeTonalSpot},
+    utils::argb::Argb,
+};
+
+const VARIANTS: [Variant; 9] = [
+    Variant::Monochrome,
+    Variant::Neutral,
+    Variant::TonalSpot,
+    Variant::Vibrant,
+    Variant::Expressive,
+    Variant::Content,
+    Variant::Fidelity,
+    Variant::Rainbow,
+    Variant::FruitSalad,
+];
+
+fn blue() -> Hct {
+    // Hue 266.
+    return Hct::from_argb(Argb(0xff4285f4));
+}
+
+fn spec_2025(variant: Variant, is_dark: bool, platform: Platform) -> DynamicScheme {
+    return DynamicScheme::from_spec(
+        variant,
+        blue(),
+        is_dark,
+        0.0,
+        SpecVersion::Spec2025,
+        platform,
+    );
+}
+
+fn tone(argb: Argb) -> f64 {
+    return Hct::from_argb(argb).get_tone();
+}
+
+#[test]
+fn defaults_to_2021_on_phone() {
+    let scheme = DynamicScheme::from_variant(Variant::TonalSpot, blue(), false, 0.0);
+    assert_eq!(scheme.spec_version, SpecVersion::Spec2021);
+    assert_eq!(scheme.platform, Platform::Phone);
+    assert!(scheme == SchemeTonalSpot::new(blue(), false, 0.0).dynamic_scheme);
+    assert!(SchemeBuilder::new(blue()).build() == scheme);
+}
+
+#[test]
+fn spec_2025_tonal_spot_palettes() {
+    let light = spec_2025(Variant::TonalSpot, false, Platform::Phone);
+    let dark = spec_2025(Variant::TonalSpot, true, Platform::Phone);
+    let watch = spec_2025(Variant::TonalSpot, true, Platform::Watch);
+    assert_eq!(light.primary_palette.chroma, 32.0);
+    assert_eq!(dark.primary_palette.chroma, 26.0);
+    assert_eq!(watch.primary_palette.chroma, 32.0);
+    assert_eq!(light.secondary_palette.chroma, 16.0);
+    assert_eq!(light.neutral_palette.chroma, 5.0);
+    assert_eq!(watch.neutral_palette.chroma, 10.0);
+    assert_eq!(light.neutral_variant_palette.chroma, 5.0 * 1.7);
+    assert_eq!(light.tertiary_palette.chroma, 28.0);
+    assert_eq!(watch.tertiary_palette.chroma, 32.0);
+    assert_eq!(
+        light.tertiary_palette.hue.round(),
+        (blue().get_hue() + 40.0).round()
+    );
+    assert_eq!(light.error_palette, TonalPalette::of(22.0, 60.0));
+}
+
+#[test]
+fn spec_2025_vibrant_and_expressive_palettes() {
+    let vibrant = spec_2025(Variant::Vibrant, false, Platform::Phone);
+    assert_eq!(vibrant.primary_palette.chroma, 74.0);
+    assert_eq!(vibrant.neutral_palette.chroma, 28.0);
+    assert_eq!(
+        vibrant.neutral_palette.hue.round(),
+        (blue().get_hue() + 10.0).round()
+    );
+    let vibrant_watch = spec_2025(Variant::Vibrant, false, Platform::Watch);
+    assert_eq!(vibrant_watch.primary_palette.chroma, 56.0);
+    assert_eq!(vibrant_watch.neutral_palette.chroma, 20.0);
+
+    let expressive_light = spec_2025(Variant::Expressive, false, Platform::Phone);
+    let expressive_dark = spec_2025(Variant::Expressive, true, Platform::Phone);
+    assert_eq!(expressive_light.primary_palette.chroma, 48.0);
+    assert_eq!(expressive_dark.primary_palette.chroma, 36.0);
+    assert_eq!(expressive_light.neutral_palette.chroma, 18.0);
+    assert_eq!(expressive_dark.neutral_palette.chroma, 14.0);
+    assert_eq!(expressive_light.neutral_variant_palette.chroma, 18.0 * 2.3);
+}
+
+#[test]
+fn spec_2025_neutral_palettes() {
+    let neutral = spec_2025(Variant::Neutral, false, Platform::Phone);
+    // Hue 266 is blue, which gets more chroma.
+    assert_eq!(neutral.primary_palette.chroma, 12.0);
+    assert_eq!(neutral.neutral_palette.chroma, 1.4);
+    assert_eq!(neutral.error_palette.chroma, 50.0);
+}
+
+#[test]
+fn spec_2025_keeps_other_variants() {
+    for variant in [
+        Variant::Monochrome,
+        Variant::Content,
+        Variant::Fidelity,
+        Variant::Rainbow,
+        Variant::FruitSalad,
+    ] {
+        let spec_2021 = DynamicScheme::from_variant(variant.clone(), blue(), true, 0.0);
+        let spec_2025 = spec_2025(variant, true, Platform::Phone);
+        assert_eq!(spec_2025.primary_palette, spec_2021.primary_palette);
+        assert_eq!(spec_2025.secondary_palette, spec_2021.secondary_palette);
+        assert_eq!(spec_2025.tertiary_palette, spec_2021.tertiary_palette);
+        assert_eq!(spec_2025.neutral_palette, spec_2021.neutral_palette);
+        assert_eq!(
+            spec_2025.neutral_variant_palette,
+            spec_2021.neutral_variant_palette
+        );
+        assert_eq!(spec_2025.error_palette, spec_2021.error_palette);
+        // The dim roles only exist in Spec2025.
+        let spec_2021_roles = spec_2021.resolve();
+        let spec_2025_roles = spec_2025.resolve();
+        for role in ColorRole::ALL {
+            if role.for_spec(SpecVersion::Spec2021) == role {
+                assert_eq!(spec_2025_roles.get(role), spec_2021_roles.get(role));
+            }
+        }
+    }
+}
+
+#[test]
+fn spec_2025_surface_tones() {
+    let light = spec_2025(Variant::TonalSpot, false, Platform::Phone);
+    assert_eq!(tone(light.surface()).round(), 98.0);
+    assert_eq!(tone(light.background()).round(), 98.0);
+    assert_eq!(tone(light.surface_dim()).round(), 87.0);
+    assert_eq!(tone(light.surface_container_high()).round(), 92.0);
+
+    let dark = spec_2025(Variant::TonalSpot, true, Platform::Phone);
+    assert_eq!(tone(dark.surface()).round(), 4.0);
+    assert_eq!(tone(dark.surface_bright()).round(), 18.0);
+    assert_eq!(tone(dark.surface_container()).round(), 9.0);
+    assert_eq!(tone(dark.surface_container_highest()).round(), 15.0);
+
+    let vibrant = spec_2025(Variant::Vibrant, false, Platform::Phone);
+    assert_eq!(tone(vibrant.surface()).round(), 97.0);
+    assert_eq!(tone(vibrant.surface_container_high()).round(), 90.0);
+
+    let watch = spec_2025(Variant::TonalSpot, true, Platform::Watch);
+    assert_eq!(tone(watch.surface()).round(), 0.0);
+    assert_eq!(tone(watch.surface_container_high()).round(), 25.0);
+}
+
+#[test]
+fn spec_2025_accent_tones() {
+    // Containers follow the chroma of their palettes, rather than fixed tones.
+    let tonal_spot = spec_2025(Variant::TonalSpot, false, Platform::Phone);
+    let spec_2021 = DynamicScheme::from_variant(Variant::TonalSpot, blue(), false, 0.0);
+    assert_eq!(tone(spec_2021.primary_container()).round(), 90.0);
+    assert!(tone(tonal_spot.primary_container()) < 85.0);
+    assert!(tone(tonal_spot.error_container()) < 85.0);
+
+    let expressive = spec_2025(Variant::Expressive, false, Platform::Phone);
+    assert!(tone(expressive.primary_container()) >= 78.0);
+    assert!(tone(expressive.secondary_container()) >= 90.0);
+
+    // Dark Expressive schemes have light containers.
+    let expressive_dark = spec_2025(Variant::Expressive, true, Platform::Phone);
+    assert_eq!(tone(expressive_dark.secondary_container()).round(), 15.0);
+    assert!(tone(expressive_dark.primary_container()) > 60.0);
+    assert!(tone(expressive_dark.tertiary_container()) > 60.0);
+
+    let watch = spec_2025(Variant::TonalSpot, true, Platform::Watch);
+    assert_eq!(tone(watch.primary_container()).round(), 30.0);
+    assert_eq!(tone(watch.error_container()).round(), 30.0);
+}
+
+#[test]
+fn spec_2025_roles_keep_contrast() {
+    for variant in [
+        Variant::Neutral,
+        Variant::TonalSpot,
+        Variant::Vibrant,
+        Variant::Expressive,
+    ] {
+        for is_dark in [false, true] {
+            let scheme = spec_2025(variant.clone(), is_dark, Platform::Phone);
+            // The surface closest in tone to the accents.
+            let surface = if is_dark {
+                tone(scheme.surface_bright())
+            } else {
+                tone(scheme.surface_dim())
+            };
+            for (name, accent) in [
+                ("primary", scheme.primary()),
+                ("secondary", scheme.secondary()),
+                ("tertiary", scheme.tertiary()),
+                ("error", scheme.error()),
+            ] {
+                let ratio = Contrast::ratio_of_tones(surface, tone(accent));
+                assert!(
+                    ratio >= 4.4,
+                    "{:?} dark={} {}: {}",
+                    variant,
+                    is_dark,
+                    name,
+                    ratio
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn builder_selects_spec_and_platform() {
+    let scheme = SchemeBuilder::new(blue())
+        .variant(Variant::Vibrant)
+        .spec_version(SpecVersion::Spec2025)
+        .platform(Platform::Watch)
+        .build();
+    assert!(scheme == spec_2025(Variant::Vibrant, false, Platform::Watch));
+}
+
+#[test]
+fn dim_roles_are_darker() {
+    for variant in VARIANTS {
+        // Dark schemes move the dim roles away from their background, so
+        // contrast wins over the delta there.
+        for contrast_level in [-1.0, 0.0, 0.5, 1.0] {
+            let scheme = DynamicScheme::from_spec(
+                variant.clone(),
+                blue(),
+                false,
+                contrast_level,
+                SpecVersion::Spec2025,
+                Platform::Phone,
+            );
+            for (dim, base) in [
+                (scheme.primary_dim(), scheme.primary()),
+                (scheme.secondary_dim(), scheme.secondary()),
+                (scheme.tertiary_dim(), scheme.tertiary()),
+                (scheme.error_dim(), scheme.error()),
+            ] {
+                assert!(
+                    tone(dim) <= (tone(base) - 4.0).max(0.5),
+                    "{:?} contrast={}: dim {} base {}",
+                    variant,
+                    contrast_level,
+                    tone(dim),
+                    tone(base)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn dim_roles_keep_contrast() {
+    for variant in VARIANTS {
+        for is_dark in [false, true] {
+            for (contrast_level, ratio) in [(-1.0, 4.5), (0.0, 4.5), (0.5, 7.0), (1.0, 11.0)] {
+                if is_dark && contrast_level < 0.5 {
+                    // Contrast 4.5 lands in the awkward zone (T50-59), which
+                    // the dim roles leave towards T49.
+                    continue;
+                }
+                let scheme = DynamicScheme::from_spec(
+                    variant.clone(),
+                    blue(),
+                    is_dark,
+                    contrast_level,
+                    SpecVersion::Spec2025,
+                    Platform::Phone,
+                );
+                let background = tone(scheme.surface_container_high());
+                for dim in [
+                    scheme.primary_dim(),
+                    scheme.secondary_dim(),
+                    scheme.tertiary_dim(),
+                    scheme.error_dim(),
+                ] {
+                    assert!(
+                        Contrast::ratio_of_tones(background, tone(dim)) >= ratio - 0.1,
+                        "{:?} dark={} contrast={}: dim {} background {}",
+                        variant,
+                        is_dark,
+                        contrast_level,
+                        tone(dim),
+                        background
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn spec_2021_resolves_dim_roles_as_base_roles() {
+    for variant in VARIANTS {
+        for is_dark in [false, true] {
+            let scheme = DynamicScheme::from_variant(variant.clone(), blue(), is_dark, 0.0);
+            let resolved = scheme.resolve();
+            for (dim, base) in [
+                (ColorRole::PrimaryDim, ColorRole::Primary),
+                (ColorRole::SecondaryDim, ColorRole::Secondary),
+                (ColorRole::TertiaryDim, ColorRole::Tertiary),
+                (ColorRole::ErrorDim, ColorRole::Error),
+            ] {
+                assert_eq!(dim.for_spec(SpecVersion::Spec2021), base);
+                assert_eq!(dim.for_spec(SpecVersion::Spec2025), dim);
+                assert_eq!(scheme.get(dim), scheme.get(base));
+                assert_eq!(resolved.get(dim), resolved.get(base));
+            }
+            assert_eq!(scheme.primary_dim(), scheme.primary());
+            assert_eq!(scheme.error_dim(), scheme.error());
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use crate::{
+    contrast::contrast::Contrast,
+    hct::hct::Hct,
+    swatch::swatch::{Swatch, SwatchKind, SwatchPalette},
+    utils::argb::Argb,
+};
+
+fn hct(hue: f64, chroma: f64, tone: f64) -> Argb {
+    return Hct::from(hue, chroma, tone).to_argb();
+}
+
+fn populations(colors: &[(Argb, i64)]) -> HashMap<Argb, i64> {
+    return colors.iter().copied().collect();
+}
+
+#[test]
+fn text_colors_reach_ratios() {
+    for hue in [0.0, 90.0, 180.0, 270.0] {
+        for tone in 0..=100 {
+            let swatch = Swatch::new(hct(hue, 40.0, tone as f64), 1);
+            let tone = swatch.argb.lstar();
+            let title = Contrast::ratio_of_tones(tone, swatch.title_text_color.lstar());
+            let body = Contrast::ratio_of_tones(tone, swatch.body_text_color.lstar());
+            assert!(
+                title >= Swatch::TITLE_TEXT_RATIO,
+                "T{} title {}",
+                tone,
+                title
+            );
+            assert!(body >= Swatch::BODY_TEXT_RATIO, "T{} body {}", tone, body);
+        }
+    }
+}
+
+#[test]
+fn text_colors_prefer_light_on_dark() {
+    let swatch = Swatch::new(hct(270.0, 40.0, 20.0), 1);
+    assert!(swatch.body_text_color.lstar() > 50.0);
+    let swatch = Swatch::new(hct(270.0, 40.0, 90.0), 1);
+    assert!(swatch.body_text_color.lstar() < 50.0);
+}
+
+#[test]
+fn picks_every_kind() {
+    let vibrant = hct(250.0, 60.0, 50.0);
+    let light_vibrant = hct(90.0, 60.0, 80.0);
+    let dark_vibrant = hct(20.0, 60.0, 25.0);
+    let muted = hct(200.0, 16.0, 50.0);
+    let light_muted = hct(140.0, 14.0, 75.0);
+    let dark_muted = hct(300.0, 12.0, 25.0);
+    let palette = SwatchPalette::from_populations(&populations(&[
+        (vibrant, 10),
+        (light_vibrant, 20),
+        (dark_vibrant, 30),
+        (muted, 40),
+        (light_muted, 50),
+        (dark_muted, 60),
+    ]));
+
+    assert_eq!(palette.vibrant().map(|swatch| swatch.argb), Some(vibrant));
+    assert_eq!(
+        palette.light_vibrant().map(|swatch| swatch.argb),
+        Some(light_vibrant)
+    );
+    assert_eq!(
+        palette.dark_vibrant().map(|swatch| swatch.argb),
+        Some(dark_vibrant)
+    );
+    assert_eq!(palette.muted().map(|swatch| swatch.argb), Some(muted));
+    assert_eq!(
+        palette.light_muted().map(|swatch| swatch.argb),
+        Some(light_muted)
+    );
+    assert_eq!(
+        palette.dark_muted().map(|swatch| swatch.argb),
+        Some(dark_muted)
+    );
+    assert_eq!(
+        palette.dominant().map(|swatch| swatch.argb),
+        Some(dark_muted)
+    );
+    assert_eq!(palette.vibrant().unwrap().population, 10);
+}
+
+#[test]
+fn swatches_are_picked_once() {
+    // Within the tones of both light vibrant and vibrant swatches.
+    let color = hct(250.0, 60.0, 60.0);
+    let palette = SwatchPalette::from_populations(&populations(&[(color, 1)]));
+    assert_eq!(
+        palette.light_vibrant().map(|swatch| swatch.argb),
+        Some(color)
+    );
+    assert_eq!(palette.vibrant(), None);
+}
+
+#[test]
+fn prefers_closer_and_more_populous_swatches() {
+    let close = hct(250.0, 70.0, 50.0);
+    let far = hct(250.0, 40.0, 35.0);
+    let palette = SwatchPalette::from_populations(&populations(&[(close, 10), (far, 10)]));
+    assert_eq!(palette.vibrant().map(|swatch| swatch.argb), Some(close));
+    assert_eq!(palette.dark_vibrant().map(|swatch| swatch.argb), Some(far));
+}
+
+#[test]
+fn grays_are_only_muted() {
+    let palette = SwatchPalette::from_populations(&populations(&[
+        (Argb(0xff777777), 1),
+        (Argb(0xffdddddd), 1),
+        (Argb(0xff222222), 1),
+    ]));
+    for kind in [
+        SwatchKind::Vibrant,
+        SwatchKind::LightVibrant,
+        SwatchKind::DarkVibrant,
+    ] {
+        assert_eq!(palette.get(kind), None);
+    }
+    assert_eq!(
+        palette.muted().map(|swatch| swatch.argb),
+        Some(Argb(0xff777777))
+    );
+    assert_eq!(
+        palette.light_muted().map(|swatch| swatch.argb),
+        Some(Argb(0xffdddddd))
+    );
+    assert_eq!(
+        palette.dark_muted().map(|swatch| swatch.argb),
+        Some(Argb(0xff222222))
+    );
+}
+
+#[test]
+fn from_pixels() {
+    let vibrant = hct(250.0, 60.0, 50.0);
+    let muted = hct(200.0, 16.0, 50.0);
+    let mut pixels = vec![vibrant; 300];
+    pixels.extend(vec![muted; 100]);
+    let palette = SwatchPalette::from_pixels(&pixels, None);
+    assert_eq!(palette.swatches().len(), 2);
+    assert_eq!(palette.dominant().map(|swatch| swatch.argb), Some(vibrant));
+    assert_eq!(palette.vibrant().map(|swatch| swatch.argb), Some(vibrant));
+    assert_eq!(palette.muted().map(|swatch| swatch.argb), Some(muted));
+    assert_eq!(palette.muted().unwrap().population, 100);
+}
+
+#[test]
+fn empty_palette() {
+    let palette = SwatchPalette::from_pixels(&[], None);
+    assert!(palette.swatches().is_empty());
+    assert_eq!(palette.dominant(), None);
+    for kind in SwatchKind::ALL {
+        assert_eq!(palette.get(kind), None);
+    }
+}
@@ -0,0 +1,220 @@
+use crate::{
+    dynamiccolor::variant::Variant,
+    hct::hct::Hct,
+    quantize::{quantizer::Quantizer, quantizer_celebi::QuantizerCelebi},
+    scheme::scheme_builder::SchemeBuilder,
+    score::score::Score,
+    utils::{
+        argb::Argb,
+        math_utils::MathUtils,
+        theme_from_image::{ThemeCandidate, ThemeFromImage, ThemePreview},
+    },
+};
+
+const RED: Argb = Argb(0xffd32f2f);
+const BLUE: Argb = Argb(0xff1e88e5);
+const GREY: Argb = Argb(0xff808080);
+
+/// An image of [count] pixels, two thirds [RED] and one third [BLUE].
+fn image(count: usize) -> Vec<Argb> {
+    return (0..count)
+        .map(|i| if i % 3 == 2 { BLUE } else { RED })
+        .collect();
+}
+
+#[test]
+fn matches_manual_pipeline() {
+    let pixels = image(3000);
+    let theme = ThemeFromImage::new().theme(&pixels);
+
+    let result = QuantizerCelebi {}.quantize(&pixels, 128, None);
+    let ranked = Score::score_argb(&result.color_to_count, None, None, None);
+    assert_eq!(theme.candidates, ranked);
+    assert_eq!(theme.source_color, ranked[0]);
+    assert!(theme.scheme == SchemeBuilder::new(Hct::from_argb(ranked[0])).build());
+}
+
+#[test]
+fn ranks_dominant_color_first() {
+    let theme = ThemeFromImage::new().theme(&image(3000));
+    assert_eq!(theme.source_color, RED);
+    assert_eq!(theme.candidates, vec![RED, BLUE]);
+}
+
+#[test]
+fn applies_scheme_options() {
+    let theme = ThemeFromImage::new()
+        .variant(Variant::Vibrant)
+        .is_dark(true)
+        .contrast_level(0.5)
+        .theme(&image(300));
+    let expected = SchemeBuilder::new(Hct::from_argb(RED))
+        .variant(Variant::Vibrant)
+        .is_dark(true)
+        .contrast_level(0.5)
+        .build();
+    assert!(theme.scheme == expected);
+}
+
+#[test]
+fn limits_candidates() {
+    let theme = ThemeFromImage::new().candidate_count(1).theme(&image(300));
+    assert_eq!(theme.candidates, vec![RED]);
+}
+
+#[test]
+fn falls_back_without_suitable_colors() {
+    let fallback = Argb(0xff00897b);
+    let builder = ThemeFromImage::new().fallback_color(fallback);
+    assert_eq!(builder.theme(&[GREY; 100]).candidates, vec![fallback]);
+    assert_eq!(builder.theme(&[]).source_color, fallback);
+}
+
+#[test]
+fn skips_translucent_pixels() {
+    let mut pixels = image(300);
+    pixels.extend([Argb(0x801e88e5); 3000]);
+    pixels.extend([Argb(0x001e88e5); 3000]);
+    assert_eq!(ThemeFromImage::new().sample(&pixels).len(), 300);
+    assert_eq!(ThemeFromImage::new().theme(&pixels).source_color, RED);
+}
+
+#[test]
+fn downsamples_large_images() {
+    let pixels = image(100_000);
+    let builder = ThemeFromImage::new();
+    let sampled = builder.sample(&pixels);
+    assert!(sampled.len() <= ThemeFromImage::DEFAULT_MAX_PIXELS);
+    assert!(sampled.len() > ThemeFromImage::DEFAULT_MAX_PIXELS / 2);
+    assert_eq!(builder.clone().max_pixels(0).sample(&pixels).len(), 100_000);
+    assert_eq!(builder.clone().max_pixels(1000).sample(&pixels).len(), 1000);
+    assert_eq!(ThemeFromImage::new().theme(&pixels).source_color, RED);
+}
+
+#[test]
+fn downsamples_before_skipping_translucent_pixels() {
+    let mut pixels = image(10_000);
+    pixels.extend([Argb(0x001e88e5); 10_000]);
+    let sampled = ThemeFromImage::new().max_pixels(1000).sample(&pixels);
+    assert_eq!(sampled.len(), 500);
+    assert!(sampled.iter().all(|p| p.is_opaque()));
+}
+
+const GREEN: Argb = Argb(0xff43a047);
+const YELLOW: Argb = Argb(0xfffdd835);
+
+/// An image of [count] pixels of [RED], [BLUE], [GREEN] and [YELLOW], in
+/// decreasing proportions.
+fn four_hue_image(count: usize) -> Vec<Argb> {
+    return (0..count)
+        .map(|i| match i % 10 {
+            0..=3 => RED,
+            4..=6 => BLUE,
+            7..=8 => GREEN,
+            _ => YELLOW,
+        })
+        .collect();
+}
+
+#[test]
+fn theme_candidates_have_distinct_primaries() {
+    let builder = ThemeFromImage::new();
+    let candidates = builder.theme_candidates(&four_hue_image(1000), 4);
+    assert_eq!(candidates.len(), 4);
+    assert_eq!(
+        candidates[0].source_color,
+        builder.theme(&four_hue_image(1000)).source_color
+    );
+    for (i, candidate) in candidates.iter().enumerate() {
+        assert_eq!(candidate.variant, Variant::TonalSpot);
+        assert_eq!(
+            candidate.preview,
+            ThemePreview::from_scheme(&candidate.scheme)
+        );
+        assert_eq!(candidate.preview.primary, candidate.scheme.primary());
+        for other in &candidates[..i] {
+            assert_ne!(candidate.source_color, other.source_color);
+            assert!(
+                MathUtils::difference_degrees(
+                    candidate.scheme.primary_palette.hue,
+                    other.scheme.primary_palette.hue
+                ) >= 15.0
+            );
+        }
+    }
+}
+
+#[test]
+fn theme_candidates_match_theme() {
+    let pixels = four_hue_image(1000);
+    let builder = ThemeFromImage::new()
+        .variant(Variant::Vibrant)
+        .is_dark(true);
+    let candidates = builder.theme_candidates(&pixels, 1);
+    assert_eq!(candidates.len(), 1);
+    assert!(candidates[0].scheme == builder.theme(&pixels).scheme);
+    assert!(builder.theme_candidates(&pixels, 0).is_empty());
+}
+
+#[test]
+fn theme_candidates_respect_min_hue_distance() {
+    let candidates = ThemeFromImage::new()
+        .min_hue_distance(100.0)
+        .theme_candidates(&four_hue_image(1000), 4);
+    assert_eq!(candidates.len(), 4);
+    let distinct: Vec<&ThemeCandidate> = candidates
+        .iter()
+        .filter(|candidate| candidate.variant == Variant::TonalSpot)
+        .collect();
+    assert!(distinct.len() < 4);
+    for (i, candidate) in distinct.iter().enumerate() {
+        for other in &distinct[..i] {
+            assert!(
+                MathUtils::difference_degrees(
+                    candidate.scheme.primary_palette.hue,
+                    other.scheme.primary_palette.hue
+                ) >= 100.0
+            );
+        }
+    }
+    assert_eq!(candidates[distinct.len()].variant, Variant::Rainbow);
+    assert_eq!(
+        candidates[distinct.len()].source_color,
+        candidates[0].source_color
+    );
+}
+
+#[test]
+fn theme_candidates_fall_back_to_variants_for_one_hue() {
+    let candidates = ThemeFromImage::new().theme_candidates(&[RED; 300], 4);
+    let variants: Vec<Variant> = candidates.iter().map(|c| c.variant.clone()).collect();
+    assert_eq!(
+        variants,
+        vec![
+            Variant::TonalSpot,
+            Variant::Rainbow,
+            Variant::Neutral,
+            Variant::Expressive
+        ]
+    );
+    assert!(candidates
+        .iter()
+        .all(|candidate| candidate.source_color == RED));
+
+    let candidates = ThemeFromImage::new()
+        .fallback_variants(vec![Variant::Neutral])
+        .theme_candidates(&[RED; 300], 4);
+    assert_eq!(candidates.len(), 2);
+}
+
+#[test]
+fn theme_candidates_without_suitable_colors() {
+    let fallback = Argb(0xff00897b);
+    let candidates = ThemeFromImage::new()
+        .fallback_color(fallback)
+        .theme_candidates(&[GREY; 100], 3);
+    assert_eq!(candidates.len(), 3);
+    assert!(candidates
+        .iter()
+        .all(|candidate| candidate.source_color == fallback));
+}
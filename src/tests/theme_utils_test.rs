@@ -0,0 +1,82 @@
+use crate::{
+    blend::blend::Blend,
+    contrast::contrast::Contrast,
+    dynamiccolor::{dynamic_scheme::DynamicScheme, variant::Variant},
+    hct::hct::Hct,
+    palettes::core_palette::CorePalette,
+    utils::{
+        argb::Argb,
+        theme_utils::{ColorGroup, CustomColorGroup},
+    },
+};
+
+const SOURCE: Argb = Argb(0xff4285f4);
+const BRAND: Argb = Argb(0xff2e7d32);
+
+fn scheme(is_dark: bool, contrast_level: f64) -> DynamicScheme {
+    return DynamicScheme::from_variant(
+        Variant::TonalSpot,
+        Hct::from_argb(SOURCE),
+        is_dark,
+        contrast_level,
+    );
+}
+
+#[test]
+fn harmonizes_by_default() {
+    let group = CustomColorGroup::new("brand", BRAND);
+    assert!(group.harmonize);
+    assert_eq!(
+        group.value_for(&scheme(false, 0.0)),
+        Blend::harmonize_argb(BRAND, SOURCE)
+    );
+}
+
+#[test]
+fn without_harmonize_keeps_value() {
+    let group = CustomColorGroup::new("brand", BRAND).harmonize(false);
+    assert_eq!(group.value_for(&scheme(false, 0.0)), BRAND);
+    assert_eq!(
+        group.palette(&scheme(false, 0.0)),
+        CorePalette::from_argb(BRAND).primary
+    );
+}
+
+#[test]
+fn standard_contrast_matches_theme_builder_tones() {
+    let group = CustomColorGroup::new("brand", BRAND);
+    let palette = group.palette(&scheme(false, 0.0));
+    assert_eq!(
+        group.resolve(&scheme(false, 0.0)),
+        ColorGroup {
+            color: palette.get_argb(40),
+            on_color: palette.get_argb(100),
+            color_container: palette.get_argb(90),
+            on_color_container: palette.get_argb(10),
+        }
+    );
+    assert_eq!(
+        group.resolve(&scheme(true, 0.0)),
+        ColorGroup {
+            color: palette.get_argb(80),
+            on_color: palette.get_argb(20),
+            color_container: palette.get_argb(30),
+            on_color_container: palette.get_argb(90),
+        }
+    );
+}
+
+#[test]
+fn honors_contrast_level() {
+    let group = CustomColorGroup::new("brand", BRAND);
+    for is_dark in [false, true] {
+        let standard = group.resolve(&scheme(is_dark, 0.0));
+        let high = group.resolve(&scheme(is_dark, 1.0));
+        assert_ne!(high, standard);
+        let ratio = Contrast::ratio_of_tones(
+            Hct::from_argb(high.color).get_tone(),
+            Hct::from_argb(high.on_color).get_tone(),
+        );
+        assert!(ratio >= 11.0, "ratio {} too low", ratio);
+    }
+}
@@ -0,0 +1,71 @@
+use std::{sync::Arc, thread};
+
+use crate::{
+    dynamiccolor::{
+        dynamic_color::DynamicColor, dynamic_scheme::DynamicScheme,
+        material_dynamic_colors::MaterialDynamicColors,
+    },
+    hct::hct::Hct,
+    palettes::{core_palette::CorePalette, tonal_palette::TonalPalette},
+    scheme::scheme_tonal_spot::SchemeTonalSpot,
+    temperature::temperature_cache::TemperatureCache,
+    utils::argb::Argb,
+};
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn types_are_send_and_sync() {
+    assert_send_sync::<DynamicColor>();
+    assert_send_sync::<DynamicScheme>();
+    assert_send_sync::<TonalPalette>();
+    assert_send_sync::<CorePalette>();
+    assert_send_sync::<TemperatureCache>();
+}
+
+#[test]
+fn tonal_palette_shared_across_threads() {
+    let palette = Arc::new(TonalPalette::of(270.0, 36.0));
+    let expected = TonalPalette::of(270.0, 36.0).get_argb_list();
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let palette = Arc::clone(&palette);
+            thread::spawn(move || palette.get_argb_list())
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), expected);
+    }
+}
+
+#[test]
+fn dynamic_color_shared_across_threads() {
+    let color = Arc::new(MaterialDynamicColors::primary_container());
+    let scheme =
+        Arc::new(SchemeTonalSpot::new(Hct::from_argb(Argb(0xff4285f4)), false, 0.0).dynamic_scheme);
+    let expected = scheme.primary_container();
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let color = Arc::clone(&color);
+            let scheme = Arc::clone(&scheme);
+            thread::spawn(move || color.get_color(&scheme))
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), expected);
+    }
+}
+
+#[test]
+fn temperature_cache_shared_across_threads() {
+    let cache = Arc::new(TemperatureCache::new(Hct::from_argb(Argb(0xff0000ff))));
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let cache = Arc::clone(&cache);
+            thread::spawn(move || cache.get_complement())
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap().to_argb(), Argb(0xff9d0002));
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{
+    error::error::Error,
+    quantize::{
+        pixel_histogram::PixelHistogram,
+        quantizer::Quantizer,
+        quantizer_celebi::QuantizerCelebi,
+        quantizer_wsmeans::{QuantizerWsmeans, WsmeansOptions},
+        quantizer_wu::QuantizerWu,
+    },
+    score::score::Score,
+    utils::argb::Argb,
+};
+
+const RED: Argb = Argb(0xffff0000);
+const BLUE: Argb = Argb(0xff0000ff);
+
+#[test]
+fn histogram_from_weights() {
+    let histogram = PixelHistogram::from_weighted(&[RED, BLUE, RED, BLUE], &[10, 0, 5, 1]).unwrap();
+    assert_eq!(histogram.colors(), &[RED, BLUE]);
+    assert_eq!(histogram.count_of(RED), 15);
+    assert_eq!(histogram.count_of(BLUE), 1);
+}
+
+#[test]
+fn histogram_leaves_out_zero_weights() {
+    let histogram = PixelHistogram::from_weighted(&[RED, BLUE], &[3, 0]).unwrap();
+    assert_eq!(histogram.colors(), &[RED]);
+}
+
+#[test]
+fn histogram_rejects_mismatched_weights() {
+    assert_eq!(
+        PixelHistogram::from_weighted(&[RED, BLUE], &[1]),
+        Err(Error::InvalidLength {
+            expected: 2,
+            actual: 1
+        })
+    );
+}
+
+#[test]
+fn histogram_from_alpha() {
+    let histogram = PixelHistogram::from_alpha_weighted(&[Argb(0x80ff0000), RED, Argb(0x000000ff)]);
+    assert_eq!(histogram.colors(), &[RED]);
+    assert_eq!(histogram.count_of(RED), 0x80 + 0xff);
+}
+
+#[test]
+fn wu_populations_are_weighted() {
+    let result = QuantizerWu::new()
+        .quantize_weighted(&[RED, BLUE, BLUE, BLUE], &[200, 10, 10, 10], 8, None)
+        .unwrap();
+    assert_eq!(result.color_to_count.get(&RED), Some(&200));
+    assert_eq!(result.color_to_count.get(&BLUE), Some(&30));
+}
+
+#[test]
+fn weights_change_the_top_score() {
+    let mut pixels = vec![BLUE; 90];
+    pixels.extend(vec![RED; 10]);
+    let unweighted = QuantizerCelebi {}.quantize(&pixels, 8, None);
+    assert_eq!(
+        Score::score_argb(&unweighted.color_to_count, Some(1), None, None),
+        vec![BLUE]
+    );
+
+    // Only the red subject in the middle of the image is marked.
+    let weights: Vec<u8> = pixels
+        .iter()
+        .map(|pixel| if *pixel == RED { 255 } else { 1 })
+        .collect();
+    let weighted = QuantizerCelebi {}
+        .quantize_weighted(&pixels, &weights, 8, None)
+        .unwrap();
+    assert_eq!(
+        Score::score_argb(&weighted.color_to_count, Some(1), None, None),
+        vec![RED]
+    );
+}
+
+#[test]
+fn wsmeans_clusters_move_towards_weight() {
+    let light = Argb(0xffff8080);
+    let dark = Argb(0xff800000);
+    let histogram = PixelHistogram::from_weighted(&[light, dark], &[250, 5]).unwrap();
+    let result = QuantizerWsmeans::quantize_histogram(
+        &histogram,
+        1,
+        None,
+        None,
+        &WsmeansOptions::default(),
+        None,
+    );
+    let (cluster, count) = result.color_to_count.into_iter().next().unwrap();
+    assert_eq!(count, 255);
+    assert!(cluster.red() > 0xf0, "{:x}", cluster.0);
+}
+
+#[test]
+fn unit_weights_match_unweighted() {
+    let pixels: Vec<Argb> = (0..400u32)
+        .map(|i| {
+            Argb::from_rgb(
+                (i * 5 % 256) as u8,
+                (i * 9 % 256) as u8,
+                (i * 17 % 256) as u8,
+            )
+        })
+        .collect();
+    let weights = vec![1; pixels.len()];
+    assert_eq!(
+        QuantizerCelebi {}
+            .quantize_weighted(&pixels, &weights, 16, None)
+            .unwrap()
+            .color_to_count,
+        QuantizerCelebi {}
+            .quantize(&pixels, 16, None)
+            .color_to_count
+    );
+}
+
+#[test]
+fn alpha_weighted_keeps_translucent_pixels() {
+    let pixels = [Argb(0x40ff0000), Argb(0x40ff0000), Argb(0xff0000ff)];
+    let result = QuantizerWu::new().quantize_alpha_weighted(&pixels, 8, Some(true));
+    assert_eq!(result.color_to_count.get(&RED), Some(&0x80));
+    assert_eq!(result.color_to_count.get(&BLUE), Some(&0xff));
+    assert_eq!(result.input_pixel_to_cluster_pixel.get(&RED), Some(&RED));
+}
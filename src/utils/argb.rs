@@ -0,0 +1,204 @@
+use std::{fmt, str::FromStr};
+
+use crate::error::error::Error;
+
+use super::{color_utils::ColorUtils, math_utils::MathUtils};
+
+/// A color in ARGB format, packed as `0xAARRGGBB`.
+///
+/// The wrapped `u32` can't be negative or exceed `0xFFFFFFFF`, so an [Argb]
+/// can't be confused with a population, a count or any other integer.
+///
+/// With the `serde` feature, an [Argb] is serialized as its `u32` value.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Argb(pub u32);
+
+impl Argb {
+    /// Creates a color from its alpha, red, green and blue components.
+    pub const fn new(alpha: u8, red: u8, green: u8, blue: u8) -> Argb {
+        Argb((alpha as u32) << 24 | (red as u32) << 16 | (green as u32) << 8 | blue as u32)
+    }
+
+    /// Creates an opaque color from its red, green and blue components.
+    pub const fn from_rgb(red: u8, green: u8, blue: u8) -> Argb {
+        Self::new(255, red, green, blue)
+    }
+
+    /// The alpha component.
+    pub const fn alpha(self) -> u8 {
+        (self.0 >> 24) as u8
+    }
+
+    /// The red component.
+    pub const fn red(self) -> u8 {
+        (self.0 >> 16) as u8
+    }
+
+    /// The green component.
+    pub const fn green(self) -> u8 {
+        (self.0 >> 8) as u8
+    }
+
+    /// The blue component.
+    pub const fn blue(self) -> u8 {
+        self.0 as u8
+    }
+
+    /// Whether the color is fully opaque.
+    pub const fn is_opaque(self) -> bool {
+        self.alpha() == 255
+    }
+
+    /// Converts a color from linear RGB components to ARGB format.
+    pub fn from_linrgb(linrgb: &[f64]) -> Argb {
+        let r = ColorUtils::delinearized(linrgb[0]);
+        let g = ColorUtils::delinearized(linrgb[1]);
+        let b = ColorUtils::delinearized(linrgb[2]);
+        Argb::from_rgb(r as u8, g as u8, b as u8)
+    }
+
+    /// Converts a color from XYZ to ARGB.
+    pub fn from_xyz(x: f64, y: f64, z: f64) -> Argb {
+        let matrix = ColorUtils::_xyz_to_srgb();
+        let linear_r = matrix[0][0] * x + matrix[0][1] * y + matrix[0][2] * z;
+        let linear_g = matrix[1][0] * x + matrix[1][1] * y + matrix[1][2] * z;
+        let linear_b = matrix[2][0] * x + matrix[2][1] * y + matrix[2][2] * z;
+        Self::from_linrgb(&[linear_r, linear_g, linear_b])
+    }
+
+    /// Converts a color from ARGB to XYZ.
+    pub fn to_xyz(self) -> Vec<f64> {
+        let r = ColorUtils::linearized(self.red() as i64);
+        let g = ColorUtils::linearized(self.green() as i64);
+        let b = ColorUtils::linearized(self.blue() as i64);
+        MathUtils::matrix_multiply([r, g, b].to_vec(), ColorUtils::_srgb_to_xyz())
+    }
+
+    /// Converts a color represented in Lab color space into ARGB.
+    pub fn from_lab(l: f64, a: f64, b: f64) -> Argb {
+        let white_point = ColorUtils::white_point_d65();
+        let fy = (l + 16.0) / 116.0;
+        let fx = a / 500.0 + fy;
+        let fz = fy - b / 200.0;
+        let x_normalized = ColorUtils::_lab_invf(fx);
+        let y_normalized = ColorUtils::_lab_invf(fy);
+        let z_normalized = ColorUtils::_lab_invf(fz);
+        let x = x_normalized * white_point[0];
+        let y = y_normalized * white_point[1];
+        let z = z_normalized * white_point[2];
+        Self::from_xyz(x, y, z)
+    }
+
+    /// Converts a color from ARGB representation to L*a*b*
+    /// representation.
+    pub fn to_lab(self) -> Vec<f64> {
+        let xyz = self.to_xyz();
+        let white_point = ColorUtils::white_point_d65();
+        let fx = ColorUtils::_lab_f(xyz[0] / white_point[0]);
+        let fy = ColorUtils::_lab_f(xyz[1] / white_point[1]);
+        let fz = ColorUtils::_lab_f(xyz[2] / white_point[2]);
+        let l = 116.0 * fy - 16.0;
+        let a = 500.0 * (fx - fy);
+        let b = 200.0 * (fy - fz);
+        [l, a, b].to_vec()
+    }
+
+    /// Converts an L* value to a grayscale color with lightness matching L*.
+    pub fn from_lstar(lstar: f64) -> Argb {
+        let y = ColorUtils::y_from_lstar(lstar);
+        let component = ColorUtils::delinearized(y) as u8;
+        Argb::from_rgb(component, component, component)
+    }
+
+    /// Computes the L* value of the color, from L*a*b*.
+    pub fn lstar(self) -> f64 {
+        let y = self.to_xyz()[1];
+        116.0 * ColorUtils::_lab_f(y / 100.0) - 16.0
+    }
+}
+
+impl From<u32> for Argb {
+    fn from(value: u32) -> Self {
+        Argb(value)
+    }
+}
+
+impl From<Argb> for u32 {
+    fn from(argb: Argb) -> Self {
+        argb.0
+    }
+}
+
+impl From<Argb> for i64 {
+    fn from(argb: Argb) -> Self {
+        argb.0 as i64
+    }
+}
+
+impl TryFrom<i64> for Argb {
+    type Error = Error;
+
+    /// Converts a color stored in an `i64`, as the deprecated integer APIs do.
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        u32::try_from(value)
+            .map(Argb)
+            .map_err(|_| Error::InvalidArgb(value))
+    }
+}
+
+impl From<[u8; 4]> for Argb {
+    /// Converts `[alpha, red, green, blue]` components.
+    fn from(components: [u8; 4]) -> Self {
+        Argb::new(components[0], components[1], components[2], components[3])
+    }
+}
+
+impl From<Argb> for [u8; 4] {
+    /// Converts to `[alpha, red, green, blue]` components.
+    fn from(argb: Argb) -> Self {
+        [argb.alpha(), argb.red(), argb.green(), argb.blue()]
+    }
+}
+
+impl fmt::Display for Argb {
+    /// Formats the color as `#RRGGBB`, or `#AARRGGBB` when it isn't opaque.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_opaque() {
+            write!(
+                f,
+                "#{:02X}{:02X}{:02X}",
+                self.red(),
+                self.green(),
+                self.blue()
+            )
+        } else {
+            write!(f, "#{:08X}", self.0)
+        }
+    }
+}
+
+impl FromStr for Argb {
+    type Err = Error;
+
+    /// Parses `RRGGBB` or `AARRGGBB` hex digits, with an optional leading `#`.
+    /// Colors without an alpha component are opaque.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.strip_prefix('#').unwrap_or(s);
+        let error = || Error::InvalidHex(s.to_string());
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(error());
+        }
+        let value = u32::from_str_radix(digits, 16).map_err(|_| error())?;
+        match digits.len() {
+            6 => Ok(Argb(0xff000000 | value)),
+            8 => Ok(Argb(value)),
+            _ => Err(error()),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use super::math_utils::MathUtils;
+use super::{argb::Argb, math_utils::MathUtils};
 
 /// Color science utilities.
 ///
@@ -6,7 +6,7 @@ use super::math_utils::MathUtils;
 /// conversions that aren't HCT or CAM16.
 pub struct ColorUtils {}
 impl ColorUtils {
-    fn _srgb_to_xyz() -> Vec<Vec<f64>> {
+    pub(crate) fn _srgb_to_xyz() -> Vec<Vec<f64>> {
         vec![
             vec![0.41233895, 0.35762064, 0.18051042],
             vec![0.2126, 0.7152, 0.0722],
@@ -14,7 +14,7 @@ impl ColorUtils {
         ]
     }
 
-    fn _xyz_to_srgb() -> Vec<Vec<f64>> {
+    pub(crate) fn _xyz_to_srgb() -> Vec<Vec<f64>> {
         vec![
             vec![
                 3.2413774792388685,
@@ -35,82 +35,63 @@ impl ColorUtils {
     }
 
     /// Converts a color from RGB components to ARGB format.
+    #[deprecated = "use `Argb::from_rgb` instead"]
     pub fn argb_from_rgb(red: i64, green: i64, blue: i64) -> i64 {
-        return 255 << 24 | (red & 255) << 16 | (green & 255) << 8 | blue & 255;
+        Argb::from_rgb(red as u8, green as u8, blue as u8).into()
     }
     /// Converts a color from linear RGB components to ARGB format.
+    #[deprecated = "use `Argb::from_linrgb` instead"]
     pub fn argb_from_linrgb(linrgb: Vec<f64>) -> i64 {
-        let r = Self::delinearized(linrgb[0]);
-        let g = Self::delinearized(linrgb[1]);
-        let b = Self::delinearized(linrgb[2]);
-        return Self::argb_from_rgb(r, g, b);
+        Argb::from_linrgb(&linrgb).into()
     }
 
     /// Returns the alpha component of a color in ARGB format.
+    #[deprecated = "use `Argb::alpha` instead"]
     pub fn alpha_from_argb(argb: i64) -> i64 {
-        return argb >> 24 & 255;
+        Argb(argb as u32).alpha() as i64
     }
 
     /// Returns the red component of a color in ARGB format.
+    #[deprecated = "use `Argb::red` instead"]
     pub fn red_from_argb(argb: i64) -> i64 {
-        return argb >> 16 & 255;
+        Argb(argb as u32).red() as i64
     }
 
     /// Returns the green component of a color in ARGB format.
+    #[deprecated = "use `Argb::green` instead"]
     pub fn green_from_argb(argb: i64) -> i64 {
-        return argb >> 8 & 255;
+        Argb(argb as u32).green() as i64
     }
 
     /// Returns the blue component of a color in ARGB format.
+    #[deprecated = "use `Argb::blue` instead"]
     pub fn blue_from_argb(argb: i64) -> i64 {
-        return argb & 255;
+        Argb(argb as u32).blue() as i64
     }
 
     /// Returns whether a color in ARGB format is opaque.
+    #[deprecated = "use `Argb::is_opaque` instead"]
     pub fn is_opaque(argb: i64) -> bool {
-        return Self::alpha_from_argb(argb) >= 255;
+        Argb(argb as u32).is_opaque()
     }
 
     /// Converts a color from ARGB to XYZ.
+    #[deprecated = "use `Argb::from_xyz` instead"]
     pub fn argb_from_xyz(x: f64, y: f64, z: f64) -> i64 {
-        let matrix = Self::_xyz_to_srgb();
-        let linear_r = matrix[0][0] * x + matrix[0][1] * y + matrix[0][2] * z;
-        let linear_g = matrix[1][0] * x + matrix[1][1] * y + matrix[1][2] * z;
-        let linear_b = matrix[2][0] * x + matrix[2][1] * y + matrix[2][2] * z;
-        let r = Self::delinearized(linear_r);
-        let g = Self::delinearized(linear_g);
-        let b = Self::delinearized(linear_b);
-        return Self::argb_from_rgb(r, g, b);
+        Argb::from_xyz(x, y, z).into()
     }
 
     /// Converts a color from XYZ to ARGB.
+    #[deprecated = "use `Argb::to_xyz` instead"]
     pub fn xyz_from_argb(argb: i64) -> Vec<f64> {
-        let r = Self::linearized(Self::red_from_argb(argb));
-        let g = Self::linearized(Self::green_from_argb(argb));
-        let b = Self::linearized(Self::blue_from_argb(argb));
-        return MathUtils::matrix_multiply(
-            [r, g, b].to_vec(),
-            Self::_srgb_to_xyz()
-                .iter()
-                .map(|row| row.to_vec())
-                .collect(),
-        );
+        Argb(argb as u32).to_xyz()
     }
 
     /// Converts a color represented in Lab color space into an ARGB
     /// integer.
+    #[deprecated = "use `Argb::from_lab` instead"]
     pub fn argb_from_lab(l: f64, a: f64, b: f64) -> i64 {
-        let white_point = Self::_white_point_d65();
-        let fy = (l + 16.0) / 116.0;
-        let fx = a / 500.0 + fy;
-        let fz = fy - b / 200.0;
-        let x_normalized = Self::_lab_invf(fx);
-        let y_normalized = Self::_lab_invf(fy);
-        let z_normalized = Self::_lab_invf(fz);
-        let x = x_normalized * white_point[0];
-        let y = y_normalized * white_point[1];
-        let z = z_normalized * white_point[2];
-        return Self::argb_from_xyz(x, y, z);
+        Argb::from_lab(l, a, b).into()
     }
 
     /// Converts a color from ARGB representation to L*a*b*
@@ -118,25 +99,9 @@ impl ColorUtils {
     ///
     /// [argb] the ARGB representation of a color
     /// Returns a Lab object representing the color
+    #[deprecated = "use `Argb::to_lab` instead"]
     pub fn lab_from_argb(argb: i64) -> Vec<f64> {
-        let linear_r = Self::linearized(Self::red_from_argb(argb));
-        let linear_g = Self::linearized(Self::green_from_argb(argb));
-        let linear_b = Self::linearized(Self::blue_from_argb(argb));
-        let matrix = Self::_srgb_to_xyz();
-        let x = matrix[0][0] * linear_r + matrix[0][1] * linear_g + matrix[0][2] * linear_b;
-        let y = matrix[1][0] * linear_r + matrix[1][1] * linear_g + matrix[1][2] * linear_b;
-        let z = matrix[2][0] * linear_r + matrix[2][1] * linear_g + matrix[2][2] * linear_b;
-        let white_point = Self::_white_point_d65();
-        let x_normalized = x / white_point[0];
-        let y_normalized = y / white_point[1];
-        let z_normalized = z / white_point[2];
-        let fx = Self::_lab_f(x_normalized);
-        let fy = Self::_lab_f(y_normalized);
-        let fz = Self::_lab_f(z_normalized);
-        let l = 116.0 * fy - 16.0;
-        let a = 500.0 * (fx - fy);
-        let b = 200.0 * (fy - fz);
-        return [l, a, b].to_vec();
+        Argb(argb as u32).to_lab()
     }
 
     /// Converts an L* value to an ARGB representation.
@@ -144,19 +109,18 @@ impl ColorUtils {
     /// [lstar] L* in L*a*b*
     /// Returns ARGB representation of grayscale color with lightness
     /// matching L*
+    #[deprecated = "use `Argb::from_lstar` instead"]
     pub fn argb_from_lstar(lstar: f64) -> i64 {
-        let y = Self::y_from_lstar(lstar);
-        let component = Self::delinearized(y);
-        return Self::argb_from_rgb(component, component, component);
+        Argb::from_lstar(lstar).into()
     }
 
     /// Computes the L* value of a color in ARGB representation.
     ///
     /// [argb] ARGB representation of a color
     /// Returns L*, from L*a*b*, coordinate of the color
+    #[deprecated = "use `Argb::lstar` instead"]
     pub fn lstar_from_argb(argb: i64) -> f64 {
-        let y = Self::xyz_from_argb(argb)[1];
-        return 116.0 * Self::_lab_f(y / 100.0) - 16.0;
+        Argb(argb as u32).lstar()
     }
 
     /// Converts an L* value to a Y value.
@@ -225,7 +189,7 @@ impl ColorUtils {
         return Self::_white_point_d65();
     }
 
-    fn _lab_f(t: f64) -> f64 {
+    pub(crate) fn _lab_f(t: f64) -> f64 {
         let e = 216.0 / 24389.0;
         let kappa = 24389.0 / 27.0;
 
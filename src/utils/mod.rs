@@ -1,3 +1,6 @@
+pub mod argb;
 pub mod color_utils;
 pub mod math_utils;
 pub mod string_utils;
+pub mod theme_from_image;
+pub mod theme_utils;
@@ -1,4 +1,4 @@
-use super::color_utils::ColorUtils;
+use super::argb::Argb;
 
 pub struct StringUtils {}
 impl StringUtils {
@@ -10,21 +10,20 @@ impl StringUtils {
         }
     }
 
+    #[deprecated = "use `Argb`'s `Display` implementation instead"]
     pub fn hex_from_argb(argb: i64, leading_hash_sign: Option<bool>) -> String {
         let leading_hash_sign = leading_hash_sign.unwrap_or(true);
-
-        let red = ColorUtils::red_from_argb(argb);
-        let green = ColorUtils::green_from_argb(argb);
-        let blue = ColorUtils::blue_from_argb(argb);
+        let argb = Argb(argb as u32);
 
         return format!(
             "{}{}",
             Self::_map_bool_string(leading_hash_sign, "#", ""),
-            format!("{:02x}{:02x}{:02x}", red, green, blue).to_uppercase()
+            format!("{:02x}{:02x}{:02x}", argb.red(), argb.green(), argb.blue()).to_uppercase()
         );
     }
 
+    #[deprecated = "use `Argb`'s `FromStr` implementation instead"]
     pub fn argb_from_hex(hex: String) -> Option<i64> {
-        return i64::from_str_radix(hex.trim_start_matches("#"), 16).ok();
+        return hex.parse::<Argb>().ok().map(|argb| argb.into());
     }
 }
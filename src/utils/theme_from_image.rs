@@ -0,0 +1,296 @@
+use crate::{
+    dynamiccolor::{dynamic_scheme::DynamicScheme, variant::Variant},
+    hct::hct::Hct,
+    quantize::{quantizer::Quantizer, quantizer_celebi::QuantizerCelebi},
+    scheme::scheme_builder::SchemeBuilder,
+    score::score::{Score, ScoreOptions},
+    utils::math_utils::MathUtils,
+};
+
+#[cfg(feature = "image")]
+use crate::quantize::image_pixels::ImagePixels;
+
+use super::argb::Argb;
+
+/// A scheme generated from an image, see [ThemeFromImage].
+#[derive(Clone, PartialEq)]
+pub struct ImageTheme {
+    /// The color the scheme was generated from, the first of [candidates].
+    pub source_color: Argb,
+    /// Suitable source colors of the image, most suitable first.
+    pub candidates: Vec<Argb>,
+    pub scheme: DynamicScheme,
+}
+
+/// Roles of a scheme to preview it, such as in a wallpaper picker.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ThemePreview {
+    pub primary: Argb,
+    pub primary_container: Argb,
+    pub secondary: Argb,
+    pub tertiary: Argb,
+    pub surface: Argb,
+}
+
+impl ThemePreview {
+    pub fn from_scheme(scheme: &DynamicScheme) -> ThemePreview {
+        ThemePreview {
+            primary: scheme.primary(),
+            primary_container: scheme.primary_container(),
+            secondary: scheme.secondary(),
+            tertiary: scheme.tertiary(),
+            surface: scheme.surface(),
+        }
+    }
+}
+
+/// One of the schemes offered by [ThemeFromImage::theme_candidates].
+#[derive(Clone, PartialEq)]
+pub struct ThemeCandidate {
+    pub source_color: Argb,
+    pub variant: Variant,
+    pub scheme: DynamicScheme,
+    pub preview: ThemePreview,
+}
+
+/// Generates a [DynamicScheme] from the pixels of an image.
+///
+/// The pixels are quantized with [QuantizerCelebi], the resulting colors are
+/// ranked with [Score], and the most suitable one becomes the source color of
+/// the scheme.
+///
+/// Defaults to a light [Variant::TonalSpot] scheme at standard contrast,
+/// quantizing to 128 colors and ranking up to 4 candidates, with Google Blue
+/// as the fallback color. Candidate schemes are at least 15 degrees of hue
+/// apart, falling back to [Variant::Rainbow], [Variant::Neutral] and
+/// [Variant::Expressive].
+#[derive(Clone, Debug)]
+pub struct ThemeFromImage {
+    max_colors: i64,
+    candidate_count: i64,
+    max_pixels: usize,
+    fallback_color: Argb,
+    variant: Variant,
+    is_dark: bool,
+    contrast_level: f64,
+    min_hue_distance: f64,
+    fallback_variants: Vec<Variant>,
+}
+
+impl Default for ThemeFromImage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ThemeFromImage {
+    /// The default of [max_pixels], the pixel count of a 128x128 image.
+    pub const DEFAULT_MAX_PIXELS: usize = 128 * 128;
+
+    pub fn new() -> ThemeFromImage {
+        ThemeFromImage {
+            max_colors: 128,
+            candidate_count: 4,
+            max_pixels: Self::DEFAULT_MAX_PIXELS,
+            fallback_color: Argb(0xff4285f4),
+            variant: Variant::TonalSpot,
+            is_dark: false,
+            contrast_level: 0.0,
+            min_hue_distance: 15.0,
+            fallback_variants: vec![Variant::Rainbow, Variant::Neutral, Variant::Expressive],
+        }
+    }
+
+    /// The number of colors the image is quantized to.
+    pub fn max_colors(mut self, max_colors: i64) -> ThemeFromImage {
+        self.max_colors = max_colors;
+        self
+    }
+
+    /// The maximum number of ranked candidate colors.
+    pub fn candidate_count(mut self, candidate_count: i64) -> ThemeFromImage {
+        self.candidate_count = candidate_count;
+        self
+    }
+
+    /// Images with more pixels than [max_pixels] are downsampled, keeping
+    /// evenly spaced pixels, before they are quantized. 0 disables
+    /// downsampling.
+    pub fn max_pixels(mut self, max_pixels: usize) -> ThemeFromImage {
+        self.max_pixels = max_pixels;
+        self
+    }
+
+    /// The source color used when the image has no suitable colors.
+    pub fn fallback_color(mut self, fallback_color: Argb) -> ThemeFromImage {
+        self.fallback_color = fallback_color;
+        self
+    }
+
+    /// The variant, or style, of the scheme.
+    pub fn variant(mut self, variant: Variant) -> ThemeFromImage {
+        self.variant = variant;
+        self
+    }
+
+    /// Whether the scheme is in dark mode.
+    pub fn is_dark(mut self, is_dark: bool) -> ThemeFromImage {
+        self.is_dark = is_dark;
+        self
+    }
+
+    /// Value from -1 to 1, see [DynamicScheme.contrast_level].
+    pub fn contrast_level(mut self, contrast_level: f64) -> ThemeFromImage {
+        self.contrast_level = contrast_level;
+        self
+    }
+
+    /// The minimum hue difference, in degrees, between the primaries of
+    /// candidates with different source colors, see [theme_candidates].
+    pub fn min_hue_distance(mut self, min_hue_distance: f64) -> ThemeFromImage {
+        self.min_hue_distance = min_hue_distance;
+        self
+    }
+
+    /// The variants used, in order, when the image has too few distinct hues
+    /// for [theme_candidates].
+    pub fn fallback_variants(mut self, fallback_variants: Vec<Variant>) -> ThemeFromImage {
+        self.fallback_variants = fallback_variants;
+        self
+    }
+
+    /// The opaque pixels of [pixels] that are quantized, at most
+    /// [max_pixels] of them.
+    ///
+    /// Large images are sampled every few pixels, evenly across [pixels], and
+    /// transparent and translucent samples are skipped.
+    pub fn sample(&self, pixels: &[Argb]) -> Vec<Argb> {
+        let step = if self.max_pixels == 0 {
+            1
+        } else {
+            pixels.len().div_ceil(self.max_pixels).max(1)
+        };
+        return pixels
+            .iter()
+            .step_by(step)
+            .copied()
+            .filter(|p| p.is_opaque())
+            .collect();
+    }
+
+    /// Suitable source colors of [pixels], most suitable first.
+    ///
+    /// There is always at least one candidate: [fallback_color] if the image
+    /// has no suitable colors.
+    pub fn candidates(&self, pixels: &[Argb]) -> Vec<Argb> {
+        let sampled = self.sample(pixels);
+        let result = QuantizerCelebi {}.quantize(&sampled, self.max_colors, None);
+        return Score::score_argb(
+            &result.color_to_count,
+            Some(self.candidate_count),
+            Some(self.fallback_color),
+            None,
+        );
+    }
+
+    /// Generates a scheme from [pixels].
+    pub fn theme(&self, pixels: &[Argb]) -> ImageTheme {
+        let candidates = self.candidates(pixels);
+        let source_color = candidates[0];
+        let scheme = SchemeBuilder::new(Hct::from_argb(source_color))
+            .variant(self.variant.clone())
+            .is_dark(self.is_dark)
+            .contrast_level(self.contrast_level)
+            .build();
+        return ImageTheme {
+            source_color,
+            candidates,
+            scheme,
+        };
+    }
+
+    /// Generates up to [count] schemes from [pixels], most suitable first, to
+    /// offer as choices like the Android wallpaper picker does.
+    ///
+    /// Each suitable source color of the image gives a scheme of [variant],
+    /// skipping those whose primary hue is within [min_hue_distance] of an
+    /// earlier candidate's. If that leaves fewer than [count] candidates, as
+    /// with an image of a single hue, the most suitable source color is
+    /// reused with each of [fallback_variants] until there are [count].
+    pub fn theme_candidates(&self, pixels: &[Argb], count: usize) -> Vec<ThemeCandidate> {
+        if count == 0 {
+            return Vec::new();
+        }
+        let sampled = self.sample(pixels);
+        let result = QuantizerCelebi {}.quantize(&sampled, self.max_colors, None);
+        let min_hue_separation = self.min_hue_distance.ceil().max(0.0) as i64;
+        let options = ScoreOptions {
+            desired: count as i64,
+            fallback_color: self.fallback_color,
+            max_hue_separation: min_hue_separation.max(90),
+            min_hue_separation,
+            ..ScoreOptions::default()
+        };
+        let source_colors = Score::score_with(&result.color_to_count, &options);
+
+        let mut candidates: Vec<ThemeCandidate> = Vec::new();
+        for source_color in &source_colors {
+            let candidate = self._candidate(*source_color, self.variant.clone());
+            let hue = candidate.scheme.primary_palette.hue;
+            let too_close = candidates.iter().any(|chosen| {
+                MathUtils::difference_degrees(hue, chosen.scheme.primary_palette.hue)
+                    < self.min_hue_distance
+            });
+            if !too_close {
+                candidates.push(candidate);
+            }
+            if candidates.len() >= count {
+                return candidates;
+            }
+        }
+        for variant in &self.fallback_variants {
+            if candidates.len() >= count {
+                break;
+            }
+            let duplicate = candidates.iter().any(|chosen| {
+                chosen.source_color == source_colors[0] && chosen.variant == *variant
+            });
+            if !duplicate {
+                candidates.push(self._candidate(source_colors[0], variant.clone()));
+            }
+        }
+        return candidates;
+    }
+
+    fn _candidate(&self, source_color: Argb, variant: Variant) -> ThemeCandidate {
+        let scheme = SchemeBuilder::new(Hct::from_argb(source_color))
+            .variant(variant.clone())
+            .is_dark(self.is_dark)
+            .contrast_level(self.contrast_level)
+            .build();
+        return ThemeCandidate {
+            source_color,
+            variant,
+            preview: ThemePreview::from_scheme(&scheme),
+            scheme,
+        };
+    }
+
+    /// Generates a scheme from the opaque pixels of [image], see
+    /// [ImagePixels].
+    #[cfg(feature = "image")]
+    pub fn theme_image(&self, image: impl Into<ImagePixels>) -> ImageTheme {
+        return self.theme(&image.into());
+    }
+
+    /// Generates up to [count] schemes from the opaque pixels of [image], see
+    /// [theme_candidates].
+    #[cfg(feature = "image")]
+    pub fn theme_candidates_image(
+        &self,
+        image: impl Into<ImagePixels>,
+        count: usize,
+    ) -> Vec<ThemeCandidate> {
+        return self.theme_candidates(&image.into(), count);
+    }
+}
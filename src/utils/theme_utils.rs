@@ -0,0 +1,72 @@
+use crate::{
+    blend::blend::Blend,
+    dynamiccolor::dynamic_scheme::DynamicScheme,
+    palettes::{core_palette::CorePalette, tonal_palette::TonalPalette},
+};
+
+use super::argb::Argb;
+
+/// Color roles of a custom color, see [CustomColorGroup].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ColorGroup {
+    pub color: Argb,
+    pub on_color: Argb,
+    pub color_container: Argb,
+    pub on_color_container: Argb,
+}
+
+/// A custom color, such as a brand color, expanded into [ColorGroup] roles
+/// that fit a [DynamicScheme].
+///
+/// The roles follow the same tones, contrast curves and tone delta
+/// constraints as the scheme's error roles, so they honor its [is_dark] and
+/// [contrast_level].
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomColorGroup {
+    pub name: String,
+    pub value: Argb,
+    /// Whether [value] is shifted towards the scheme's source color with
+    /// [Blend::harmonize_argb]. Defaults to true.
+    pub harmonize: bool,
+}
+
+impl CustomColorGroup {
+    pub fn new(name: &str, value: Argb) -> CustomColorGroup {
+        CustomColorGroup {
+            name: name.to_string(),
+            value,
+            harmonize: true,
+        }
+    }
+
+    /// Whether [value] is harmonized with the scheme's source color.
+    pub fn harmonize(mut self, harmonize: bool) -> CustomColorGroup {
+        self.harmonize = harmonize;
+        self
+    }
+
+    /// The custom color as used in [scheme], i.e. harmonized with its source
+    /// color if [harmonize] is set.
+    pub fn value_for(&self, scheme: &DynamicScheme) -> Argb {
+        if self.harmonize {
+            return Blend::harmonize_argb(self.value, scheme.source_color_argb);
+        }
+        return self.value;
+    }
+
+    /// The palette the roles of the custom color are taken from in [scheme].
+    pub fn palette(&self, scheme: &DynamicScheme) -> TonalPalette {
+        return CorePalette::from_argb(self.value_for(scheme)).primary;
+    }
+
+    /// The roles of the custom color in [scheme].
+    pub fn resolve(&self, scheme: &DynamicScheme) -> ColorGroup {
+        let scheme = scheme.clone().with_error_palette(self.palette(scheme));
+        return ColorGroup {
+            color: scheme.error(),
+            on_color: scheme.on_error(),
+            color_container: scheme.error_container(),
+            on_color_container: scheme.on_error_container(),
+        };
+    }
+}